@@ -57,6 +57,8 @@ export type DomainConfig = {
   numOrigins: number;
   rpId: ReadonlyUint8Array;
   origins: ReadonlyUint8Array;
+  requireUserPresence: number;
+  requireUserVerification: number;
 };
 
 export type DomainConfigArgs = {
@@ -68,6 +70,8 @@ export type DomainConfigArgs = {
   numOrigins: number;
   rpId: ReadonlyUint8Array;
   origins: ReadonlyUint8Array;
+  requireUserPresence: number;
+  requireUserVerification: number;
 };
 
 export function getDomainConfigEncoder(): FixedSizeEncoder<DomainConfigArgs> {
@@ -82,6 +86,8 @@ export function getDomainConfigEncoder(): FixedSizeEncoder<DomainConfigArgs> {
       ['numOrigins', getU8Encoder()],
       ['rpId', fixEncoderSize(getBytesEncoder(), 255)],
       ['origins', fixEncoderSize(getBytesEncoder(), 515)],
+      ['requireUserPresence', getU8Encoder()],
+      ['requireUserVerification', getU8Encoder()],
     ]),
     (value) => ({ ...value, discriminator: DOMAIN_CONFIG_DISCRIMINATOR })
   );
@@ -98,6 +104,8 @@ export function getDomainConfigDecoder(): FixedSizeDecoder<DomainConfig> {
     ['numOrigins', getU8Decoder()],
     ['rpId', fixDecoderSize(getBytesDecoder(), 255)],
     ['origins', fixDecoderSize(getBytesDecoder(), 515)],
+    ['requireUserPresence', getU8Decoder()],
+    ['requireUserVerification', getU8Decoder()],
   ]);
 }
 
@@ -162,5 +170,5 @@ export async function fetchAllMaybeDomainConfig(
 }
 
 export function getDomainConfigSize(): number {
-  return 846;
+  return 848;
 }
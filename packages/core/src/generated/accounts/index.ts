@@ -6,8 +6,10 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './configProposal';
 export * from './domainConfig';
 export * from './globalCounter';
+export * from './recovery';
 export * from './settings';
 export * from './transactionBuffer';
 export * from './user';
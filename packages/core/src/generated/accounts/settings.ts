@@ -23,6 +23,8 @@ import {
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -41,10 +43,18 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  getActionThresholdsDecoder,
+  getActionThresholdsEncoder,
   getMemberDecoder,
   getMemberEncoder,
+  getRecoveryConfigDecoder,
+  getRecoveryConfigEncoder,
+  type ActionThresholds,
+  type ActionThresholdsArgs,
   type Member,
   type MemberArgs,
+  type RecoveryConfig,
+  type RecoveryConfigArgs,
 } from '../types';
 
 export const SETTINGS_DISCRIMINATOR = new Uint8Array([
@@ -55,6 +65,7 @@ export function getSettingsDiscriminatorBytes() {
   return fixEncoderSize(getBytesEncoder(), 8).encode(SETTINGS_DISCRIMINATOR);
 }
 
+/** Accounts from the first deployment are upgraded by `migrate_settings`, see "Upgrading the program" in the README. */
 export type Settings = {
   discriminator: ReadonlyUint8Array;
   index: bigint;
@@ -64,6 +75,26 @@ export type Settings = {
   bump: number;
   padding: number;
   latestSlotNumber: bigint;
+  actionThresholds: ActionThresholds;
+  /** Delay in seconds between proposing and applying a config change. 0 allows immediate changes. */
+  configTimelock: bigint;
+  recoveryConfig: RecoveryConfig;
+  /**
+   * Incremented by every applied config action, so transaction buffers created under an older
+   * member set or threshold can be told apart.
+   */
+  configVersion: bigint;
+  /** Seconds a transaction buffer stays executable after creation. 0 falls back to `TRANSACTION_TIME_LIMIT`. */
+  transactionExpiry: bigint;
+  /**
+   * Sequence number the next transaction buffer of this wallet is created with, which also seeds
+   * its address.
+   */
+  transactionIndex: bigint;
+  /** Transaction buffers created for this wallet that have not been closed yet. */
+  openTransactionBuffers: number;
+  /** Config proposals created for this wallet that have not been executed or cancelled yet. */
+  openConfigProposals: number;
 };
 
 export type SettingsArgs = {
@@ -74,6 +105,26 @@ export type SettingsArgs = {
   bump: number;
   padding: number;
   latestSlotNumber: number | bigint;
+  actionThresholds: ActionThresholdsArgs;
+  /** Delay in seconds between proposing and applying a config change. 0 allows immediate changes. */
+  configTimelock: number | bigint;
+  recoveryConfig: RecoveryConfigArgs;
+  /**
+   * Incremented by every applied config action, so transaction buffers created under an older
+   * member set or threshold can be told apart.
+   */
+  configVersion: number | bigint;
+  /** Seconds a transaction buffer stays executable after creation. 0 falls back to `TRANSACTION_TIME_LIMIT`. */
+  transactionExpiry: number | bigint;
+  /**
+   * Sequence number the next transaction buffer of this wallet is created with, which also seeds
+   * its address.
+   */
+  transactionIndex: number | bigint;
+  /** Transaction buffers created for this wallet that have not been closed yet. */
+  openTransactionBuffers: number;
+  /** Config proposals created for this wallet that have not been executed or cancelled yet. */
+  openConfigProposals: number;
 };
 
 export function getSettingsEncoder(): Encoder<SettingsArgs> {
@@ -87,6 +138,14 @@ export function getSettingsEncoder(): Encoder<SettingsArgs> {
       ['bump', getU8Encoder()],
       ['padding', getU8Encoder()],
       ['latestSlotNumber', getU64Encoder()],
+      ['actionThresholds', getActionThresholdsEncoder()],
+      ['configTimelock', getU64Encoder()],
+      ['recoveryConfig', getRecoveryConfigEncoder()],
+      ['configVersion', getU64Encoder()],
+      ['transactionExpiry', getU64Encoder()],
+      ['transactionIndex', getU64Encoder()],
+      ['openTransactionBuffers', getU32Encoder()],
+      ['openConfigProposals', getU32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: SETTINGS_DISCRIMINATOR })
   );
@@ -102,6 +161,14 @@ export function getSettingsDecoder(): Decoder<Settings> {
     ['bump', getU8Decoder()],
     ['padding', getU8Decoder()],
     ['latestSlotNumber', getU64Decoder()],
+    ['actionThresholds', getActionThresholdsDecoder()],
+    ['configTimelock', getU64Decoder()],
+    ['recoveryConfig', getRecoveryConfigDecoder()],
+    ['configVersion', getU64Decoder()],
+    ['transactionExpiry', getU64Decoder()],
+    ['transactionIndex', getU64Decoder()],
+    ['openTransactionBuffers', getU32Decoder()],
+    ['openConfigProposals', getU32Decoder()],
   ]);
 }
 
@@ -27,8 +27,6 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU16Decoder,
-  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
   getU64Decoder,
@@ -51,10 +49,14 @@ import {
 import {
   getExpectedSignerDecoder,
   getExpectedSignerEncoder,
+  getExpiryModeDecoder,
+  getExpiryModeEncoder,
   getMemberKeyDecoder,
   getMemberKeyEncoder,
   type ExpectedSigner,
   type ExpectedSignerArgs,
+  type ExpiryMode,
+  type ExpiryModeArgs,
   type MemberKey,
   type MemberKeyArgs,
 } from '../types';
@@ -80,12 +82,24 @@ export type TransactionBuffer = {
   /** Flag to preauthorize execution before sufficient threshold is met */
   preauthorizeExecution: boolean;
   validTill: bigint;
+  /** Transaction cannot be executed before this time (0 when unscheduled) */
+  notBefore: bigint;
+  /** Whether `valid_till` and `not_before` are unix timestamps or slot numbers */
+  expiryMode: ExpiryMode;
+  /** Settings config version at creation. Votes and execution are rejected once it changes. */
+  configVersion: bigint;
   /** Payer for the transaction buffer */
   payer: Address;
   /** transaction bump */
   bump: number;
-  /** Index to seed address derivation */
-  bufferIndex: number;
+  /** Wallet-wide sequence number of the transaction, used to seed address derivation */
+  transactionIndex: bigint;
+  /** Index of the vault the transaction executes from */
+  vaultIndex: number;
+  /** Number of messages in the batch */
+  messageCount: number;
+  /** Number of messages of the batch executed so far */
+  executedMessages: number;
   /** Hash of the final assembled transaction message. */
   finalBufferHash: ReadonlyUint8Array;
   /** The size of the final assembled transaction message. */
@@ -96,8 +110,14 @@ export type TransactionBuffer = {
   executor: MemberKey;
   /** Buffer hash for all the buffer extend instruction */
   bufferExtendHashes: Array<ReadonlyUint8Array>;
+  /** Size of every chunk but the last */
+  chunkSize: number;
+  /** Which chunks have been written, in the order of `buffer_extend_hashes` */
+  writtenChunks: Array<boolean>;
   /** Members that voted for this transaction */
   voters: Array<MemberKey>;
+  /** Members that rejected this transaction */
+  rejecters: Array<MemberKey>;
   /** All Signers that are expected to initiate / vote / execute this transaction (used for off-chain inspection by the transaction manager) */
   expectedSigners: Array<ExpectedSigner>;
   /** The buffer of the transaction message. */
@@ -114,12 +134,24 @@ export type TransactionBufferArgs = {
   /** Flag to preauthorize execution before sufficient threshold is met */
   preauthorizeExecution: boolean;
   validTill: number | bigint;
+  /** Transaction cannot be executed before this time (0 when unscheduled) */
+  notBefore: number | bigint;
+  /** Whether `valid_till` and `not_before` are unix timestamps or slot numbers */
+  expiryMode: ExpiryModeArgs;
+  /** Settings config version at creation. Votes and execution are rejected once it changes. */
+  configVersion: number | bigint;
   /** Payer for the transaction buffer */
   payer: Address;
   /** transaction bump */
   bump: number;
-  /** Index to seed address derivation */
-  bufferIndex: number;
+  /** Wallet-wide sequence number of the transaction, used to seed address derivation */
+  transactionIndex: number | bigint;
+  /** Index of the vault the transaction executes from */
+  vaultIndex: number;
+  /** Number of messages in the batch */
+  messageCount: number;
+  /** Number of messages of the batch executed so far */
+  executedMessages: number;
   /** Hash of the final assembled transaction message. */
   finalBufferHash: ReadonlyUint8Array;
   /** The size of the final assembled transaction message. */
@@ -130,8 +162,14 @@ export type TransactionBufferArgs = {
   executor: MemberKeyArgs;
   /** Buffer hash for all the buffer extend instruction */
   bufferExtendHashes: Array<ReadonlyUint8Array>;
+  /** Size of every chunk but the last */
+  chunkSize: number;
+  /** Which chunks have been written, in the order of `buffer_extend_hashes` */
+  writtenChunks: Array<boolean>;
   /** Members that voted for this transaction */
   voters: Array<MemberKeyArgs>;
+  /** Members that rejected this transaction */
+  rejecters: Array<MemberKeyArgs>;
   /** All Signers that are expected to initiate / vote / execute this transaction (used for off-chain inspection by the transaction manager) */
   expectedSigners: Array<ExpectedSignerArgs>;
   /** The buffer of the transaction message. */
@@ -147,18 +185,27 @@ export function getTransactionBufferEncoder(): Encoder<TransactionBufferArgs> {
       ['canExecute', getBooleanEncoder()],
       ['preauthorizeExecution', getBooleanEncoder()],
       ['validTill', getU64Encoder()],
+      ['notBefore', getU64Encoder()],
+      ['expiryMode', getExpiryModeEncoder()],
+      ['configVersion', getU64Encoder()],
       ['payer', getAddressEncoder()],
       ['bump', getU8Encoder()],
-      ['bufferIndex', getU8Encoder()],
+      ['transactionIndex', getU64Encoder()],
+      ['vaultIndex', getU8Encoder()],
+      ['messageCount', getU8Encoder()],
+      ['executedMessages', getU8Encoder()],
       ['finalBufferHash', fixEncoderSize(getBytesEncoder(), 32)],
-      ['finalBufferSize', getU16Encoder()],
+      ['finalBufferSize', getU32Encoder()],
       ['creator', getMemberKeyEncoder()],
       ['executor', getMemberKeyEncoder()],
       [
         'bufferExtendHashes',
         getArrayEncoder(fixEncoderSize(getBytesEncoder(), 32)),
       ],
+      ['chunkSize', getU32Encoder()],
+      ['writtenChunks', getArrayEncoder(getBooleanEncoder())],
       ['voters', getArrayEncoder(getMemberKeyEncoder())],
+      ['rejecters', getArrayEncoder(getMemberKeyEncoder())],
       ['expectedSigners', getArrayEncoder(getExpectedSignerEncoder())],
       ['buffer', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ]),
@@ -174,18 +221,27 @@ export function getTransactionBufferDecoder(): Decoder<TransactionBuffer> {
     ['canExecute', getBooleanDecoder()],
     ['preauthorizeExecution', getBooleanDecoder()],
     ['validTill', getU64Decoder()],
+    ['notBefore', getU64Decoder()],
+    ['expiryMode', getExpiryModeDecoder()],
+    ['configVersion', getU64Decoder()],
     ['payer', getAddressDecoder()],
     ['bump', getU8Decoder()],
-    ['bufferIndex', getU8Decoder()],
+    ['transactionIndex', getU64Decoder()],
+    ['vaultIndex', getU8Decoder()],
+    ['messageCount', getU8Decoder()],
+    ['executedMessages', getU8Decoder()],
     ['finalBufferHash', fixDecoderSize(getBytesDecoder(), 32)],
-    ['finalBufferSize', getU16Decoder()],
+    ['finalBufferSize', getU32Decoder()],
     ['creator', getMemberKeyDecoder()],
     ['executor', getMemberKeyDecoder()],
     [
       'bufferExtendHashes',
       getArrayDecoder(fixDecoderSize(getBytesDecoder(), 32)),
     ],
+    ['chunkSize', getU32Decoder()],
+    ['writtenChunks', getArrayDecoder(getBooleanDecoder())],
     ['voters', getArrayDecoder(getMemberKeyDecoder())],
+    ['rejecters', getArrayDecoder(getMemberKeyDecoder())],
     ['expectedSigners', getArrayDecoder(getExpectedSignerDecoder())],
     ['buffer', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
   ]);
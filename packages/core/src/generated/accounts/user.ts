@@ -85,6 +85,11 @@ export type User = {
   role: UserRole;
   transactionManagerUrl: Option<string>;
   bump: number;
+  /**
+   * Last signature counter seen from the user's passkey, see https://w3c.github.io/webauthn/#signature-counter.
+   * Kept last so accounts created before it existed can be upgraded by `migrate_user_account`.
+   */
+  signCount: number;
 };
 
 export type UserArgs = {
@@ -96,6 +101,11 @@ export type UserArgs = {
   role: UserRoleArgs;
   transactionManagerUrl: OptionOrNullable<string>;
   bump: number;
+  /**
+   * Last signature counter seen from the user's passkey, see https://w3c.github.io/webauthn/#signature-counter.
+   * Kept last so accounts created before it existed can be upgraded by `migrate_user_account`.
+   */
+  signCount: number;
 };
 
 export function getUserEncoder(): Encoder<UserArgs> {
@@ -120,6 +130,7 @@ export function getUserEncoder(): Encoder<UserArgs> {
         ),
       ],
       ['bump', getU8Encoder()],
+      ['signCount', getU32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: USER_DISCRIMINATOR })
   );
@@ -144,6 +155,7 @@ export function getUserDecoder(): Decoder<User> {
       getOptionDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
     ],
     ['bump', getU8Decoder()],
+    ['signCount', getU32Decoder()],
   ]);
 }
 
@@ -98,7 +98,7 @@ export const MULTI_WALLET_ERROR__FINAL_BUFFER_HASH_MISMATCH = 0x1797; // 6039
 export const MULTI_WALLET_ERROR__FINAL_BUFFER_SIZE_EXCEEDED = 0x1798; // 6040
 /** FinalBufferSizeMismatch: Declared buffer size does not match the actual serialized size. */
 export const MULTI_WALLET_ERROR__FINAL_BUFFER_SIZE_MISMATCH = 0x1799; // 6041
-/** TransactionHasExpired: This transaction has expired. It must be executed within 3 minutes of approval. */
+/** TransactionHasExpired: This transaction has expired. It must be executed within the wallet's transaction expiry window. */
 export const MULTI_WALLET_ERROR__TRANSACTION_HAS_EXPIRED = 0x179a; // 6042
 /** TransactionNotApproved: This transaction has not yet reached the required approval threshold. */
 export const MULTI_WALLET_ERROR__TRANSACTION_NOT_APPROVED = 0x179b; // 6043
@@ -148,16 +148,113 @@ export const MULTI_WALLET_ERROR__INVALID_USER_ED25519_CONFIG = 0x17b0; // 6064
 export const MULTI_WALLET_ERROR__MISSING_SETTINGS_ACCOUNT_FOR_DELEGATE = 0x17b1; // 6065
 /** MissingNewAuthority: Missing new authority account. Required for domain configuration updates. */
 export const MULTI_WALLET_ERROR__MISSING_NEW_AUTHORITY = 0x17b2; // 6066
+/** InvalidMemberWeight: Invalid member weight: members with vote permission must have a weight of at least 1. */
+export const MULTI_WALLET_ERROR__INVALID_MEMBER_WEIGHT = 0x17b3; // 6067
+/** ConfigTimelockActive: A config timelock is active. Configuration changes must go through a config proposal. */
+export const MULTI_WALLET_ERROR__CONFIG_TIMELOCK_ACTIVE = 0x17b4; // 6068
+/** ConfigProposalTimelockNotElapsed: The config proposal timelock has not elapsed yet. */
+export const MULTI_WALLET_ERROR__CONFIG_PROPOSAL_TIMELOCK_NOT_ELAPSED = 0x17b5; // 6069
+/** ConfigProposalTimelockElapsed: The config proposal timelock has elapsed. It can no longer be cancelled. */
+export const MULTI_WALLET_ERROR__CONFIG_PROPOSAL_TIMELOCK_ELAPSED = 0x17b6; // 6070
+/** ConfigActionsHashMismatch: Config actions hash mismatch: the stored config actions may be corrupted or altered. */
+export const MULTI_WALLET_ERROR__CONFIG_ACTIONS_HASH_MISMATCH = 0x17b7; // 6071
+/** InvalidGuardianConfig: Invalid guardian configuration: guardians cannot hold permissions or be delegates. */
+export const MULTI_WALLET_ERROR__INVALID_GUARDIAN_CONFIG = 0x17b8; // 6072
+/** InvalidGuardianThreshold: Invalid guardian threshold: it cannot exceed the number of guardians. */
+export const MULTI_WALLET_ERROR__INVALID_GUARDIAN_THRESHOLD = 0x17b9; // 6073
+/** RecoveryDelayTooShort: Recovery delay is below the minimum required while recovery is enabled. */
+export const MULTI_WALLET_ERROR__RECOVERY_DELAY_TOO_SHORT = 0x17ba; // 6074
+/** RecoveryNotEnabled: Recovery is not enabled for this wallet. Set a guardian threshold first. */
+export const MULTI_WALLET_ERROR__RECOVERY_NOT_ENABLED = 0x17bb; // 6075
+/** InsufficientGuardianSigners: Insufficient guardian signers to initiate recovery. */
+export const MULTI_WALLET_ERROR__INSUFFICIENT_GUARDIAN_SIGNERS = 0x17bc; // 6076
+/** RecoveryDelayNotElapsed: The recovery delay has not elapsed yet. */
+export const MULTI_WALLET_ERROR__RECOVERY_DELAY_NOT_ELAPSED = 0x17bd; // 6077
+/** ConfigVersionMismatch: The wallet configuration changed after this transaction buffer was created. Create a new transaction buffer. */
+export const MULTI_WALLET_ERROR__CONFIG_VERSION_MISMATCH = 0x17be; // 6078
+/** InvalidMultiWalletMemberConfig: Invalid multi-wallet member configuration: a nested wallet must be a regular, non-delegated member. */
+export const MULTI_WALLET_ERROR__INVALID_MULTI_WALLET_MEMBER_CONFIG = 0x17bf; // 6079
+/** InvalidTransactionExpiry: Invalid transaction expiry: it must be non-zero and within the expiry window. */
+export const MULTI_WALLET_ERROR__INVALID_TRANSACTION_EXPIRY = 0x17c0; // 6080
+/** ConflictingVote: A member cannot both approve and reject the same transaction. */
+export const MULTI_WALLET_ERROR__CONFLICTING_VOTE = 0x17c1; // 6081
+/** VoterNotFound: The signer has no vote on this transaction to withdraw. */
+export const MULTI_WALLET_ERROR__VOTER_NOT_FOUND = 0x17c2; // 6082
+/** BufferChunksMissing: Not every chunk of the transaction buffer has been written yet. */
+export const MULTI_WALLET_ERROR__BUFFER_CHUNKS_MISSING = 0x17c3; // 6083
+/** BufferChunkAlreadyWritten: This chunk of the transaction buffer has already been written. */
+export const MULTI_WALLET_ERROR__BUFFER_CHUNK_ALREADY_WRITTEN = 0x17c4; // 6084
+/** TransactionNotExpired: This transaction has not expired and still matches the wallet configuration, so it cannot be closed without the creator's approval. */
+export const MULTI_WALLET_ERROR__TRANSACTION_NOT_EXPIRED = 0x17c5; // 6085
+/** TransactionNotYetExecutable: This transaction is scheduled and cannot be executed before its not-before time. */
+export const MULTI_WALLET_ERROR__TRANSACTION_NOT_YET_EXECUTABLE = 0x17c6; // 6086
+/** InvalidEd25519Instruction: The referenced instruction is not an Ed25519 signature verification instruction. */
+export const MULTI_WALLET_ERROR__INVALID_ED25519_INSTRUCTION = 0x17c7; // 6087
+/** Ed25519ChallengeMismatch: The Ed25519 signed message does not match the expected challenge for this action. */
+export const MULTI_WALLET_ERROR__ED25519_CHALLENGE_MISMATCH = 0x17c8; // 6088
+/** InvalidSecp256k1Instruction: The referenced instruction is not a secp256k1 signature verification instruction. */
+export const MULTI_WALLET_ERROR__INVALID_SECP256K1_INSTRUCTION = 0x17c9; // 6089
+/** Secp256k1ChallengeMismatch: The secp256k1 signed message is not the EIP-191 wrapping of the expected challenge for this action. */
+export const MULTI_WALLET_ERROR__SECP256K1_CHALLENGE_MISMATCH = 0x17ca; // 6090
+/** InvalidSecp256k1MemberConfig: Invalid Ethereum key member configuration: it must be a regular member without delegation. */
+export const MULTI_WALLET_ERROR__INVALID_SECP256K1_MEMBER_CONFIG = 0x17cb; // 6091
+/** Secp256r1ChallengeMismatch: The raw secp256r1 signed message does not match the expected challenge. */
+export const MULTI_WALLET_ERROR__SECP256R1_CHALLENGE_MISMATCH = 0x17cc; // 6092
+/** InvalidAuthenticatorData: The authenticatorData in the signed message is malformed. */
+export const MULTI_WALLET_ERROR__INVALID_AUTHENTICATOR_DATA = 0x17cd; // 6093
+/** UserPresenceRequired: This domain requires user presence, but the assertion lacks the UP flag. */
+export const MULTI_WALLET_ERROR__USER_PRESENCE_REQUIRED = 0x17ce; // 6094
+/** UserVerificationRequired: This domain requires user verification, but the assertion lacks the UV flag. */
+export const MULTI_WALLET_ERROR__USER_VERIFICATION_REQUIRED = 0x17cf; // 6095
+/** SignCountNotIncreased: The passkey signature counter did not increase, the key may have been cloned. */
+export const MULTI_WALLET_ERROR__SIGN_COUNT_NOT_INCREASED = 0x17d0; // 6096
+/** UserAccountKeyMismatch: The user account is not the signer's writable user account. */
+export const MULTI_WALLET_ERROR__USER_ACCOUNT_KEY_MISMATCH = 0x17d1; // 6097
+/** MemberKeyTypeMismatch: A member key can only be rotated to a key of the same type. */
+export const MULTI_WALLET_ERROR__MEMBER_KEY_TYPE_MISMATCH = 0x17d2; // 6098
+/** WalletHasOpenAccounts: The wallet still has open transaction buffers, config proposals or a recovery. */
+export const MULTI_WALLET_ERROR__WALLET_HAS_OPEN_ACCOUNTS = 0x17d3; // 6099
+/** VaultTokenAccountNotEmpty: A vault token account must be emptied before the wallet can be closed. */
+export const MULTI_WALLET_ERROR__VAULT_TOKEN_ACCOUNT_NOT_EMPTY = 0x17d4; // 6100
+/** TransactionBatchStarted: The transaction batch has started executing and can only run to completion. */
+export const MULTI_WALLET_ERROR__TRANSACTION_BATCH_STARTED = 0x17d5; // 6101
+/** InvalidKeyType: Unknown member key type. */
+export const MULTI_WALLET_ERROR__INVALID_KEY_TYPE = 0x17d6; // 6102
+/** InvalidSecp256r1RawMemberConfig: Invalid raw P-256 key member configuration: it must be a regular member without delegation. */
+export const MULTI_WALLET_ERROR__INVALID_SECP256R1_RAW_MEMBER_CONFIG = 0x17d7; // 6103
+/** AccountAlreadyMigrated: This account already uses the current layout. */
+export const MULTI_WALLET_ERROR__ACCOUNT_ALREADY_MIGRATED = 0x17d8; // 6104
+/** ConfigProposalNotStale: The config proposal still matches the wallet configuration. */
+export const MULTI_WALLET_ERROR__CONFIG_PROPOSAL_NOT_STALE = 0x17d9; // 6105
+/** RecoveryVetoWindowElapsed: The recovery delay has elapsed. It can no longer be vetoed. */
+export const MULTI_WALLET_ERROR__RECOVERY_VETO_WINDOW_ELAPSED = 0x17da; // 6106
+/** BufferGrowthExceeded: A single extend can grow the transaction buffer by at most 10 KiB. Write the earlier chunks first. */
+export const MULTI_WALLET_ERROR__BUFFER_GROWTH_EXCEEDED = 0x17db; // 6107
+/** DuplicateTransactionBuffer: The same transaction buffer was passed more than once. */
+export const MULTI_WALLET_ERROR__DUPLICATE_TRANSACTION_BUFFER = 0x17dc; // 6108
 
 export type MultiWalletError =
+  | typeof MULTI_WALLET_ERROR__ACCOUNT_ALREADY_MIGRATED
   | typeof MULTI_WALLET_ERROR__ALREADY_DELEGATED
+  | typeof MULTI_WALLET_ERROR__BUFFER_CHUNKS_MISSING
+  | typeof MULTI_WALLET_ERROR__BUFFER_CHUNK_ALREADY_WRITTEN
+  | typeof MULTI_WALLET_ERROR__BUFFER_GROWTH_EXCEEDED
   | typeof MULTI_WALLET_ERROR__CLIENT_DATA_HASH_MISMATCH
+  | typeof MULTI_WALLET_ERROR__CONFIG_ACTIONS_HASH_MISMATCH
+  | typeof MULTI_WALLET_ERROR__CONFIG_PROPOSAL_NOT_STALE
+  | typeof MULTI_WALLET_ERROR__CONFIG_PROPOSAL_TIMELOCK_ELAPSED
+  | typeof MULTI_WALLET_ERROR__CONFIG_PROPOSAL_TIMELOCK_NOT_ELAPSED
+  | typeof MULTI_WALLET_ERROR__CONFIG_TIMELOCK_ACTIVE
+  | typeof MULTI_WALLET_ERROR__CONFIG_VERSION_MISMATCH
+  | typeof MULTI_WALLET_ERROR__CONFLICTING_VOTE
   | typeof MULTI_WALLET_ERROR__CREDENTIAL_ID_IS_MISSING
   | typeof MULTI_WALLET_ERROR__DOMAIN_CONFIG_IS_DISABLED
   | typeof MULTI_WALLET_ERROR__DOMAIN_CONFIG_IS_MISSING
   | typeof MULTI_WALLET_ERROR__DOMAIN_CONFIG_KEY_MISMATCH
   | typeof MULTI_WALLET_ERROR__DUPLICATE_MEMBER
+  | typeof MULTI_WALLET_ERROR__DUPLICATE_TRANSACTION_BUFFER
   | typeof MULTI_WALLET_ERROR__DURABLE_NONCE_DETECTED
+  | typeof MULTI_WALLET_ERROR__ED25519_CHALLENGE_MISMATCH
   | typeof MULTI_WALLET_ERROR__EMPTY_MEMBERS
   | typeof MULTI_WALLET_ERROR__EXPECTED_ADMINISTRATOR_ROLE_MISMATCH
   | typeof MULTI_WALLET_ERROR__EXPECTED_MESSAGE_HASH_MISMATCH
@@ -165,6 +262,7 @@ export type MultiWalletError =
   | typeof MULTI_WALLET_ERROR__FINAL_BUFFER_HASH_MISMATCH
   | typeof MULTI_WALLET_ERROR__FINAL_BUFFER_SIZE_EXCEEDED
   | typeof MULTI_WALLET_ERROR__FINAL_BUFFER_SIZE_MISMATCH
+  | typeof MULTI_WALLET_ERROR__INSUFFICIENT_GUARDIAN_SIGNERS
   | typeof MULTI_WALLET_ERROR__INSUFFICIENT_SIGNERS_WITH_VOTE_PERMISSION
   | typeof MULTI_WALLET_ERROR__INSUFFICIENT_SIGNER_WITH_EXECUTE_PERMISSION
   | typeof MULTI_WALLET_ERROR__INSUFFICIENT_SIGNER_WITH_INITIATE_PERMISSION
@@ -172,18 +270,29 @@ export type MultiWalletError =
   | typeof MULTI_WALLET_ERROR__INVALID_ACCOUNT_INDEX
   | typeof MULTI_WALLET_ERROR__INVALID_ADMINISTRATOR_CONFIG
   | typeof MULTI_WALLET_ERROR__INVALID_ARGUMENTS
+  | typeof MULTI_WALLET_ERROR__INVALID_AUTHENTICATOR_DATA
   | typeof MULTI_WALLET_ERROR__INVALID_BUFFER
+  | typeof MULTI_WALLET_ERROR__INVALID_ED25519_INSTRUCTION
+  | typeof MULTI_WALLET_ERROR__INVALID_GUARDIAN_CONFIG
+  | typeof MULTI_WALLET_ERROR__INVALID_GUARDIAN_THRESHOLD
+  | typeof MULTI_WALLET_ERROR__INVALID_KEY_TYPE
   | typeof MULTI_WALLET_ERROR__INVALID_MEMBER_KEY_FORMAT
+  | typeof MULTI_WALLET_ERROR__INVALID_MEMBER_WEIGHT
+  | typeof MULTI_WALLET_ERROR__INVALID_MULTI_WALLET_MEMBER_CONFIG
   | typeof MULTI_WALLET_ERROR__INVALID_NON_DELEGATED_SIGNERS
   | typeof MULTI_WALLET_ERROR__INVALID_NUMBER_OF_ACCOUNTS
   | typeof MULTI_WALLET_ERROR__INVALID_PERMANENT_MEMBER_CONFIG
+  | typeof MULTI_WALLET_ERROR__INVALID_SECP256K1_INSTRUCTION
+  | typeof MULTI_WALLET_ERROR__INVALID_SECP256K1_MEMBER_CONFIG
   | typeof MULTI_WALLET_ERROR__INVALID_SECP256R1_INSTRUCTION
   | typeof MULTI_WALLET_ERROR__INVALID_SECP256R1_PUBLIC_KEY
+  | typeof MULTI_WALLET_ERROR__INVALID_SECP256R1_RAW_MEMBER_CONFIG
   | typeof MULTI_WALLET_ERROR__INVALID_SECP256R1_VERIFY_ARG
   | typeof MULTI_WALLET_ERROR__INVALID_SIGNATURE_OFFSETS
   | typeof MULTI_WALLET_ERROR__INVALID_SLOT_NUMBER
   | typeof MULTI_WALLET_ERROR__INVALID_SYSVAR_DATA_FORMAT
   | typeof MULTI_WALLET_ERROR__INVALID_THRESHOLD
+  | typeof MULTI_WALLET_ERROR__INVALID_TRANSACTION_EXPIRY
   | typeof MULTI_WALLET_ERROR__INVALID_TRANSACTION_MANAGER_CONFIG
   | typeof MULTI_WALLET_ERROR__INVALID_TRANSACTION_MANAGER_PERMISSION
   | typeof MULTI_WALLET_ERROR__INVALID_TRANSACTION_MESSAGE
@@ -192,6 +301,7 @@ export type MultiWalletError =
   | typeof MULTI_WALLET_ERROR__INVALID_USER_TRANSACTION_MANAGER_CONFIG
   | typeof MULTI_WALLET_ERROR__MALFORMED_SIGNED_MESSAGE
   | typeof MULTI_WALLET_ERROR__MAX_LENGTH_EXCEEDED
+  | typeof MULTI_WALLET_ERROR__MEMBER_KEY_TYPE_MISMATCH
   | typeof MULTI_WALLET_ERROR__MEMBER_NOT_FOUND
   | typeof MULTI_WALLET_ERROR__MISSING_ACCOUNT
   | typeof MULTI_WALLET_ERROR__MISSING_INSTRUCTIONS_SYSVAR
@@ -206,29 +316,58 @@ export type MultiWalletError =
   | typeof MULTI_WALLET_ERROR__PAYER_MISMATCH
   | typeof MULTI_WALLET_ERROR__PERMANENT_MEMBER
   | typeof MULTI_WALLET_ERROR__PROTECTED_ACCOUNT
+  | typeof MULTI_WALLET_ERROR__RECOVERY_DELAY_NOT_ELAPSED
+  | typeof MULTI_WALLET_ERROR__RECOVERY_DELAY_TOO_SHORT
+  | typeof MULTI_WALLET_ERROR__RECOVERY_NOT_ENABLED
+  | typeof MULTI_WALLET_ERROR__RECOVERY_VETO_WINDOW_ELAPSED
   | typeof MULTI_WALLET_ERROR__RP_ID_HASH_MISMATCH
+  | typeof MULTI_WALLET_ERROR__SECP256K1_CHALLENGE_MISMATCH
+  | typeof MULTI_WALLET_ERROR__SECP256R1_CHALLENGE_MISMATCH
   | typeof MULTI_WALLET_ERROR__SETTINGS_KEY_MISMATCH
   | typeof MULTI_WALLET_ERROR__SIGNATURE_INDEX_OUT_OF_BOUNDS
+  | typeof MULTI_WALLET_ERROR__SIGN_COUNT_NOT_INCREASED
   | typeof MULTI_WALLET_ERROR__SLOT_NUMBER_NOT_FOUND
   | typeof MULTI_WALLET_ERROR__TOO_MANY_MEMBERS
+  | typeof MULTI_WALLET_ERROR__TRANSACTION_BATCH_STARTED
   | typeof MULTI_WALLET_ERROR__TRANSACTION_HAS_EXPIRED
   | typeof MULTI_WALLET_ERROR__TRANSACTION_NOT_APPROVED
+  | typeof MULTI_WALLET_ERROR__TRANSACTION_NOT_EXPIRED
+  | typeof MULTI_WALLET_ERROR__TRANSACTION_NOT_YET_EXECUTABLE
   | typeof MULTI_WALLET_ERROR__TRANSPORTS_IS_MISSING
   | typeof MULTI_WALLET_ERROR__UNAUTHORISED_TO_CLOSE_TRANSACTION_BUFFER
   | typeof MULTI_WALLET_ERROR__UNAUTHORIZED_ADMIN_ONLY
-  | typeof MULTI_WALLET_ERROR__UNEXPECTED_SIGNER;
+  | typeof MULTI_WALLET_ERROR__UNEXPECTED_SIGNER
+  | typeof MULTI_WALLET_ERROR__USER_ACCOUNT_KEY_MISMATCH
+  | typeof MULTI_WALLET_ERROR__USER_PRESENCE_REQUIRED
+  | typeof MULTI_WALLET_ERROR__USER_VERIFICATION_REQUIRED
+  | typeof MULTI_WALLET_ERROR__VAULT_TOKEN_ACCOUNT_NOT_EMPTY
+  | typeof MULTI_WALLET_ERROR__VOTER_NOT_FOUND
+  | typeof MULTI_WALLET_ERROR__WALLET_HAS_OPEN_ACCOUNTS;
 
 let multiWalletErrorMessages: Record<MultiWalletError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   multiWalletErrorMessages = {
+    [MULTI_WALLET_ERROR__ACCOUNT_ALREADY_MIGRATED]: `This account already uses the current layout.`,
     [MULTI_WALLET_ERROR__ALREADY_DELEGATED]: `This member is already delegated to another wallet.`,
+    [MULTI_WALLET_ERROR__BUFFER_CHUNKS_MISSING]: `Not every chunk of the transaction buffer has been written yet.`,
+    [MULTI_WALLET_ERROR__BUFFER_CHUNK_ALREADY_WRITTEN]: `This chunk of the transaction buffer has already been written.`,
+    [MULTI_WALLET_ERROR__BUFFER_GROWTH_EXCEEDED]: `A single extend can grow the transaction buffer by at most 10 KiB. Write the earlier chunks first.`,
     [MULTI_WALLET_ERROR__CLIENT_DATA_HASH_MISMATCH]: `The clientDataJSON hash extracted from the signature does not match the generated hash.`,
+    [MULTI_WALLET_ERROR__CONFIG_ACTIONS_HASH_MISMATCH]: `Config actions hash mismatch: the stored config actions may be corrupted or altered.`,
+    [MULTI_WALLET_ERROR__CONFIG_PROPOSAL_NOT_STALE]: `The config proposal still matches the wallet configuration.`,
+    [MULTI_WALLET_ERROR__CONFIG_PROPOSAL_TIMELOCK_ELAPSED]: `The config proposal timelock has elapsed. It can no longer be cancelled.`,
+    [MULTI_WALLET_ERROR__CONFIG_PROPOSAL_TIMELOCK_NOT_ELAPSED]: `The config proposal timelock has not elapsed yet.`,
+    [MULTI_WALLET_ERROR__CONFIG_TIMELOCK_ACTIVE]: `A config timelock is active. Configuration changes must go through a config proposal.`,
+    [MULTI_WALLET_ERROR__CONFIG_VERSION_MISMATCH]: `The wallet configuration changed after this transaction buffer was created. Create a new transaction buffer.`,
+    [MULTI_WALLET_ERROR__CONFLICTING_VOTE]: `A member cannot both approve and reject the same transaction.`,
     [MULTI_WALLET_ERROR__CREDENTIAL_ID_IS_MISSING]: `Missing credential id.`,
     [MULTI_WALLET_ERROR__DOMAIN_CONFIG_IS_DISABLED]: `The domain configuration account is disabled. Please contact support or try again later.`,
     [MULTI_WALLET_ERROR__DOMAIN_CONFIG_IS_MISSING]: `Missing domain configuration account.`,
     [MULTI_WALLET_ERROR__DOMAIN_CONFIG_KEY_MISMATCH]: `Domain configuration account key mismatch. The provided domain config does not match the expected value.`,
     [MULTI_WALLET_ERROR__DUPLICATE_MEMBER]: `Duplicate public keys detected among members. Each member must have a unique key.`,
+    [MULTI_WALLET_ERROR__DUPLICATE_TRANSACTION_BUFFER]: `The same transaction buffer was passed more than once.`,
     [MULTI_WALLET_ERROR__DURABLE_NONCE_DETECTED]: `Durable nonce detected: this program does not support transactions using a durable nonce.`,
+    [MULTI_WALLET_ERROR__ED25519_CHALLENGE_MISMATCH]: `The Ed25519 signed message does not match the expected challenge for this action.`,
     [MULTI_WALLET_ERROR__EMPTY_MEMBERS]: `No members provided. A multisig wallet must contain at least one member.`,
     [MULTI_WALLET_ERROR__EXPECTED_ADMINISTRATOR_ROLE_MISMATCH]: `Expected a User with Administrator role for this instruction.`,
     [MULTI_WALLET_ERROR__EXPECTED_MESSAGE_HASH_MISMATCH]: `The message hash associated with the signer does not match the pre-declared expected message hash.`,
@@ -236,6 +375,7 @@ if (process.env.NODE_ENV !== 'production') {
     [MULTI_WALLET_ERROR__FINAL_BUFFER_HASH_MISMATCH]: `Final buffer hash mismatch: the serialized data may be corrupted or altered.`,
     [MULTI_WALLET_ERROR__FINAL_BUFFER_SIZE_EXCEEDED]: `The serialized transaction buffer exceeds the maximum size of 10,128 bytes.`,
     [MULTI_WALLET_ERROR__FINAL_BUFFER_SIZE_MISMATCH]: `Declared buffer size does not match the actual serialized size.`,
+    [MULTI_WALLET_ERROR__INSUFFICIENT_GUARDIAN_SIGNERS]: `Insufficient guardian signers to initiate recovery.`,
     [MULTI_WALLET_ERROR__INSUFFICIENT_SIGNERS_WITH_VOTE_PERMISSION]: `Not enough members with vote permission to meet the approval threshold.`,
     [MULTI_WALLET_ERROR__INSUFFICIENT_SIGNER_WITH_EXECUTE_PERMISSION]: `At least one signer with execute permission is required to proceed.`,
     [MULTI_WALLET_ERROR__INSUFFICIENT_SIGNER_WITH_INITIATE_PERMISSION]: `At least one signer with initiate permission is required to perform this action.`,
@@ -243,18 +383,29 @@ if (process.env.NODE_ENV !== 'production') {
     [MULTI_WALLET_ERROR__INVALID_ACCOUNT_INDEX]: `Invalid account index in transaction message. The account index is out of bounds.`,
     [MULTI_WALLET_ERROR__INVALID_ADMINISTRATOR_CONFIG]: `User with Administrator role requires a valid domain config, be a ed25519 Signer and have no delegated wallet.`,
     [MULTI_WALLET_ERROR__INVALID_ARGUMENTS]: `Invalid or missing instruction arguments. Ensure all required arguments are correctly provided.`,
+    [MULTI_WALLET_ERROR__INVALID_AUTHENTICATOR_DATA]: `The authenticatorData in the signed message is malformed.`,
     [MULTI_WALLET_ERROR__INVALID_BUFFER]: `Buffer validation failed: contents do not match the expected hash (possible tampering detected).`,
+    [MULTI_WALLET_ERROR__INVALID_ED25519_INSTRUCTION]: `The referenced instruction is not an Ed25519 signature verification instruction.`,
+    [MULTI_WALLET_ERROR__INVALID_GUARDIAN_CONFIG]: `Invalid guardian configuration: guardians cannot hold permissions or be delegates.`,
+    [MULTI_WALLET_ERROR__INVALID_GUARDIAN_THRESHOLD]: `Invalid guardian threshold: it cannot exceed the number of guardians.`,
+    [MULTI_WALLET_ERROR__INVALID_KEY_TYPE]: `Unknown member key type.`,
     [MULTI_WALLET_ERROR__INVALID_MEMBER_KEY_FORMAT]: `Member key conversion failed. The provided key format is invalid.`,
+    [MULTI_WALLET_ERROR__INVALID_MEMBER_WEIGHT]: `Invalid member weight: members with vote permission must have a weight of at least 1.`,
+    [MULTI_WALLET_ERROR__INVALID_MULTI_WALLET_MEMBER_CONFIG]: `Invalid multi-wallet member configuration: a nested wallet must be a regular, non-delegated member.`,
     [MULTI_WALLET_ERROR__INVALID_NON_DELEGATED_SIGNERS]: `Only delegated members are allowed for this transaction.`,
     [MULTI_WALLET_ERROR__INVALID_NUMBER_OF_ACCOUNTS]: `Unexpected number of accounts provided for this instruction.`,
     [MULTI_WALLET_ERROR__INVALID_PERMANENT_MEMBER_CONFIG]: `User with Permanent Member role must have a delegated wallet.`,
+    [MULTI_WALLET_ERROR__INVALID_SECP256K1_INSTRUCTION]: `The referenced instruction is not a secp256k1 signature verification instruction.`,
+    [MULTI_WALLET_ERROR__INVALID_SECP256K1_MEMBER_CONFIG]: `Invalid Ethereum key member configuration: it must be a regular member without delegation.`,
     [MULTI_WALLET_ERROR__INVALID_SECP256R1_INSTRUCTION]: `The instruction preceding this program invocation is not a secp256r1 verification instruction.`,
     [MULTI_WALLET_ERROR__INVALID_SECP256R1_PUBLIC_KEY]: `The extracted public key has an invalid length or encoding.`,
+    [MULTI_WALLET_ERROR__INVALID_SECP256R1_RAW_MEMBER_CONFIG]: `Invalid raw P-256 key member configuration: it must be a regular member without delegation.`,
     [MULTI_WALLET_ERROR__INVALID_SECP256R1_VERIFY_ARG]: `Malformed or missing WebAuthn verification parameters. Please provide valid secp256r1 signature arguments.`,
     [MULTI_WALLET_ERROR__INVALID_SIGNATURE_OFFSETS]: `Failed to deserialize secp256r1 signature offsets from the instruction data.`,
     [MULTI_WALLET_ERROR__INVALID_SLOT_NUMBER]: `The specified slot number is not recent enough. Slot must be within the recent slot history window.`,
     [MULTI_WALLET_ERROR__INVALID_SYSVAR_DATA_FORMAT]: `Failed to parse the Slot History sysvar: data format is invalid or corrupted.`,
     [MULTI_WALLET_ERROR__INVALID_THRESHOLD]: `Invalid threshold: must be at least 1 and cannot exceed the number of voting-eligible members.`,
+    [MULTI_WALLET_ERROR__INVALID_TRANSACTION_EXPIRY]: `Invalid transaction expiry: it must be non-zero and within the expiry window.`,
     [MULTI_WALLET_ERROR__INVALID_TRANSACTION_MANAGER_CONFIG]: `User with Transaction Manager role requires a valid transaction manager url, be a ed25519 Signer and have no delegated wallet.`,
     [MULTI_WALLET_ERROR__INVALID_TRANSACTION_MANAGER_PERMISSION]: `Invalid Transaction Manager permissions. Only initiate is allowed.`,
     [MULTI_WALLET_ERROR__INVALID_TRANSACTION_MESSAGE]: `Malformed transaction message: structure or formatting does not match the expected layout.`,
@@ -263,6 +414,7 @@ if (process.env.NODE_ENV !== 'production') {
     [MULTI_WALLET_ERROR__INVALID_USER_TRANSACTION_MANAGER_CONFIG]: `Invalid user configuration. Transaction manager URL must be empty for non-transaction-manager users.`,
     [MULTI_WALLET_ERROR__MALFORMED_SIGNED_MESSAGE]: `The extracted WebAuthn signed message payload is malformed.`,
     [MULTI_WALLET_ERROR__MAX_LENGTH_EXCEEDED]: `An input string exceeds the maximum allowed character length.`,
+    [MULTI_WALLET_ERROR__MEMBER_KEY_TYPE_MISMATCH]: `A member key can only be rotated to a key of the same type.`,
     [MULTI_WALLET_ERROR__MEMBER_NOT_FOUND]: `Member not found in settings. Cannot remove a member that does not exist.`,
     [MULTI_WALLET_ERROR__MISSING_ACCOUNT]: `A required account is missing from the instruction context.`,
     [MULTI_WALLET_ERROR__MISSING_INSTRUCTIONS_SYSVAR]: `Missing instructions sysvar account. Required for secp256r1 signature verification.`,
@@ -277,17 +429,33 @@ if (process.env.NODE_ENV !== 'production') {
     [MULTI_WALLET_ERROR__PAYER_MISMATCH]: `Payer account mismatch. The provided payer does not match the transaction buffer payer.`,
     [MULTI_WALLET_ERROR__PERMANENT_MEMBER]: `Permanent members cannot be removed from a wallet.`,
     [MULTI_WALLET_ERROR__PROTECTED_ACCOUNT]: `Writable CPI calls to protected accounts are not permitted.`,
+    [MULTI_WALLET_ERROR__RECOVERY_DELAY_NOT_ELAPSED]: `The recovery delay has not elapsed yet.`,
+    [MULTI_WALLET_ERROR__RECOVERY_DELAY_TOO_SHORT]: `Recovery delay is below the minimum required while recovery is enabled.`,
+    [MULTI_WALLET_ERROR__RECOVERY_NOT_ENABLED]: `Recovery is not enabled for this wallet. Set a guardian threshold first.`,
+    [MULTI_WALLET_ERROR__RECOVERY_VETO_WINDOW_ELAPSED]: `The recovery delay has elapsed. It can no longer be vetoed.`,
     [MULTI_WALLET_ERROR__RP_ID_HASH_MISMATCH]: `The rpIdHash extracted from the signed message does not match the expected value.`,
+    [MULTI_WALLET_ERROR__SECP256K1_CHALLENGE_MISMATCH]: `The secp256k1 signed message is not the EIP-191 wrapping of the expected challenge for this action.`,
+    [MULTI_WALLET_ERROR__SECP256R1_CHALLENGE_MISMATCH]: `The raw secp256r1 signed message does not match the expected challenge.`,
     [MULTI_WALLET_ERROR__SETTINGS_KEY_MISMATCH]: `Settings key mismatch. The provided settings key does not match the expected value.`,
     [MULTI_WALLET_ERROR__SIGNATURE_INDEX_OUT_OF_BOUNDS]: `The signature index provided is out of bounds for the secp256r1 instruction.`,
+    [MULTI_WALLET_ERROR__SIGN_COUNT_NOT_INCREASED]: `The passkey signature counter did not increase, the key may have been cloned.`,
     [MULTI_WALLET_ERROR__SLOT_NUMBER_NOT_FOUND]: `The specified slot number was not found in the provided slot history.`,
     [MULTI_WALLET_ERROR__TOO_MANY_MEMBERS]: `Too many members: a maximum of 4 members are supported.`,
-    [MULTI_WALLET_ERROR__TRANSACTION_HAS_EXPIRED]: `This transaction has expired. It must be executed within 3 minutes of approval.`,
+    [MULTI_WALLET_ERROR__TRANSACTION_BATCH_STARTED]: `The transaction batch has started executing and can only run to completion.`,
+    [MULTI_WALLET_ERROR__TRANSACTION_HAS_EXPIRED]: `This transaction has expired. It must be executed within the wallet's transaction expiry window.`,
     [MULTI_WALLET_ERROR__TRANSACTION_NOT_APPROVED]: `This transaction has not yet reached the required approval threshold.`,
+    [MULTI_WALLET_ERROR__TRANSACTION_NOT_EXPIRED]: `This transaction has not expired and still matches the wallet configuration, so it cannot be closed without the creator's approval.`,
+    [MULTI_WALLET_ERROR__TRANSACTION_NOT_YET_EXECUTABLE]: `This transaction is scheduled and cannot be executed before its not-before time.`,
     [MULTI_WALLET_ERROR__TRANSPORTS_IS_MISSING]: `Missing transports.`,
     [MULTI_WALLET_ERROR__UNAUTHORISED_TO_CLOSE_TRANSACTION_BUFFER]: `Only the transaction creator or rent payer may close this transaction buffer.`,
     [MULTI_WALLET_ERROR__UNAUTHORIZED_ADMIN_ONLY]: `Unauthorized: Only the admin account is allowed to perform this action.`,
     [MULTI_WALLET_ERROR__UNEXPECTED_SIGNER]: `Member is not part of the expected signers for this transaction.`,
+    [MULTI_WALLET_ERROR__USER_ACCOUNT_KEY_MISMATCH]: `The user account is not the signer's writable user account.`,
+    [MULTI_WALLET_ERROR__USER_PRESENCE_REQUIRED]: `This domain requires user presence, but the assertion lacks the UP flag.`,
+    [MULTI_WALLET_ERROR__USER_VERIFICATION_REQUIRED]: `This domain requires user verification, but the assertion lacks the UV flag.`,
+    [MULTI_WALLET_ERROR__VAULT_TOKEN_ACCOUNT_NOT_EMPTY]: `A vault token account must be emptied before the wallet can be closed.`,
+    [MULTI_WALLET_ERROR__VOTER_NOT_FOUND]: `The signer has no vote on this transaction to withdraw.`,
+    [MULTI_WALLET_ERROR__WALLET_HAS_OPEN_ACCOUNTS]: `The wallet still has open transaction buffers, config proposals or a recovery.`,
   };
 }
 
@@ -15,6 +15,8 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
@@ -95,11 +97,15 @@ export type CreateDomainConfigInstructionData = {
   discriminator: ReadonlyUint8Array;
   rpId: string;
   origins: Array<string>;
+  requireUserPresence: boolean;
+  requireUserVerification: boolean;
 };
 
 export type CreateDomainConfigInstructionDataArgs = {
   rpId: string;
   origins: Array<string>;
+  requireUserPresence: boolean;
+  requireUserVerification: boolean;
 };
 
 export function getCreateDomainConfigInstructionDataEncoder(): Encoder<CreateDomainConfigInstructionDataArgs> {
@@ -113,6 +119,8 @@ export function getCreateDomainConfigInstructionDataEncoder(): Encoder<CreateDom
           addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())
         ),
       ],
+      ['requireUserPresence', getBooleanEncoder()],
+      ['requireUserVerification', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: CREATE_DOMAIN_CONFIG_DISCRIMINATOR })
   );
@@ -126,6 +134,8 @@ export function getCreateDomainConfigInstructionDataDecoder(): Decoder<CreateDom
       'origins',
       getArrayDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())),
     ],
+    ['requireUserPresence', getBooleanDecoder()],
+    ['requireUserVerification', getBooleanDecoder()],
   ]);
 }
 
@@ -157,6 +167,8 @@ export type CreateDomainConfigAsyncInput<
   userAccount?: Address<TAccountUserAccount>;
   rpId: CreateDomainConfigInstructionDataArgs['rpId'];
   origins: CreateDomainConfigInstructionDataArgs['origins'];
+  requireUserPresence: CreateDomainConfigInstructionDataArgs['requireUserPresence'];
+  requireUserVerification: CreateDomainConfigInstructionDataArgs['requireUserVerification'];
   remainingAccounts: CreateDomainConfigInstructionExtraArgs['remainingAccounts'];
 };
 
@@ -265,6 +277,8 @@ export type CreateDomainConfigInput<
   userAccount: Address<TAccountUserAccount>;
   rpId: CreateDomainConfigInstructionDataArgs['rpId'];
   origins: CreateDomainConfigInstructionDataArgs['origins'];
+  requireUserPresence: CreateDomainConfigInstructionDataArgs['requireUserPresence'];
+  requireUserVerification: CreateDomainConfigInstructionDataArgs['requireUserVerification'];
   remainingAccounts: CreateDomainConfigInstructionExtraArgs['remainingAccounts'];
 };
 
@@ -14,6 +14,8 @@ import {
   fixEncoderSize,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getOptionDecoder,
@@ -92,10 +94,14 @@ export type EditDomainConfigInstruction<
 export type EditDomainConfigInstructionData = {
   discriminator: ReadonlyUint8Array;
   newOrigins: Option<Array<string>>;
+  newRequireUserPresence: Option<boolean>;
+  newRequireUserVerification: Option<boolean>;
 };
 
 export type EditDomainConfigInstructionDataArgs = {
   newOrigins: OptionOrNullable<Array<string>>;
+  newRequireUserPresence: OptionOrNullable<boolean>;
+  newRequireUserVerification: OptionOrNullable<boolean>;
 };
 
 export function getEditDomainConfigInstructionDataEncoder(): Encoder<EditDomainConfigInstructionDataArgs> {
@@ -110,6 +116,8 @@ export function getEditDomainConfigInstructionDataEncoder(): Encoder<EditDomainC
           )
         ),
       ],
+      ['newRequireUserPresence', getOptionEncoder(getBooleanEncoder())],
+      ['newRequireUserVerification', getOptionEncoder(getBooleanEncoder())],
     ]),
     (value) => ({ ...value, discriminator: EDIT_DOMAIN_CONFIG_DISCRIMINATOR })
   );
@@ -124,6 +132,8 @@ export function getEditDomainConfigInstructionDataDecoder(): Decoder<EditDomainC
         getArrayDecoder(addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder()))
       ),
     ],
+    ['newRequireUserPresence', getOptionDecoder(getBooleanDecoder())],
+    ['newRequireUserVerification', getOptionDecoder(getBooleanDecoder())],
   ]);
 }
 
@@ -154,6 +164,8 @@ export type EditDomainConfigInput<
   userAccount?: Address<TAccountUserAccount>;
   systemProgram?: Address<TAccountSystemProgram>;
   newOrigins: EditDomainConfigInstructionDataArgs['newOrigins'];
+  newRequireUserPresence: EditDomainConfigInstructionDataArgs['newRequireUserPresence'];
+  newRequireUserVerification: EditDomainConfigInstructionDataArgs['newRequireUserVerification'];
   remainingAccounts: EditDomainConfigInstructionExtraArgs['remainingAccounts'];
 };
 
@@ -7,6 +7,11 @@
  */
 
 export * from './changeConfig';
+export * from './closeWallet';
+export * from './configProposalCancel';
+export * from './configProposalCloseStale';
+export * from './configProposalCreate';
+export * from './configProposalExecute';
 export * from './createDomainConfig';
 export * from './createDomainUserAccount';
 export * from './createGlobalCounter';
@@ -16,12 +21,21 @@ export * from './disableDomainConfig';
 export * from './editDomainConfig';
 export * from './editTransactionManagerUrl';
 export * from './editUserDelegate';
+export * from './migrateDomainConfig';
+export * from './migrateSettings';
+export * from './migrateUserAccount';
 export * from './nativeTransferIntent';
+export * from './recoveryExecute';
+export * from './recoveryInitiate';
+export * from './recoveryVeto';
 export * from './tokenTransferIntent';
 export * from './transactionBufferClose';
+export * from './transactionBufferCloseExpired';
 export * from './transactionBufferCreate';
 export * from './transactionBufferExecute';
 export * from './transactionBufferExtend';
+export * from './transactionBufferReject';
+export * from './transactionBufferUnvote';
 export * from './transactionBufferVote';
 export * from './transactionExecute';
 export * from './transactionExecuteSync';
@@ -10,16 +10,16 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
-  getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
-  getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type Address,
@@ -35,11 +35,7 @@ import {
 } from '@solana/kit';
 import { parseRemainingAccounts } from '../../hooked';
 import { MULTI_WALLET_PROGRAM_ADDRESS } from '../programs';
-import {
-  expectAddress,
-  getAccountMetaFactory,
-  type ResolvedAccount,
-} from '../shared';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
   getTransactionSyncSignersDecoder,
   getTransactionSyncSignersEncoder,
@@ -96,11 +92,13 @@ export type NativeTransferIntentInstruction<
 export type NativeTransferIntentInstructionData = {
   discriminator: ReadonlyUint8Array;
   amount: bigint;
+  vaultIndex: number;
   signers: Array<TransactionSyncSigners>;
 };
 
 export type NativeTransferIntentInstructionDataArgs = {
   amount: number | bigint;
+  vaultIndex: number;
   signers: Array<TransactionSyncSignersArgs>;
 };
 
@@ -109,6 +107,7 @@ export function getNativeTransferIntentInstructionDataEncoder(): Encoder<NativeT
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 1)],
       ['amount', getU64Encoder()],
+      ['vaultIndex', getU8Encoder()],
       ['signers', getArrayEncoder(getTransactionSyncSignersEncoder())],
     ]),
     (value) => ({
@@ -122,6 +121,7 @@ export function getNativeTransferIntentInstructionDataDecoder(): Decoder<NativeT
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
     ['amount', getU64Decoder()],
+    ['vaultIndex', getU8Decoder()],
     ['signers', getArrayDecoder(getTransactionSyncSignersDecoder())],
   ]);
 }
@@ -140,138 +140,6 @@ export type NativeTransferIntentInstructionExtraArgs = {
   remainingAccounts: Array<{ address: Address; role: number }>;
 };
 
-export type NativeTransferIntentAsyncInput<
-  TAccountSettings extends string = string,
-  TAccountSlotHashSysvar extends string = string,
-  TAccountInstructionsSysvar extends string = string,
-  TAccountSource extends string = string,
-  TAccountDestination extends string = string,
-  TAccountSystemProgram extends string = string,
-> = {
-  settings: Address<TAccountSettings>;
-  slotHashSysvar?: Address<TAccountSlotHashSysvar>;
-  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
-  source?: Address<TAccountSource>;
-  destination: Address<TAccountDestination>;
-  systemProgram?: Address<TAccountSystemProgram>;
-  amount: NativeTransferIntentInstructionDataArgs['amount'];
-  signers: NativeTransferIntentInstructionDataArgs['signers'];
-  remainingAccounts: NativeTransferIntentInstructionExtraArgs['remainingAccounts'];
-};
-
-export async function getNativeTransferIntentInstructionAsync<
-  TAccountSettings extends string,
-  TAccountSlotHashSysvar extends string,
-  TAccountInstructionsSysvar extends string,
-  TAccountSource extends string,
-  TAccountDestination extends string,
-  TAccountSystemProgram extends string,
-  TProgramAddress extends Address = typeof MULTI_WALLET_PROGRAM_ADDRESS,
->(
-  input: NativeTransferIntentAsyncInput<
-    TAccountSettings,
-    TAccountSlotHashSysvar,
-    TAccountInstructionsSysvar,
-    TAccountSource,
-    TAccountDestination,
-    TAccountSystemProgram
-  >,
-  config?: { programAddress?: TProgramAddress }
-): Promise<
-  NativeTransferIntentInstruction<
-    TProgramAddress,
-    TAccountSettings,
-    TAccountSlotHashSysvar,
-    TAccountInstructionsSysvar,
-    TAccountSource,
-    TAccountDestination,
-    TAccountSystemProgram
-  >
-> {
-  // Program address.
-  const programAddress = config?.programAddress ?? MULTI_WALLET_PROGRAM_ADDRESS;
-
-  // Original accounts.
-  const originalAccounts = {
-    settings: { value: input.settings ?? null, isWritable: true },
-    slotHashSysvar: { value: input.slotHashSysvar ?? null, isWritable: false },
-    instructionsSysvar: {
-      value: input.instructionsSysvar ?? null,
-      isWritable: false,
-    },
-    source: { value: input.source ?? null, isWritable: true },
-    destination: { value: input.destination ?? null, isWritable: true },
-    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
-  };
-  const accounts = originalAccounts as Record<
-    keyof typeof originalAccounts,
-    ResolvedAccount
-  >;
-
-  // Original args.
-  const args = { ...input };
-
-  // Resolver scope.
-  const resolverScope = { programAddress, accounts, args };
-
-  // Resolve default values.
-  if (!accounts.slotHashSysvar.value) {
-    accounts.slotHashSysvar.value =
-      'SysvarS1otHashes111111111111111111111111111' as Address<'SysvarS1otHashes111111111111111111111111111'>;
-  }
-  if (!accounts.instructionsSysvar.value) {
-    accounts.instructionsSysvar.value =
-      'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
-  }
-  if (!accounts.source.value) {
-    accounts.source.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
-          ])
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.settings.value)),
-        getBytesEncoder().encode(new Uint8Array([118, 97, 117, 108, 116])),
-      ],
-    });
-  }
-  if (!accounts.systemProgram.value) {
-    accounts.systemProgram.value =
-      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
-  }
-
-  // Remaining accounts.
-  const remainingAccounts: AccountMeta[] =
-    parseRemainingAccounts(resolverScope);
-
-  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
-  return Object.freeze({
-    accounts: [
-      getAccountMeta(accounts.settings),
-      getAccountMeta(accounts.slotHashSysvar),
-      getAccountMeta(accounts.instructionsSysvar),
-      getAccountMeta(accounts.source),
-      getAccountMeta(accounts.destination),
-      getAccountMeta(accounts.systemProgram),
-      ...remainingAccounts,
-    ],
-    data: getNativeTransferIntentInstructionDataEncoder().encode(
-      args as NativeTransferIntentInstructionDataArgs
-    ),
-    programAddress,
-  } as NativeTransferIntentInstruction<
-    TProgramAddress,
-    TAccountSettings,
-    TAccountSlotHashSysvar,
-    TAccountInstructionsSysvar,
-    TAccountSource,
-    TAccountDestination,
-    TAccountSystemProgram
-  >);
-}
-
 export type NativeTransferIntentInput<
   TAccountSettings extends string = string,
   TAccountSlotHashSysvar extends string = string,
@@ -287,6 +155,7 @@ export type NativeTransferIntentInput<
   destination: Address<TAccountDestination>;
   systemProgram?: Address<TAccountSystemProgram>;
   amount: NativeTransferIntentInstructionDataArgs['amount'];
+  vaultIndex: NativeTransferIntentInstructionDataArgs['vaultIndex'];
   signers: NativeTransferIntentInstructionDataArgs['signers'];
   remainingAccounts: NativeTransferIntentInstructionExtraArgs['remainingAccounts'];
 };
@@ -20,6 +20,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
@@ -127,11 +129,13 @@ export type TokenTransferIntentInstruction<
 export type TokenTransferIntentInstructionData = {
   discriminator: ReadonlyUint8Array;
   amount: bigint;
+  vaultIndex: number;
   signers: Array<TransactionSyncSigners>;
 };
 
 export type TokenTransferIntentInstructionDataArgs = {
   amount: number | bigint;
+  vaultIndex: number;
   signers: Array<TransactionSyncSignersArgs>;
 };
 
@@ -140,6 +144,7 @@ export function getTokenTransferIntentInstructionDataEncoder(): Encoder<TokenTra
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 1)],
       ['amount', getU64Encoder()],
+      ['vaultIndex', getU8Encoder()],
       ['signers', getArrayEncoder(getTransactionSyncSignersEncoder())],
     ]),
     (value) => ({
@@ -153,6 +158,7 @@ export function getTokenTransferIntentInstructionDataDecoder(): Decoder<TokenTra
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
     ['amount', getU64Decoder()],
+    ['vaultIndex', getU8Decoder()],
     ['signers', getArrayDecoder(getTransactionSyncSignersDecoder())],
   ]);
 }
@@ -189,7 +195,7 @@ export type TokenTransferIntentAsyncInput<
   payer: TransactionSigner<TAccountPayer>;
   slotHashSysvar?: Address<TAccountSlotHashSysvar>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
-  source?: Address<TAccountSource>;
+  source: Address<TAccountSource>;
   sourceSplTokenAccount?: Address<TAccountSourceSplTokenAccount>;
   destination: Address<TAccountDestination>;
   destinationSplTokenAccount?: Address<TAccountDestinationSplTokenAccount>;
@@ -198,6 +204,7 @@ export type TokenTransferIntentAsyncInput<
   mint: Address<TAccountMint>;
   systemProgram?: Address<TAccountSystemProgram>;
   amount: TokenTransferIntentInstructionDataArgs['amount'];
+  vaultIndex: TokenTransferIntentInstructionDataArgs['vaultIndex'];
   signers: TokenTransferIntentInstructionDataArgs['signers'];
   remainingAccounts: TokenTransferIntentInstructionExtraArgs['remainingAccounts'];
 };
@@ -299,20 +306,6 @@ export async function getTokenTransferIntentInstructionAsync<
     accounts.instructionsSysvar.value =
       'Sysvar1nstructions1111111111111111111111111' as Address<'Sysvar1nstructions1111111111111111111111111'>;
   }
-  if (!accounts.source.value) {
-    accounts.source.value = await getProgramDerivedAddress({
-      programAddress,
-      seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([
-            109, 117, 108, 116, 105, 95, 119, 97, 108, 108, 101, 116,
-          ])
-        ),
-        getAddressEncoder().encode(expectAddress(accounts.settings.value)),
-        getBytesEncoder().encode(new Uint8Array([118, 97, 117, 108, 116])),
-      ],
-    });
-  }
   if (!accounts.tokenProgram.value) {
     accounts.tokenProgram.value =
       'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
@@ -417,6 +410,7 @@ export type TokenTransferIntentInput<
   mint: Address<TAccountMint>;
   systemProgram?: Address<TAccountSystemProgram>;
   amount: TokenTransferIntentInstructionDataArgs['amount'];
+  vaultIndex: TokenTransferIntentInstructionDataArgs['vaultIndex'];
   signers: TokenTransferIntentInstructionDataArgs['signers'];
   remainingAccounts: TokenTransferIntentInstructionExtraArgs['remainingAccounts'];
 };
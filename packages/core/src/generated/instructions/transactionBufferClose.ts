@@ -38,8 +38,12 @@ import { parseRemainingAccounts } from '../../hooked';
 import { MULTI_WALLET_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
+  getSecp256k1VerifyArgsDecoder,
+  getSecp256k1VerifyArgsEncoder,
   getSecp256r1VerifyArgsDecoder,
   getSecp256r1VerifyArgsEncoder,
+  type Secp256k1VerifyArgs,
+  type Secp256k1VerifyArgsArgs,
   type Secp256r1VerifyArgs,
   type Secp256r1VerifyArgsArgs,
 } from '../types';
@@ -57,6 +61,7 @@ export type TransactionBufferCloseInstruction<
   TAccountSettings extends string | AccountMeta<string> = string,
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountDomainConfig extends string | AccountMeta<string> = string,
+  TAccountUserAccount extends string | AccountMeta<string> = string,
   TAccountTransactionBuffer extends string | AccountMeta<string> = string,
   TAccountCloser extends string | AccountMeta<string> = string,
   TAccountSlotHashSysvar extends string | AccountMeta<string> =
@@ -77,6 +82,9 @@ export type TransactionBufferCloseInstruction<
       TAccountDomainConfig extends string
         ? ReadonlyAccount<TAccountDomainConfig>
         : TAccountDomainConfig,
+      TAccountUserAccount extends string
+        ? WritableAccount<TAccountUserAccount>
+        : TAccountUserAccount,
       TAccountTransactionBuffer extends string
         ? WritableAccount<TAccountTransactionBuffer>
         : TAccountTransactionBuffer,
@@ -97,10 +105,12 @@ export type TransactionBufferCloseInstruction<
 export type TransactionBufferCloseInstructionData = {
   discriminator: ReadonlyUint8Array;
   secp256r1VerifyArgs: Option<Secp256r1VerifyArgs>;
+  secp256k1VerifyArgs: Option<Secp256k1VerifyArgs>;
 };
 
 export type TransactionBufferCloseInstructionDataArgs = {
   secp256r1VerifyArgs: OptionOrNullable<Secp256r1VerifyArgsArgs>;
+  secp256k1VerifyArgs: OptionOrNullable<Secp256k1VerifyArgsArgs>;
 };
 
 export function getTransactionBufferCloseInstructionDataEncoder(): Encoder<TransactionBufferCloseInstructionDataArgs> {
@@ -111,6 +121,10 @@ export function getTransactionBufferCloseInstructionDataEncoder(): Encoder<Trans
         'secp256r1VerifyArgs',
         getOptionEncoder(getSecp256r1VerifyArgsEncoder()),
       ],
+      [
+        'secp256k1VerifyArgs',
+        getOptionEncoder(getSecp256k1VerifyArgsEncoder()),
+      ],
     ]),
     (value) => ({
       ...value,
@@ -123,6 +137,7 @@ export function getTransactionBufferCloseInstructionDataDecoder(): Decoder<Trans
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
     ['secp256r1VerifyArgs', getOptionDecoder(getSecp256r1VerifyArgsDecoder())],
+    ['secp256k1VerifyArgs', getOptionDecoder(getSecp256k1VerifyArgsDecoder())],
   ]);
 }
 
@@ -144,6 +159,7 @@ export type TransactionBufferCloseInput<
   TAccountSettings extends string = string,
   TAccountPayer extends string = string,
   TAccountDomainConfig extends string = string,
+  TAccountUserAccount extends string = string,
   TAccountTransactionBuffer extends string = string,
   TAccountCloser extends string = string,
   TAccountSlotHashSysvar extends string = string,
@@ -152,11 +168,13 @@ export type TransactionBufferCloseInput<
   settings: Address<TAccountSettings>;
   payer: Address<TAccountPayer>;
   domainConfig?: Address<TAccountDomainConfig>;
+  userAccount?: Address<TAccountUserAccount>;
   transactionBuffer: Address<TAccountTransactionBuffer>;
   closer?: TransactionSigner<TAccountCloser>;
   slotHashSysvar?: Address<TAccountSlotHashSysvar>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
   secp256r1VerifyArgs: TransactionBufferCloseInstructionDataArgs['secp256r1VerifyArgs'];
+  secp256k1VerifyArgs: TransactionBufferCloseInstructionDataArgs['secp256k1VerifyArgs'];
   remainingAccounts: TransactionBufferCloseInstructionExtraArgs['remainingAccounts'];
 };
 
@@ -164,6 +182,7 @@ export function getTransactionBufferCloseInstruction<
   TAccountSettings extends string,
   TAccountPayer extends string,
   TAccountDomainConfig extends string,
+  TAccountUserAccount extends string,
   TAccountTransactionBuffer extends string,
   TAccountCloser extends string,
   TAccountSlotHashSysvar extends string,
@@ -174,6 +193,7 @@ export function getTransactionBufferCloseInstruction<
     TAccountSettings,
     TAccountPayer,
     TAccountDomainConfig,
+    TAccountUserAccount,
     TAccountTransactionBuffer,
     TAccountCloser,
     TAccountSlotHashSysvar,
@@ -185,6 +205,7 @@ export function getTransactionBufferCloseInstruction<
   TAccountSettings,
   TAccountPayer,
   TAccountDomainConfig,
+  TAccountUserAccount,
   TAccountTransactionBuffer,
   TAccountCloser,
   TAccountSlotHashSysvar,
@@ -198,6 +219,7 @@ export function getTransactionBufferCloseInstruction<
     settings: { value: input.settings ?? null, isWritable: true },
     payer: { value: input.payer ?? null, isWritable: true },
     domainConfig: { value: input.domainConfig ?? null, isWritable: false },
+    userAccount: { value: input.userAccount ?? null, isWritable: true },
     transactionBuffer: {
       value: input.transactionBuffer ?? null,
       isWritable: true,
@@ -240,6 +262,7 @@ export function getTransactionBufferCloseInstruction<
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.domainConfig),
+      getAccountMeta(accounts.userAccount),
       getAccountMeta(accounts.transactionBuffer),
       getAccountMeta(accounts.closer),
       getAccountMeta(accounts.slotHashSysvar),
@@ -255,6 +278,7 @@ export function getTransactionBufferCloseInstruction<
     TAccountSettings,
     TAccountPayer,
     TAccountDomainConfig,
+    TAccountUserAccount,
     TAccountTransactionBuffer,
     TAccountCloser,
     TAccountSlotHashSysvar,
@@ -271,10 +295,11 @@ export type ParsedTransactionBufferCloseInstruction<
     settings: TAccountMetas[0];
     payer: TAccountMetas[1];
     domainConfig?: TAccountMetas[2] | undefined;
-    transactionBuffer: TAccountMetas[3];
-    closer?: TAccountMetas[4] | undefined;
-    slotHashSysvar?: TAccountMetas[5] | undefined;
-    instructionsSysvar?: TAccountMetas[6] | undefined;
+    userAccount?: TAccountMetas[3] | undefined;
+    transactionBuffer: TAccountMetas[4];
+    closer?: TAccountMetas[5] | undefined;
+    slotHashSysvar?: TAccountMetas[6] | undefined;
+    instructionsSysvar?: TAccountMetas[7] | undefined;
   };
   data: TransactionBufferCloseInstructionData;
 };
@@ -287,7 +312,7 @@ export function parseTransactionBufferCloseInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTransactionBufferCloseInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -309,6 +334,7 @@ export function parseTransactionBufferCloseInstruction<
       settings: getNextAccount(),
       payer: getNextAccount(),
       domainConfig: getNextOptionalAccount(),
+      userAccount: getNextOptionalAccount(),
       transactionBuffer: getNextAccount(),
       closer: getNextOptionalAccount(),
       slotHashSysvar: getNextOptionalAccount(),
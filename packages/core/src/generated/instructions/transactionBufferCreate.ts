@@ -20,8 +20,10 @@ import {
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
-  getU16Decoder,
-  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -49,10 +51,18 @@ import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
   getExpectedSignerDecoder,
   getExpectedSignerEncoder,
+  getExpiryModeDecoder,
+  getExpiryModeEncoder,
+  getSecp256k1VerifyArgsDecoder,
+  getSecp256k1VerifyArgsEncoder,
   getSecp256r1VerifyArgsDecoder,
   getSecp256r1VerifyArgsEncoder,
   type ExpectedSigner,
   type ExpectedSignerArgs,
+  type ExpiryMode,
+  type ExpiryModeArgs,
+  type Secp256k1VerifyArgs,
+  type Secp256k1VerifyArgsArgs,
   type Secp256r1VerifyArgs,
   type Secp256r1VerifyArgsArgs,
 } from '../types';
@@ -70,6 +80,7 @@ export type TransactionBufferCreateInstruction<
   TAccountSettings extends string | AccountMeta<string> = string,
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountDomainConfig extends string | AccountMeta<string> = string,
+  TAccountUserAccount extends string | AccountMeta<string> = string,
   TAccountTransactionBuffer extends string | AccountMeta<string> = string,
   TAccountCreator extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
@@ -84,7 +95,7 @@ export type TransactionBufferCreateInstruction<
   InstructionWithAccounts<
     [
       TAccountSettings extends string
-        ? ReadonlyAccount<TAccountSettings>
+        ? WritableAccount<TAccountSettings>
         : TAccountSettings,
       TAccountPayer extends string
         ? WritableSignerAccount<TAccountPayer> &
@@ -93,6 +104,9 @@ export type TransactionBufferCreateInstruction<
       TAccountDomainConfig extends string
         ? ReadonlyAccount<TAccountDomainConfig>
         : TAccountDomainConfig,
+      TAccountUserAccount extends string
+        ? WritableAccount<TAccountUserAccount>
+        : TAccountUserAccount,
       TAccountTransactionBuffer extends string
         ? WritableAccount<TAccountTransactionBuffer>
         : TAccountTransactionBuffer,
@@ -115,42 +129,73 @@ export type TransactionBufferCreateInstruction<
 
 export type TransactionBufferCreateInstructionData = {
   discriminator: ReadonlyUint8Array;
-  bufferIndex: number;
+  vaultIndex: number;
+  /** Number of vault transaction messages stored back to back in the buffer, executed in order. */
+  messageCount: number;
   preauthorizeExecution: boolean;
   bufferExtendHashes: Array<ReadonlyUint8Array>;
+  /** Size of every chunk but the last, which holds the remainder of the final buffer. */
+  chunkSize: number;
   finalBufferHash: ReadonlyUint8Array;
   finalBufferSize: number;
   expectedSigners: Array<ExpectedSigner>;
+  /** Expiry window in seconds, shorter than the wallet's. Defaults to the wallet's window. */
+  expiry: Option<bigint>;
+  /** Time before which the transaction cannot be executed, in the unit of `expiry_mode`. The expiry window starts from it. */
+  notBefore: Option<bigint>;
+  /** Clock `valid_till` and `not_before` are measured against. */
+  expiryMode: ExpiryMode;
   secp256r1VerifyArgs: Option<Secp256r1VerifyArgs>;
+  secp256k1VerifyArgs: Option<Secp256k1VerifyArgs>;
 };
 
 export type TransactionBufferCreateInstructionDataArgs = {
-  bufferIndex: number;
+  vaultIndex: number;
+  /** Number of vault transaction messages stored back to back in the buffer, executed in order. */
+  messageCount: number;
   preauthorizeExecution: boolean;
   bufferExtendHashes: Array<ReadonlyUint8Array>;
+  /** Size of every chunk but the last, which holds the remainder of the final buffer. */
+  chunkSize: number;
   finalBufferHash: ReadonlyUint8Array;
   finalBufferSize: number;
   expectedSigners: Array<ExpectedSignerArgs>;
+  /** Expiry window in seconds, shorter than the wallet's. Defaults to the wallet's window. */
+  expiry: OptionOrNullable<number | bigint>;
+  /** Time before which the transaction cannot be executed, in the unit of `expiry_mode`. The expiry window starts from it. */
+  notBefore: OptionOrNullable<number | bigint>;
+  /** Clock `valid_till` and `not_before` are measured against. */
+  expiryMode: ExpiryModeArgs;
   secp256r1VerifyArgs: OptionOrNullable<Secp256r1VerifyArgsArgs>;
+  secp256k1VerifyArgs: OptionOrNullable<Secp256k1VerifyArgsArgs>;
 };
 
 export function getTransactionBufferCreateInstructionDataEncoder(): Encoder<TransactionBufferCreateInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 1)],
-      ['bufferIndex', getU8Encoder()],
+      ['vaultIndex', getU8Encoder()],
+      ['messageCount', getU8Encoder()],
       ['preauthorizeExecution', getBooleanEncoder()],
       [
         'bufferExtendHashes',
         getArrayEncoder(fixEncoderSize(getBytesEncoder(), 32)),
       ],
+      ['chunkSize', getU32Encoder()],
       ['finalBufferHash', fixEncoderSize(getBytesEncoder(), 32)],
-      ['finalBufferSize', getU16Encoder()],
+      ['finalBufferSize', getU32Encoder()],
       ['expectedSigners', getArrayEncoder(getExpectedSignerEncoder())],
+      ['expiry', getOptionEncoder(getU64Encoder())],
+      ['notBefore', getOptionEncoder(getU64Encoder())],
+      ['expiryMode', getExpiryModeEncoder()],
       [
         'secp256r1VerifyArgs',
         getOptionEncoder(getSecp256r1VerifyArgsEncoder()),
       ],
+      [
+        'secp256k1VerifyArgs',
+        getOptionEncoder(getSecp256k1VerifyArgsEncoder()),
+      ],
     ]),
     (value) => ({
       ...value,
@@ -162,16 +207,22 @@ export function getTransactionBufferCreateInstructionDataEncoder(): Encoder<Tran
 export function getTransactionBufferCreateInstructionDataDecoder(): Decoder<TransactionBufferCreateInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
-    ['bufferIndex', getU8Decoder()],
+    ['vaultIndex', getU8Decoder()],
+    ['messageCount', getU8Decoder()],
     ['preauthorizeExecution', getBooleanDecoder()],
     [
       'bufferExtendHashes',
       getArrayDecoder(fixDecoderSize(getBytesDecoder(), 32)),
     ],
+    ['chunkSize', getU32Decoder()],
     ['finalBufferHash', fixDecoderSize(getBytesDecoder(), 32)],
-    ['finalBufferSize', getU16Decoder()],
+    ['finalBufferSize', getU32Decoder()],
     ['expectedSigners', getArrayDecoder(getExpectedSignerDecoder())],
+    ['expiry', getOptionDecoder(getU64Decoder())],
+    ['notBefore', getOptionDecoder(getU64Decoder())],
+    ['expiryMode', getExpiryModeDecoder()],
     ['secp256r1VerifyArgs', getOptionDecoder(getSecp256r1VerifyArgsDecoder())],
+    ['secp256k1VerifyArgs', getOptionDecoder(getSecp256k1VerifyArgsDecoder())],
   ]);
 }
 
@@ -193,6 +244,7 @@ export type TransactionBufferCreateInput<
   TAccountSettings extends string = string,
   TAccountPayer extends string = string,
   TAccountDomainConfig extends string = string,
+  TAccountUserAccount extends string = string,
   TAccountTransactionBuffer extends string = string,
   TAccountCreator extends string = string,
   TAccountSystemProgram extends string = string,
@@ -202,18 +254,25 @@ export type TransactionBufferCreateInput<
   settings: Address<TAccountSettings>;
   payer: TransactionSigner<TAccountPayer>;
   domainConfig?: Address<TAccountDomainConfig>;
+  userAccount?: Address<TAccountUserAccount>;
   transactionBuffer: Address<TAccountTransactionBuffer>;
   creator?: TransactionSigner<TAccountCreator>;
   systemProgram?: Address<TAccountSystemProgram>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
   slotHashSysvar?: Address<TAccountSlotHashSysvar>;
-  bufferIndex: TransactionBufferCreateInstructionDataArgs['bufferIndex'];
+  vaultIndex: TransactionBufferCreateInstructionDataArgs['vaultIndex'];
+  messageCount: TransactionBufferCreateInstructionDataArgs['messageCount'];
   preauthorizeExecution: TransactionBufferCreateInstructionDataArgs['preauthorizeExecution'];
   bufferExtendHashes: TransactionBufferCreateInstructionDataArgs['bufferExtendHashes'];
+  chunkSize: TransactionBufferCreateInstructionDataArgs['chunkSize'];
   finalBufferHash: TransactionBufferCreateInstructionDataArgs['finalBufferHash'];
   finalBufferSize: TransactionBufferCreateInstructionDataArgs['finalBufferSize'];
   expectedSigners: TransactionBufferCreateInstructionDataArgs['expectedSigners'];
+  expiry: TransactionBufferCreateInstructionDataArgs['expiry'];
+  notBefore: TransactionBufferCreateInstructionDataArgs['notBefore'];
+  expiryMode: TransactionBufferCreateInstructionDataArgs['expiryMode'];
   secp256r1VerifyArgs: TransactionBufferCreateInstructionDataArgs['secp256r1VerifyArgs'];
+  secp256k1VerifyArgs: TransactionBufferCreateInstructionDataArgs['secp256k1VerifyArgs'];
   remainingAccounts: TransactionBufferCreateInstructionExtraArgs['remainingAccounts'];
 };
 
@@ -221,6 +280,7 @@ export function getTransactionBufferCreateInstruction<
   TAccountSettings extends string,
   TAccountPayer extends string,
   TAccountDomainConfig extends string,
+  TAccountUserAccount extends string,
   TAccountTransactionBuffer extends string,
   TAccountCreator extends string,
   TAccountSystemProgram extends string,
@@ -232,6 +292,7 @@ export function getTransactionBufferCreateInstruction<
     TAccountSettings,
     TAccountPayer,
     TAccountDomainConfig,
+    TAccountUserAccount,
     TAccountTransactionBuffer,
     TAccountCreator,
     TAccountSystemProgram,
@@ -244,6 +305,7 @@ export function getTransactionBufferCreateInstruction<
   TAccountSettings,
   TAccountPayer,
   TAccountDomainConfig,
+  TAccountUserAccount,
   TAccountTransactionBuffer,
   TAccountCreator,
   TAccountSystemProgram,
@@ -255,9 +317,10 @@ export function getTransactionBufferCreateInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    settings: { value: input.settings ?? null, isWritable: false },
+    settings: { value: input.settings ?? null, isWritable: true },
     payer: { value: input.payer ?? null, isWritable: true },
     domainConfig: { value: input.domainConfig ?? null, isWritable: false },
+    userAccount: { value: input.userAccount ?? null, isWritable: true },
     transactionBuffer: {
       value: input.transactionBuffer ?? null,
       isWritable: true,
@@ -305,6 +368,7 @@ export function getTransactionBufferCreateInstruction<
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.domainConfig),
+      getAccountMeta(accounts.userAccount),
       getAccountMeta(accounts.transactionBuffer),
       getAccountMeta(accounts.creator),
       getAccountMeta(accounts.systemProgram),
@@ -321,6 +385,7 @@ export function getTransactionBufferCreateInstruction<
     TAccountSettings,
     TAccountPayer,
     TAccountDomainConfig,
+    TAccountUserAccount,
     TAccountTransactionBuffer,
     TAccountCreator,
     TAccountSystemProgram,
@@ -338,11 +403,12 @@ export type ParsedTransactionBufferCreateInstruction<
     settings: TAccountMetas[0];
     payer: TAccountMetas[1];
     domainConfig?: TAccountMetas[2] | undefined;
-    transactionBuffer: TAccountMetas[3];
-    creator?: TAccountMetas[4] | undefined;
-    systemProgram: TAccountMetas[5];
-    instructionsSysvar: TAccountMetas[6];
-    slotHashSysvar?: TAccountMetas[7] | undefined;
+    userAccount?: TAccountMetas[3] | undefined;
+    transactionBuffer: TAccountMetas[4];
+    creator?: TAccountMetas[5] | undefined;
+    systemProgram: TAccountMetas[6];
+    instructionsSysvar: TAccountMetas[7];
+    slotHashSysvar?: TAccountMetas[8] | undefined;
   };
   data: TransactionBufferCreateInstructionData;
 };
@@ -355,7 +421,7 @@ export function parseTransactionBufferCreateInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTransactionBufferCreateInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 9) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -377,6 +443,7 @@ export function parseTransactionBufferCreateInstruction<
       settings: getNextAccount(),
       payer: getNextAccount(),
       domainConfig: getNextOptionalAccount(),
+      userAccount: getNextOptionalAccount(),
       transactionBuffer: getNextAccount(),
       creator: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
@@ -38,8 +38,12 @@ import { parseRemainingAccounts } from '../../hooked';
 import { MULTI_WALLET_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
+  getSecp256k1VerifyArgsDecoder,
+  getSecp256k1VerifyArgsEncoder,
   getSecp256r1VerifyArgsDecoder,
   getSecp256r1VerifyArgsEncoder,
+  type Secp256k1VerifyArgs,
+  type Secp256k1VerifyArgsArgs,
   type Secp256r1VerifyArgs,
   type Secp256r1VerifyArgsArgs,
 } from '../types';
@@ -56,6 +60,7 @@ export type TransactionBufferExecuteInstruction<
   TProgram extends string = typeof MULTI_WALLET_PROGRAM_ADDRESS,
   TAccountSettings extends string | AccountMeta<string> = string,
   TAccountDomainConfig extends string | AccountMeta<string> = string,
+  TAccountUserAccount extends string | AccountMeta<string> = string,
   TAccountExecutor extends string | AccountMeta<string> = string,
   TAccountTransactionBuffer extends string | AccountMeta<string> = string,
   TAccountSlotHashSysvar extends string | AccountMeta<string> =
@@ -73,6 +78,9 @@ export type TransactionBufferExecuteInstruction<
       TAccountDomainConfig extends string
         ? ReadonlyAccount<TAccountDomainConfig>
         : TAccountDomainConfig,
+      TAccountUserAccount extends string
+        ? WritableAccount<TAccountUserAccount>
+        : TAccountUserAccount,
       TAccountExecutor extends string
         ? ReadonlySignerAccount<TAccountExecutor> &
             AccountSignerMeta<TAccountExecutor>
@@ -93,10 +101,12 @@ export type TransactionBufferExecuteInstruction<
 export type TransactionBufferExecuteInstructionData = {
   discriminator: ReadonlyUint8Array;
   secp256r1VerifyArgs: Option<Secp256r1VerifyArgs>;
+  secp256k1VerifyArgs: Option<Secp256k1VerifyArgs>;
 };
 
 export type TransactionBufferExecuteInstructionDataArgs = {
   secp256r1VerifyArgs: OptionOrNullable<Secp256r1VerifyArgsArgs>;
+  secp256k1VerifyArgs: OptionOrNullable<Secp256k1VerifyArgsArgs>;
 };
 
 export function getTransactionBufferExecuteInstructionDataEncoder(): Encoder<TransactionBufferExecuteInstructionDataArgs> {
@@ -107,6 +117,10 @@ export function getTransactionBufferExecuteInstructionDataEncoder(): Encoder<Tra
         'secp256r1VerifyArgs',
         getOptionEncoder(getSecp256r1VerifyArgsEncoder()),
       ],
+      [
+        'secp256k1VerifyArgs',
+        getOptionEncoder(getSecp256k1VerifyArgsEncoder()),
+      ],
     ]),
     (value) => ({
       ...value,
@@ -119,6 +133,7 @@ export function getTransactionBufferExecuteInstructionDataDecoder(): Decoder<Tra
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
     ['secp256r1VerifyArgs', getOptionDecoder(getSecp256r1VerifyArgsDecoder())],
+    ['secp256k1VerifyArgs', getOptionDecoder(getSecp256k1VerifyArgsDecoder())],
   ]);
 }
 
@@ -139,6 +154,7 @@ export type TransactionBufferExecuteInstructionExtraArgs = {
 export type TransactionBufferExecuteInput<
   TAccountSettings extends string = string,
   TAccountDomainConfig extends string = string,
+  TAccountUserAccount extends string = string,
   TAccountExecutor extends string = string,
   TAccountTransactionBuffer extends string = string,
   TAccountSlotHashSysvar extends string = string,
@@ -146,17 +162,20 @@ export type TransactionBufferExecuteInput<
 > = {
   settings: Address<TAccountSettings>;
   domainConfig?: Address<TAccountDomainConfig>;
+  userAccount?: Address<TAccountUserAccount>;
   executor?: TransactionSigner<TAccountExecutor>;
   transactionBuffer: Address<TAccountTransactionBuffer>;
   slotHashSysvar?: Address<TAccountSlotHashSysvar>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
   secp256r1VerifyArgs: TransactionBufferExecuteInstructionDataArgs['secp256r1VerifyArgs'];
+  secp256k1VerifyArgs: TransactionBufferExecuteInstructionDataArgs['secp256k1VerifyArgs'];
   remainingAccounts: TransactionBufferExecuteInstructionExtraArgs['remainingAccounts'];
 };
 
 export function getTransactionBufferExecuteInstruction<
   TAccountSettings extends string,
   TAccountDomainConfig extends string,
+  TAccountUserAccount extends string,
   TAccountExecutor extends string,
   TAccountTransactionBuffer extends string,
   TAccountSlotHashSysvar extends string,
@@ -166,6 +185,7 @@ export function getTransactionBufferExecuteInstruction<
   input: TransactionBufferExecuteInput<
     TAccountSettings,
     TAccountDomainConfig,
+    TAccountUserAccount,
     TAccountExecutor,
     TAccountTransactionBuffer,
     TAccountSlotHashSysvar,
@@ -176,6 +196,7 @@ export function getTransactionBufferExecuteInstruction<
   TProgramAddress,
   TAccountSettings,
   TAccountDomainConfig,
+  TAccountUserAccount,
   TAccountExecutor,
   TAccountTransactionBuffer,
   TAccountSlotHashSysvar,
@@ -188,6 +209,7 @@ export function getTransactionBufferExecuteInstruction<
   const originalAccounts = {
     settings: { value: input.settings ?? null, isWritable: true },
     domainConfig: { value: input.domainConfig ?? null, isWritable: false },
+    userAccount: { value: input.userAccount ?? null, isWritable: true },
     executor: { value: input.executor ?? null, isWritable: false },
     transactionBuffer: {
       value: input.transactionBuffer ?? null,
@@ -229,6 +251,7 @@ export function getTransactionBufferExecuteInstruction<
     accounts: [
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.domainConfig),
+      getAccountMeta(accounts.userAccount),
       getAccountMeta(accounts.executor),
       getAccountMeta(accounts.transactionBuffer),
       getAccountMeta(accounts.slotHashSysvar),
@@ -243,6 +266,7 @@ export function getTransactionBufferExecuteInstruction<
     TProgramAddress,
     TAccountSettings,
     TAccountDomainConfig,
+    TAccountUserAccount,
     TAccountExecutor,
     TAccountTransactionBuffer,
     TAccountSlotHashSysvar,
@@ -258,10 +282,11 @@ export type ParsedTransactionBufferExecuteInstruction<
   accounts: {
     settings: TAccountMetas[0];
     domainConfig?: TAccountMetas[1] | undefined;
-    executor?: TAccountMetas[2] | undefined;
-    transactionBuffer: TAccountMetas[3];
-    slotHashSysvar?: TAccountMetas[4] | undefined;
-    instructionsSysvar?: TAccountMetas[5] | undefined;
+    userAccount?: TAccountMetas[2] | undefined;
+    executor?: TAccountMetas[3] | undefined;
+    transactionBuffer: TAccountMetas[4];
+    slotHashSysvar?: TAccountMetas[5] | undefined;
+    instructionsSysvar?: TAccountMetas[6] | undefined;
   };
   data: TransactionBufferExecuteInstructionData;
 };
@@ -274,7 +299,7 @@ export function parseTransactionBufferExecuteInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTransactionBufferExecuteInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -295,6 +320,7 @@ export function parseTransactionBufferExecuteInstruction<
     accounts: {
       settings: getNextAccount(),
       domainConfig: getNextOptionalAccount(),
+      userAccount: getNextOptionalAccount(),
       executor: getNextOptionalAccount(),
       transactionBuffer: getNextAccount(),
       slotHashSysvar: getNextOptionalAccount(),
@@ -16,10 +16,13 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
   getU32Decoder,
   getU32Encoder,
   transformEncoder,
   type AccountMeta,
+  type AccountSignerMeta,
   type Address,
   type Codec,
   type Decoder,
@@ -29,7 +32,9 @@ import {
   type InstructionWithData,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { parseRemainingAccounts } from '../../hooked';
 import { MULTI_WALLET_PROGRAM_ADDRESS } from '../programs';
@@ -46,7 +51,10 @@ export function getTransactionBufferExtendDiscriminatorBytes() {
 export type TransactionBufferExtendInstruction<
   TProgram extends string = typeof MULTI_WALLET_PROGRAM_ADDRESS,
   TAccountSettings extends string | AccountMeta<string> = string,
+  TAccountPayer extends string | AccountMeta<string> = string,
   TAccountTransactionBuffer extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> =
+    '11111111111111111111111111111111',
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -55,19 +63,28 @@ export type TransactionBufferExtendInstruction<
       TAccountSettings extends string
         ? ReadonlyAccount<TAccountSettings>
         : TAccountSettings,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            AccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
       TAccountTransactionBuffer extends string
         ? WritableAccount<TAccountTransactionBuffer>
         : TAccountTransactionBuffer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
 
 export type TransactionBufferExtendInstructionData = {
   discriminator: ReadonlyUint8Array;
+  chunkIndex: number;
   buffer: ReadonlyUint8Array;
 };
 
 export type TransactionBufferExtendInstructionDataArgs = {
+  chunkIndex: number;
   buffer: ReadonlyUint8Array;
 };
 
@@ -75,6 +92,7 @@ export function getTransactionBufferExtendInstructionDataEncoder(): Encoder<Tran
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 1)],
+      ['chunkIndex', getU16Encoder()],
       ['buffer', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ]),
     (value) => ({
@@ -87,6 +105,7 @@ export function getTransactionBufferExtendInstructionDataEncoder(): Encoder<Tran
 export function getTransactionBufferExtendInstructionDataDecoder(): Decoder<TransactionBufferExtendInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
+    ['chunkIndex', getU16Decoder()],
     ['buffer', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
   ]);
 }
@@ -107,28 +126,39 @@ export type TransactionBufferExtendInstructionExtraArgs = {
 
 export type TransactionBufferExtendInput<
   TAccountSettings extends string = string,
+  TAccountPayer extends string = string,
   TAccountTransactionBuffer extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   settings: Address<TAccountSettings>;
+  payer: TransactionSigner<TAccountPayer>;
   transactionBuffer: Address<TAccountTransactionBuffer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  chunkIndex: TransactionBufferExtendInstructionDataArgs['chunkIndex'];
   buffer: TransactionBufferExtendInstructionDataArgs['buffer'];
   remainingAccounts: TransactionBufferExtendInstructionExtraArgs['remainingAccounts'];
 };
 
 export function getTransactionBufferExtendInstruction<
   TAccountSettings extends string,
+  TAccountPayer extends string,
   TAccountTransactionBuffer extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof MULTI_WALLET_PROGRAM_ADDRESS,
 >(
   input: TransactionBufferExtendInput<
     TAccountSettings,
-    TAccountTransactionBuffer
+    TAccountPayer,
+    TAccountTransactionBuffer,
+    TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): TransactionBufferExtendInstruction<
   TProgramAddress,
   TAccountSettings,
-  TAccountTransactionBuffer
+  TAccountPayer,
+  TAccountTransactionBuffer,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? MULTI_WALLET_PROGRAM_ADDRESS;
@@ -136,10 +166,12 @@ export function getTransactionBufferExtendInstruction<
   // Original accounts.
   const originalAccounts = {
     settings: { value: input.settings ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
     transactionBuffer: {
       value: input.transactionBuffer ?? null,
       isWritable: true,
     },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -152,6 +184,12 @@ export function getTransactionBufferExtendInstruction<
   // Resolver scope.
   const resolverScope = { programAddress, accounts, args };
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
   // Remaining accounts.
   const remainingAccounts: AccountMeta[] =
     parseRemainingAccounts(resolverScope);
@@ -160,7 +198,9 @@ export function getTransactionBufferExtendInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.settings),
+      getAccountMeta(accounts.payer),
       getAccountMeta(accounts.transactionBuffer),
+      getAccountMeta(accounts.systemProgram),
       ...remainingAccounts,
     ],
     data: getTransactionBufferExtendInstructionDataEncoder().encode(
@@ -170,7 +210,9 @@ export function getTransactionBufferExtendInstruction<
   } as TransactionBufferExtendInstruction<
     TProgramAddress,
     TAccountSettings,
-    TAccountTransactionBuffer
+    TAccountPayer,
+    TAccountTransactionBuffer,
+    TAccountSystemProgram
   >);
 }
 
@@ -181,7 +223,9 @@ export type ParsedTransactionBufferExtendInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     settings: TAccountMetas[0];
-    transactionBuffer: TAccountMetas[1];
+    payer: TAccountMetas[1];
+    transactionBuffer: TAccountMetas[2];
+    systemProgram: TAccountMetas[3];
   };
   data: TransactionBufferExtendInstructionData;
 };
@@ -194,7 +238,7 @@ export function parseTransactionBufferExtendInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTransactionBufferExtendInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -208,7 +252,9 @@ export function parseTransactionBufferExtendInstruction<
     programAddress: instruction.programAddress,
     accounts: {
       settings: getNextAccount(),
+      payer: getNextAccount(),
       transactionBuffer: getNextAccount(),
+      systemProgram: getNextAccount(),
     },
     data: getTransactionBufferExtendInstructionDataDecoder().decode(
       instruction.data
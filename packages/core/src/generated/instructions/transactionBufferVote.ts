@@ -38,8 +38,12 @@ import { parseRemainingAccounts } from '../../hooked';
 import { MULTI_WALLET_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
+  getSecp256k1VerifyArgsDecoder,
+  getSecp256k1VerifyArgsEncoder,
   getSecp256r1VerifyArgsDecoder,
   getSecp256r1VerifyArgsEncoder,
+  type Secp256k1VerifyArgs,
+  type Secp256k1VerifyArgsArgs,
   type Secp256r1VerifyArgs,
   type Secp256r1VerifyArgsArgs,
 } from '../types';
@@ -56,6 +60,7 @@ export type TransactionBufferVoteInstruction<
   TProgram extends string = typeof MULTI_WALLET_PROGRAM_ADDRESS,
   TAccountSettings extends string | AccountMeta<string> = string,
   TAccountDomainConfig extends string | AccountMeta<string> = string,
+  TAccountUserAccount extends string | AccountMeta<string> = string,
   TAccountTransactionBuffer extends string | AccountMeta<string> = string,
   TAccountVoter extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends string | AccountMeta<string> =
@@ -70,11 +75,14 @@ export type TransactionBufferVoteInstruction<
   InstructionWithAccounts<
     [
       TAccountSettings extends string
-        ? ReadonlyAccount<TAccountSettings>
+        ? WritableAccount<TAccountSettings>
         : TAccountSettings,
       TAccountDomainConfig extends string
         ? ReadonlyAccount<TAccountDomainConfig>
         : TAccountDomainConfig,
+      TAccountUserAccount extends string
+        ? WritableAccount<TAccountUserAccount>
+        : TAccountUserAccount,
       TAccountTransactionBuffer extends string
         ? WritableAccount<TAccountTransactionBuffer>
         : TAccountTransactionBuffer,
@@ -98,10 +106,12 @@ export type TransactionBufferVoteInstruction<
 export type TransactionBufferVoteInstructionData = {
   discriminator: ReadonlyUint8Array;
   secp256r1VerifyArgs: Option<Secp256r1VerifyArgs>;
+  secp256k1VerifyArgs: Option<Secp256k1VerifyArgs>;
 };
 
 export type TransactionBufferVoteInstructionDataArgs = {
   secp256r1VerifyArgs: OptionOrNullable<Secp256r1VerifyArgsArgs>;
+  secp256k1VerifyArgs: OptionOrNullable<Secp256k1VerifyArgsArgs>;
 };
 
 export function getTransactionBufferVoteInstructionDataEncoder(): Encoder<TransactionBufferVoteInstructionDataArgs> {
@@ -112,6 +122,10 @@ export function getTransactionBufferVoteInstructionDataEncoder(): Encoder<Transa
         'secp256r1VerifyArgs',
         getOptionEncoder(getSecp256r1VerifyArgsEncoder()),
       ],
+      [
+        'secp256k1VerifyArgs',
+        getOptionEncoder(getSecp256k1VerifyArgsEncoder()),
+      ],
     ]),
     (value) => ({
       ...value,
@@ -124,6 +138,7 @@ export function getTransactionBufferVoteInstructionDataDecoder(): Decoder<Transa
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 1)],
     ['secp256r1VerifyArgs', getOptionDecoder(getSecp256r1VerifyArgsDecoder())],
+    ['secp256k1VerifyArgs', getOptionDecoder(getSecp256k1VerifyArgsDecoder())],
   ]);
 }
 
@@ -144,6 +159,7 @@ export type TransactionBufferVoteInstructionExtraArgs = {
 export type TransactionBufferVoteInput<
   TAccountSettings extends string = string,
   TAccountDomainConfig extends string = string,
+  TAccountUserAccount extends string = string,
   TAccountTransactionBuffer extends string = string,
   TAccountVoter extends string = string,
   TAccountSystemProgram extends string = string,
@@ -152,18 +168,21 @@ export type TransactionBufferVoteInput<
 > = {
   settings: Address<TAccountSettings>;
   domainConfig?: Address<TAccountDomainConfig>;
+  userAccount?: Address<TAccountUserAccount>;
   transactionBuffer: Address<TAccountTransactionBuffer>;
   voter?: TransactionSigner<TAccountVoter>;
   systemProgram?: Address<TAccountSystemProgram>;
   slotHashSysvar?: Address<TAccountSlotHashSysvar>;
   instructionsSysvar?: Address<TAccountInstructionsSysvar>;
   secp256r1VerifyArgs: TransactionBufferVoteInstructionDataArgs['secp256r1VerifyArgs'];
+  secp256k1VerifyArgs: TransactionBufferVoteInstructionDataArgs['secp256k1VerifyArgs'];
   remainingAccounts: TransactionBufferVoteInstructionExtraArgs['remainingAccounts'];
 };
 
 export function getTransactionBufferVoteInstruction<
   TAccountSettings extends string,
   TAccountDomainConfig extends string,
+  TAccountUserAccount extends string,
   TAccountTransactionBuffer extends string,
   TAccountVoter extends string,
   TAccountSystemProgram extends string,
@@ -174,6 +193,7 @@ export function getTransactionBufferVoteInstruction<
   input: TransactionBufferVoteInput<
     TAccountSettings,
     TAccountDomainConfig,
+    TAccountUserAccount,
     TAccountTransactionBuffer,
     TAccountVoter,
     TAccountSystemProgram,
@@ -185,6 +205,7 @@ export function getTransactionBufferVoteInstruction<
   TProgramAddress,
   TAccountSettings,
   TAccountDomainConfig,
+  TAccountUserAccount,
   TAccountTransactionBuffer,
   TAccountVoter,
   TAccountSystemProgram,
@@ -196,8 +217,9 @@ export function getTransactionBufferVoteInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    settings: { value: input.settings ?? null, isWritable: false },
+    settings: { value: input.settings ?? null, isWritable: true },
     domainConfig: { value: input.domainConfig ?? null, isWritable: false },
+    userAccount: { value: input.userAccount ?? null, isWritable: true },
     transactionBuffer: {
       value: input.transactionBuffer ?? null,
       isWritable: true,
@@ -244,6 +266,7 @@ export function getTransactionBufferVoteInstruction<
     accounts: [
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.domainConfig),
+      getAccountMeta(accounts.userAccount),
       getAccountMeta(accounts.transactionBuffer),
       getAccountMeta(accounts.voter),
       getAccountMeta(accounts.systemProgram),
@@ -259,6 +282,7 @@ export function getTransactionBufferVoteInstruction<
     TProgramAddress,
     TAccountSettings,
     TAccountDomainConfig,
+    TAccountUserAccount,
     TAccountTransactionBuffer,
     TAccountVoter,
     TAccountSystemProgram,
@@ -275,11 +299,12 @@ export type ParsedTransactionBufferVoteInstruction<
   accounts: {
     settings: TAccountMetas[0];
     domainConfig?: TAccountMetas[1] | undefined;
-    transactionBuffer: TAccountMetas[2];
-    voter?: TAccountMetas[3] | undefined;
-    systemProgram: TAccountMetas[4];
-    slotHashSysvar?: TAccountMetas[5] | undefined;
-    instructionsSysvar?: TAccountMetas[6] | undefined;
+    userAccount?: TAccountMetas[2] | undefined;
+    transactionBuffer: TAccountMetas[3];
+    voter?: TAccountMetas[4] | undefined;
+    systemProgram: TAccountMetas[5];
+    slotHashSysvar?: TAccountMetas[6] | undefined;
+    instructionsSysvar?: TAccountMetas[7] | undefined;
   };
   data: TransactionBufferVoteInstructionData;
 };
@@ -292,7 +317,7 @@ export function parseTransactionBufferVoteInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedTransactionBufferVoteInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -313,6 +338,7 @@ export function parseTransactionBufferVoteInstruction<
     accounts: {
       settings: getNextAccount(),
       domainConfig: getNextOptionalAccount(),
+      userAccount: getNextOptionalAccount(),
       transactionBuffer: getNextAccount(),
       voter: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
@@ -90,6 +90,7 @@ export type TransactionExecuteSyncInstructionData = {
   numAccountKeys: number;
   instructions: Array<CompiledInstruction>;
   addressTableLookups: Array<TransactionMessageAddressTableLookup>;
+  vaultIndex: number;
   signers: Array<TransactionSyncSigners>;
 };
 
@@ -100,6 +101,7 @@ export type TransactionExecuteSyncInstructionDataArgs = {
   numAccountKeys: number;
   instructions: Array<CompiledInstructionArgs>;
   addressTableLookups: Array<TransactionMessageAddressTableLookupArgs>;
+  vaultIndex: number;
   signers: Array<TransactionSyncSignersArgs>;
 };
 
@@ -116,6 +118,7 @@ export function getTransactionExecuteSyncInstructionDataEncoder(): Encoder<Trans
         'addressTableLookups',
         getArrayEncoder(getTransactionMessageAddressTableLookupEncoder()),
       ],
+      ['vaultIndex', getU8Encoder()],
       ['signers', getArrayEncoder(getTransactionSyncSignersEncoder())],
     ]),
     (value) => ({
@@ -137,6 +140,7 @@ export function getTransactionExecuteSyncInstructionDataDecoder(): Decoder<Trans
       'addressTableLookups',
       getArrayDecoder(getTransactionMessageAddressTableLookupDecoder()),
     ],
+    ['vaultIndex', getU8Decoder()],
     ['signers', getArrayDecoder(getTransactionSyncSignersDecoder())],
   ]);
 }
@@ -169,6 +173,7 @@ export type TransactionExecuteSyncInput<
   numAccountKeys: TransactionExecuteSyncInstructionDataArgs['numAccountKeys'];
   instructions: TransactionExecuteSyncInstructionDataArgs['instructions'];
   addressTableLookups: TransactionExecuteSyncInstructionDataArgs['addressTableLookups'];
+  vaultIndex: TransactionExecuteSyncInstructionDataArgs['vaultIndex'];
   signers: TransactionExecuteSyncInstructionDataArgs['signers'];
   remainingAccounts: TransactionExecuteSyncInstructionExtraArgs['remainingAccounts'];
 };
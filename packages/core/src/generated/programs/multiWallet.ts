@@ -15,6 +15,11 @@ import {
 } from '@solana/kit';
 import {
   type ParsedChangeConfigInstruction,
+  type ParsedCloseWalletInstruction,
+  type ParsedConfigProposalCancelInstruction,
+  type ParsedConfigProposalCloseStaleInstruction,
+  type ParsedConfigProposalCreateInstruction,
+  type ParsedConfigProposalExecuteInstruction,
   type ParsedCreateDomainConfigInstruction,
   type ParsedCreateDomainUserAccountInstruction,
   type ParsedCreateGlobalCounterInstruction,
@@ -24,12 +29,21 @@ import {
   type ParsedEditDomainConfigInstruction,
   type ParsedEditTransactionManagerUrlInstruction,
   type ParsedEditUserDelegateInstruction,
+  type ParsedMigrateDomainConfigInstruction,
+  type ParsedMigrateSettingsInstruction,
+  type ParsedMigrateUserAccountInstruction,
   type ParsedNativeTransferIntentInstruction,
+  type ParsedRecoveryExecuteInstruction,
+  type ParsedRecoveryInitiateInstruction,
+  type ParsedRecoveryVetoInstruction,
   type ParsedTokenTransferIntentInstruction,
+  type ParsedTransactionBufferCloseExpiredInstruction,
   type ParsedTransactionBufferCloseInstruction,
   type ParsedTransactionBufferCreateInstruction,
   type ParsedTransactionBufferExecuteInstruction,
   type ParsedTransactionBufferExtendInstruction,
+  type ParsedTransactionBufferRejectInstruction,
+  type ParsedTransactionBufferUnvoteInstruction,
   type ParsedTransactionBufferVoteInstruction,
   type ParsedTransactionExecuteInstruction,
   type ParsedTransactionExecuteSyncInstruction,
@@ -39,8 +53,10 @@ export const MULTI_WALLET_PROGRAM_ADDRESS =
   'reviR1xysEChySVSWGa43a6oJ2boJYTJhwRjo8KJhhT' as Address<'reviR1xysEChySVSWGa43a6oJ2boJYTJhwRjo8KJhhT'>;
 
 export enum MultiWalletAccount {
+  ConfigProposal,
   DomainConfig,
   GlobalCounter,
+  Recovery,
   Settings,
   TransactionBuffer,
   User,
@@ -50,6 +66,17 @@ export function identifyMultiWalletAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): MultiWalletAccount {
   const data = 'data' in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([159, 239, 163, 63, 154, 191, 78, 98])
+      ),
+      0
+    )
+  ) {
+    return MultiWalletAccount.ConfigProposal;
+  }
   if (
     containsBytes(
       data,
@@ -72,6 +99,17 @@ export function identifyMultiWalletAccount(
   ) {
     return MultiWalletAccount.GlobalCounter;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([242, 166, 34, 227, 71, 217, 155, 103])
+      ),
+      0
+    )
+  ) {
+    return MultiWalletAccount.Recovery;
+  }
   if (
     containsBytes(
       data,
@@ -112,6 +150,11 @@ export function identifyMultiWalletAccount(
 
 export enum MultiWalletInstruction {
   ChangeConfig,
+  CloseWallet,
+  ConfigProposalCancel,
+  ConfigProposalCloseStale,
+  ConfigProposalCreate,
+  ConfigProposalExecute,
   CreateDomainConfig,
   CreateDomainUserAccount,
   CreateGlobalCounter,
@@ -121,12 +164,21 @@ export enum MultiWalletInstruction {
   EditDomainConfig,
   EditTransactionManagerUrl,
   EditUserDelegate,
+  MigrateDomainConfig,
+  MigrateSettings,
+  MigrateUserAccount,
   NativeTransferIntent,
+  RecoveryExecute,
+  RecoveryInitiate,
+  RecoveryVeto,
   TokenTransferIntent,
   TransactionBufferClose,
+  TransactionBufferCloseExpired,
   TransactionBufferCreate,
   TransactionBufferExecute,
   TransactionBufferExtend,
+  TransactionBufferReject,
+  TransactionBufferUnvote,
   TransactionBufferVote,
   TransactionExecute,
   TransactionExecuteSync,
@@ -145,6 +197,51 @@ export function identifyMultiWalletInstruction(
   ) {
     return MultiWalletInstruction.ChangeConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([25])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.CloseWallet;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([21])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.ConfigProposalCancel;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([32])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.ConfigProposalCloseStale;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([19])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.ConfigProposalCreate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([20])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.ConfigProposalExecute;
+  }
   if (
     containsBytes(
       data,
@@ -226,6 +323,33 @@ export function identifyMultiWalletInstruction(
   ) {
     return MultiWalletInstruction.EditUserDelegate;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([29])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.MigrateDomainConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([31])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.MigrateSettings;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([30])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.MigrateUserAccount;
+  }
   if (
     containsBytes(
       data,
@@ -235,6 +359,33 @@ export function identifyMultiWalletInstruction(
   ) {
     return MultiWalletInstruction.NativeTransferIntent;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([23])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.RecoveryExecute;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([22])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.RecoveryInitiate;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([24])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.RecoveryVeto;
+  }
   if (
     containsBytes(
       data,
@@ -253,6 +404,15 @@ export function identifyMultiWalletInstruction(
   ) {
     return MultiWalletInstruction.TransactionBufferClose;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([28])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.TransactionBufferCloseExpired;
+  }
   if (
     containsBytes(
       data,
@@ -280,6 +440,24 @@ export function identifyMultiWalletInstruction(
   ) {
     return MultiWalletInstruction.TransactionBufferExtend;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([26])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.TransactionBufferReject;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 1).encode(new Uint8Array([27])),
+      0
+    )
+  ) {
+    return MultiWalletInstruction.TransactionBufferUnvote;
+  }
   if (
     containsBytes(
       data,
@@ -318,6 +496,21 @@ export type ParsedMultiWalletInstruction<
   | ({
       instructionType: MultiWalletInstruction.ChangeConfig;
     } & ParsedChangeConfigInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.CloseWallet;
+    } & ParsedCloseWalletInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.ConfigProposalCancel;
+    } & ParsedConfigProposalCancelInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.ConfigProposalCloseStale;
+    } & ParsedConfigProposalCloseStaleInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.ConfigProposalCreate;
+    } & ParsedConfigProposalCreateInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.ConfigProposalExecute;
+    } & ParsedConfigProposalExecuteInstruction<TProgram>)
   | ({
       instructionType: MultiWalletInstruction.CreateDomainConfig;
     } & ParsedCreateDomainConfigInstruction<TProgram>)
@@ -345,15 +538,36 @@ export type ParsedMultiWalletInstruction<
   | ({
       instructionType: MultiWalletInstruction.EditUserDelegate;
     } & ParsedEditUserDelegateInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.MigrateDomainConfig;
+    } & ParsedMigrateDomainConfigInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.MigrateSettings;
+    } & ParsedMigrateSettingsInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.MigrateUserAccount;
+    } & ParsedMigrateUserAccountInstruction<TProgram>)
   | ({
       instructionType: MultiWalletInstruction.NativeTransferIntent;
     } & ParsedNativeTransferIntentInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.RecoveryExecute;
+    } & ParsedRecoveryExecuteInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.RecoveryInitiate;
+    } & ParsedRecoveryInitiateInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.RecoveryVeto;
+    } & ParsedRecoveryVetoInstruction<TProgram>)
   | ({
       instructionType: MultiWalletInstruction.TokenTransferIntent;
     } & ParsedTokenTransferIntentInstruction<TProgram>)
   | ({
       instructionType: MultiWalletInstruction.TransactionBufferClose;
     } & ParsedTransactionBufferCloseInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.TransactionBufferCloseExpired;
+    } & ParsedTransactionBufferCloseExpiredInstruction<TProgram>)
   | ({
       instructionType: MultiWalletInstruction.TransactionBufferCreate;
     } & ParsedTransactionBufferCreateInstruction<TProgram>)
@@ -363,6 +577,12 @@ export type ParsedMultiWalletInstruction<
   | ({
       instructionType: MultiWalletInstruction.TransactionBufferExtend;
     } & ParsedTransactionBufferExtendInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.TransactionBufferReject;
+    } & ParsedTransactionBufferRejectInstruction<TProgram>)
+  | ({
+      instructionType: MultiWalletInstruction.TransactionBufferUnvote;
+    } & ParsedTransactionBufferUnvoteInstruction<TProgram>)
   | ({
       instructionType: MultiWalletInstruction.TransactionBufferVote;
     } & ParsedTransactionBufferVoteInstruction<TProgram>)
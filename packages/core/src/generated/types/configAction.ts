@@ -16,6 +16,8 @@ import {
   getStructEncoder,
   getTupleDecoder,
   getTupleEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   type Codec,
@@ -25,16 +27,32 @@ import {
   type GetDiscriminatedUnionVariantContent,
 } from '@solana/kit';
 import {
+  getActionThresholdsDecoder,
+  getActionThresholdsEncoder,
   getAddMemberArgsDecoder,
   getAddMemberArgsEncoder,
   getEditMemberArgsDecoder,
   getEditMemberArgsEncoder,
+  getEditMemberWeightArgsDecoder,
+  getEditMemberWeightArgsEncoder,
+  getMemberKeyDecoder,
+  getMemberKeyEncoder,
+  getRecoveryConfigDecoder,
+  getRecoveryConfigEncoder,
   getRemoveMemberArgsDecoder,
   getRemoveMemberArgsEncoder,
+  type ActionThresholds,
+  type ActionThresholdsArgs,
   type AddMemberArgs,
   type AddMemberArgsArgs,
   type EditMemberArgs,
   type EditMemberArgsArgs,
+  type EditMemberWeightArgs,
+  type EditMemberWeightArgsArgs,
+  type MemberKey,
+  type MemberKeyArgs,
+  type RecoveryConfig,
+  type RecoveryConfigArgs,
   type RemoveMemberArgs,
   type RemoveMemberArgsArgs,
 } from '.';
@@ -43,13 +61,28 @@ export type ConfigAction =
   | { __kind: 'EditPermissions'; fields: readonly [Array<EditMemberArgs>] }
   | { __kind: 'AddMembers'; fields: readonly [Array<AddMemberArgs>] }
   | { __kind: 'RemoveMembers'; fields: readonly [Array<RemoveMemberArgs>] }
-  | { __kind: 'SetThreshold'; fields: readonly [number] };
+  | { __kind: 'SetThreshold'; fields: readonly [number] }
+  | { __kind: 'EditWeights'; fields: readonly [Array<EditMemberWeightArgs>] }
+  | { __kind: 'SetActionThresholds'; fields: readonly [ActionThresholds] }
+  | { __kind: 'SetConfigTimelock'; fields: readonly [bigint] }
+  | { __kind: 'SetRecoveryConfig'; fields: readonly [RecoveryConfig] }
+  | { __kind: 'RotateMemberKey'; old: MemberKey; new: MemberKey }
+  | { __kind: 'SetTransactionExpiry'; fields: readonly [bigint] };
 
 export type ConfigActionArgs =
   | { __kind: 'EditPermissions'; fields: readonly [Array<EditMemberArgsArgs>] }
   | { __kind: 'AddMembers'; fields: readonly [Array<AddMemberArgsArgs>] }
   | { __kind: 'RemoveMembers'; fields: readonly [Array<RemoveMemberArgsArgs>] }
-  | { __kind: 'SetThreshold'; fields: readonly [number] };
+  | { __kind: 'SetThreshold'; fields: readonly [number] }
+  | {
+      __kind: 'EditWeights';
+      fields: readonly [Array<EditMemberWeightArgsArgs>];
+    }
+  | { __kind: 'SetActionThresholds'; fields: readonly [ActionThresholdsArgs] }
+  | { __kind: 'SetConfigTimelock'; fields: readonly [number | bigint] }
+  | { __kind: 'SetRecoveryConfig'; fields: readonly [RecoveryConfigArgs] }
+  | { __kind: 'RotateMemberKey'; old: MemberKeyArgs; new: MemberKeyArgs }
+  | { __kind: 'SetTransactionExpiry'; fields: readonly [number | bigint] };
 
 export function getConfigActionEncoder(): Encoder<ConfigActionArgs> {
   return getDiscriminatedUnionEncoder([
@@ -84,6 +117,42 @@ export function getConfigActionEncoder(): Encoder<ConfigActionArgs> {
       'SetThreshold',
       getStructEncoder([['fields', getTupleEncoder([getU8Encoder()])]]),
     ],
+    [
+      'EditWeights',
+      getStructEncoder([
+        [
+          'fields',
+          getTupleEncoder([getArrayEncoder(getEditMemberWeightArgsEncoder())]),
+        ],
+      ]),
+    ],
+    [
+      'SetActionThresholds',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getActionThresholdsEncoder()])],
+      ]),
+    ],
+    [
+      'SetConfigTimelock',
+      getStructEncoder([['fields', getTupleEncoder([getU64Encoder()])]]),
+    ],
+    [
+      'SetRecoveryConfig',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getRecoveryConfigEncoder()])],
+      ]),
+    ],
+    [
+      'RotateMemberKey',
+      getStructEncoder([
+        ['old', getMemberKeyEncoder()],
+        ['new', getMemberKeyEncoder()],
+      ]),
+    ],
+    [
+      'SetTransactionExpiry',
+      getStructEncoder([['fields', getTupleEncoder([getU64Encoder()])]]),
+    ],
   ]);
 }
 
@@ -120,6 +189,42 @@ export function getConfigActionDecoder(): Decoder<ConfigAction> {
       'SetThreshold',
       getStructDecoder([['fields', getTupleDecoder([getU8Decoder()])]]),
     ],
+    [
+      'EditWeights',
+      getStructDecoder([
+        [
+          'fields',
+          getTupleDecoder([getArrayDecoder(getEditMemberWeightArgsDecoder())]),
+        ],
+      ]),
+    ],
+    [
+      'SetActionThresholds',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getActionThresholdsDecoder()])],
+      ]),
+    ],
+    [
+      'SetConfigTimelock',
+      getStructDecoder([['fields', getTupleDecoder([getU64Decoder()])]]),
+    ],
+    [
+      'SetRecoveryConfig',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getRecoveryConfigDecoder()])],
+      ]),
+    ],
+    [
+      'RotateMemberKey',
+      getStructDecoder([
+        ['old', getMemberKeyDecoder()],
+        ['new', getMemberKeyDecoder()],
+      ]),
+    ],
+    [
+      'SetTransactionExpiry',
+      getStructDecoder([['fields', getTupleDecoder([getU64Decoder()])]]),
+    ],
   ]);
 }
 
@@ -160,6 +265,70 @@ export function configAction(
     'SetThreshold'
   >['fields']
 ): GetDiscriminatedUnionVariant<ConfigActionArgs, '__kind', 'SetThreshold'>;
+export function configAction(
+  kind: 'EditWeights',
+  data: GetDiscriminatedUnionVariantContent<
+    ConfigActionArgs,
+    '__kind',
+    'EditWeights'
+  >['fields']
+): GetDiscriminatedUnionVariant<ConfigActionArgs, '__kind', 'EditWeights'>;
+export function configAction(
+  kind: 'SetActionThresholds',
+  data: GetDiscriminatedUnionVariantContent<
+    ConfigActionArgs,
+    '__kind',
+    'SetActionThresholds'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  ConfigActionArgs,
+  '__kind',
+  'SetActionThresholds'
+>;
+export function configAction(
+  kind: 'SetConfigTimelock',
+  data: GetDiscriminatedUnionVariantContent<
+    ConfigActionArgs,
+    '__kind',
+    'SetConfigTimelock'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  ConfigActionArgs,
+  '__kind',
+  'SetConfigTimelock'
+>;
+export function configAction(
+  kind: 'SetRecoveryConfig',
+  data: GetDiscriminatedUnionVariantContent<
+    ConfigActionArgs,
+    '__kind',
+    'SetRecoveryConfig'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  ConfigActionArgs,
+  '__kind',
+  'SetRecoveryConfig'
+>;
+export function configAction(
+  kind: 'RotateMemberKey',
+  data: GetDiscriminatedUnionVariantContent<
+    ConfigActionArgs,
+    '__kind',
+    'RotateMemberKey'
+  >
+): GetDiscriminatedUnionVariant<ConfigActionArgs, '__kind', 'RotateMemberKey'>;
+export function configAction(
+  kind: 'SetTransactionExpiry',
+  data: GetDiscriminatedUnionVariantContent<
+    ConfigActionArgs,
+    '__kind',
+    'SetTransactionExpiry'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  ConfigActionArgs,
+  '__kind',
+  'SetTransactionExpiry'
+>;
 export function configAction<K extends ConfigActionArgs['__kind'], Data>(
   kind: K,
   data?: Data
@@ -6,15 +6,21 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './actionThresholds';
 export * from './addMemberArgs';
 export * from './compiledInstruction';
 export * from './configAction';
+export * from './ed25519VerifyArgs';
 export * from './editMemberArgs';
+export * from './editMemberWeightArgs';
 export * from './expectedSigner';
+export * from './expiryMode';
 export * from './member';
 export * from './memberKey';
 export * from './permissions';
+export * from './recoveryConfig';
 export * from './removeMemberArgs';
+export * from './secp256k1VerifyArgs';
 export * from './secp256r1Pubkey';
 export * from './secp256r1VerifyArgs';
 export * from './secp256r1VerifyArgsWithDomainConfigIndex';
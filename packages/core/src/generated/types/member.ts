@@ -33,6 +33,7 @@ export type Member = {
   permissions: IPermissions;
   padding: number;
   isDelegate: number;
+  weight: number;
 };
 
 export type MemberArgs = {
@@ -41,6 +42,7 @@ export type MemberArgs = {
   permissions: PermissionsArgs;
   padding: number;
   isDelegate: number;
+  weight: number;
 };
 
 export function getMemberEncoder(): FixedSizeEncoder<MemberArgs> {
@@ -50,6 +52,7 @@ export function getMemberEncoder(): FixedSizeEncoder<MemberArgs> {
     ['permissions', getPermissionsEncoder()],
     ['padding', getU8Encoder()],
     ['isDelegate', getU8Encoder()],
+    ['weight', getU8Encoder()],
   ]);
 }
 
@@ -60,6 +63,7 @@ export function getMemberDecoder(): FixedSizeDecoder<Member> {
     ['permissions', getPermissionsDecoder()],
     ['padding', getU8Decoder()],
     ['isDelegate', getU8Decoder()],
+    ['weight', getU8Decoder()],
   ]);
 }
 
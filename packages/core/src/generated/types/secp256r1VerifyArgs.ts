@@ -31,25 +31,40 @@ import {
 } from '@solana/kit';
 
 export type Secp256r1VerifyArgs = {
+  /** Absolute index of the secp256r1 instruction in the transaction. */
+  instructionIndex: number;
   signedMessageIndex: number;
   slotNumber: bigint;
   originIndex: number;
   crossOrigin: boolean;
   truncatedClientDataJson: ReadonlyUint8Array;
   clientAndDeviceHash: ReadonlyUint8Array;
+  /**
+   * Plain P-256 signature over the slot-bound challenge instead of a WebAuthn assertion. The
+   * signer resolves to a `KeyType::Secp256r1Raw` member, so passkeys cannot use this mode.
+   */
+  raw: boolean;
 };
 
 export type Secp256r1VerifyArgsArgs = {
+  /** Absolute index of the secp256r1 instruction in the transaction. */
+  instructionIndex: number;
   signedMessageIndex: number;
   slotNumber: number | bigint;
   originIndex: number;
   crossOrigin: boolean;
   truncatedClientDataJson: ReadonlyUint8Array;
   clientAndDeviceHash: ReadonlyUint8Array;
+  /**
+   * Plain P-256 signature over the slot-bound challenge instead of a WebAuthn assertion. The
+   * signer resolves to a `KeyType::Secp256r1Raw` member, so passkeys cannot use this mode.
+   */
+  raw: boolean;
 };
 
 export function getSecp256r1VerifyArgsEncoder(): Encoder<Secp256r1VerifyArgsArgs> {
   return getStructEncoder([
+    ['instructionIndex', getU8Encoder()],
     ['signedMessageIndex', getU8Encoder()],
     ['slotNumber', getU64Encoder()],
     ['originIndex', getU8Encoder()],
@@ -59,11 +74,13 @@ export function getSecp256r1VerifyArgsEncoder(): Encoder<Secp256r1VerifyArgsArgs
       addEncoderSizePrefix(getBytesEncoder(), getU32Encoder()),
     ],
     ['clientAndDeviceHash', fixEncoderSize(getBytesEncoder(), 32)],
+    ['raw', getBooleanEncoder()],
   ]);
 }
 
 export function getSecp256r1VerifyArgsDecoder(): Decoder<Secp256r1VerifyArgs> {
   return getStructDecoder([
+    ['instructionIndex', getU8Decoder()],
     ['signedMessageIndex', getU8Decoder()],
     ['slotNumber', getU64Decoder()],
     ['originIndex', getU8Decoder()],
@@ -73,6 +90,7 @@ export function getSecp256r1VerifyArgsDecoder(): Decoder<Secp256r1VerifyArgs> {
       addDecoderSizePrefix(getBytesDecoder(), getU32Decoder()),
     ],
     ['clientAndDeviceHash', fixDecoderSize(getBytesDecoder(), 32)],
+    ['raw', getBooleanDecoder()],
   ]);
 }
 
@@ -26,17 +26,22 @@ import {
 export type Secp256r1VerifyArgsWithDomainConfigIndex = {
   verifyArgs: Secp256r1VerifyArgs;
   domainConfigIndex: number;
+  /** Index of the signer's user account in the remaining accounts, which tracks the passkey signature counter. */
+  userAccountIndex: number;
 };
 
 export type Secp256r1VerifyArgsWithDomainConfigIndexArgs = {
   verifyArgs: Secp256r1VerifyArgsArgs;
   domainConfigIndex: number;
+  /** Index of the signer's user account in the remaining accounts, which tracks the passkey signature counter. */
+  userAccountIndex: number;
 };
 
 export function getSecp256r1VerifyArgsWithDomainConfigIndexEncoder(): Encoder<Secp256r1VerifyArgsWithDomainConfigIndexArgs> {
   return getStructEncoder([
     ['verifyArgs', getSecp256r1VerifyArgsEncoder()],
     ['domainConfigIndex', getU8Encoder()],
+    ['userAccountIndex', getU8Encoder()],
   ]);
 }
 
@@ -44,6 +49,7 @@ export function getSecp256r1VerifyArgsWithDomainConfigIndexDecoder(): Decoder<Se
   return getStructDecoder([
     ['verifyArgs', getSecp256r1VerifyArgsDecoder()],
     ['domainConfigIndex', getU8Decoder()],
+    ['userAccountIndex', getU8Decoder()],
   ]);
 }
 
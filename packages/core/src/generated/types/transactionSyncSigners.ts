@@ -23,8 +23,16 @@ import {
   type GetDiscriminatedUnionVariantContent,
 } from '@solana/kit';
 import {
+  getEd25519VerifyArgsDecoder,
+  getEd25519VerifyArgsEncoder,
+  getSecp256k1VerifyArgsDecoder,
+  getSecp256k1VerifyArgsEncoder,
   getSecp256r1VerifyArgsWithDomainConfigIndexDecoder,
   getSecp256r1VerifyArgsWithDomainConfigIndexEncoder,
+  type Ed25519VerifyArgs,
+  type Ed25519VerifyArgsArgs,
+  type Secp256k1VerifyArgs,
+  type Secp256k1VerifyArgsArgs,
   type Secp256r1VerifyArgsWithDomainConfigIndex,
   type Secp256r1VerifyArgsWithDomainConfigIndexArgs,
 } from '.';
@@ -34,14 +42,18 @@ export type TransactionSyncSigners =
   | {
       __kind: 'Secp256r1';
       fields: readonly [Secp256r1VerifyArgsWithDomainConfigIndex];
-    };
+    }
+  | { __kind: 'Ed25519Precompile'; fields: readonly [Ed25519VerifyArgs] }
+  | { __kind: 'Secp256k1'; fields: readonly [Secp256k1VerifyArgs] };
 
 export type TransactionSyncSignersArgs =
   | { __kind: 'Ed25519'; fields: readonly [number] }
   | {
       __kind: 'Secp256r1';
       fields: readonly [Secp256r1VerifyArgsWithDomainConfigIndexArgs];
-    };
+    }
+  | { __kind: 'Ed25519Precompile'; fields: readonly [Ed25519VerifyArgsArgs] }
+  | { __kind: 'Secp256k1'; fields: readonly [Secp256k1VerifyArgsArgs] };
 
 export function getTransactionSyncSignersEncoder(): Encoder<TransactionSyncSignersArgs> {
   return getDiscriminatedUnionEncoder([
@@ -60,6 +72,18 @@ export function getTransactionSyncSignersEncoder(): Encoder<TransactionSyncSigne
         ],
       ]),
     ],
+    [
+      'Ed25519Precompile',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getEd25519VerifyArgsEncoder()])],
+      ]),
+    ],
+    [
+      'Secp256k1',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getSecp256k1VerifyArgsEncoder()])],
+      ]),
+    ],
   ]);
 }
 
@@ -80,6 +104,18 @@ export function getTransactionSyncSignersDecoder(): Decoder<TransactionSyncSigne
         ],
       ]),
     ],
+    [
+      'Ed25519Precompile',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getEd25519VerifyArgsDecoder()])],
+      ]),
+    ],
+    [
+      'Secp256k1',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getSecp256k1VerifyArgsDecoder()])],
+      ]),
+    ],
   ]);
 }
 
@@ -118,6 +154,30 @@ export function transactionSyncSigners(
   '__kind',
   'Secp256r1'
 >;
+export function transactionSyncSigners(
+  kind: 'Ed25519Precompile',
+  data: GetDiscriminatedUnionVariantContent<
+    TransactionSyncSignersArgs,
+    '__kind',
+    'Ed25519Precompile'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  TransactionSyncSignersArgs,
+  '__kind',
+  'Ed25519Precompile'
+>;
+export function transactionSyncSigners(
+  kind: 'Secp256k1',
+  data: GetDiscriminatedUnionVariantContent<
+    TransactionSyncSignersArgs,
+    '__kind',
+    'Secp256k1'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  TransactionSyncSignersArgs,
+  '__kind',
+  'Secp256k1'
+>;
 export function transactionSyncSigners<
   K extends TransactionSyncSignersArgs['__kind'],
   Data,
@@ -20,6 +20,7 @@ export enum UserRole {
   Administrator,
   PermanentMember,
   Member,
+  Guardian,
 }
 
 export type UserRoleArgs = UserRole;
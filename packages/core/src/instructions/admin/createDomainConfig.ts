@@ -7,11 +7,15 @@ export async function createDomainConfig({
   rpId,
   origins,
   authority,
+  requireUserPresence = false,
+  requireUserVerification = false,
 }: {
   payer: TransactionSigner;
   rpId: string;
   origins: string[];
   authority: TransactionSigner;
+  requireUserPresence?: boolean;
+  requireUserVerification?: boolean;
 }) {
   const domainConfig = await getDomainConfigAddress({ rpId });
 
@@ -21,6 +25,8 @@ export async function createDomainConfig({
     payer,
     domainConfig,
     rpId,
+    requireUserPresence,
+    requireUserVerification,
     userAccount: await getUserAddress(authority.address),
     remainingAccounts: [],
   });
@@ -70,6 +70,7 @@ export async function executeTransactionSync({
 
   instructions.push(
     getTransactionExecuteSyncInstruction({
+      vaultIndex: 0,
       signers: transactionSyncSigners,
       settings,
       ...customTransactionMessage,
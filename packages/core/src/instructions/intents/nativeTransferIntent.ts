@@ -46,6 +46,7 @@ export async function nativeTransferIntent({
 
   instructions.push(
     getNativeTransferIntentInstruction({
+      vaultIndex: 0,
       amount,
       signers: transactionSyncSigners,
       source: walletAddress,
@@ -69,6 +69,7 @@ export async function tokenTransferIntent({
       )[0],
       tokenProgram,
       mint,
+      vaultIndex: 0,
       amount,
       signers: transactionSyncSigners,
       remainingAccounts,
@@ -7,16 +7,28 @@ export async function editDomainConfig({
   domainConfig,
   newAuthority,
   newOrigins,
+  newRequireUserPresence,
+  newRequireUserVerification,
 }: {
   domainConfig: Address;
   authority: TransactionSigner;
   newAuthority?: TransactionSigner;
   newOrigins?: string[];
+  newRequireUserPresence?: boolean;
+  newRequireUserVerification?: boolean;
 }) {
   return getEditDomainConfigInstruction({
     domainConfig,
     authority,
     newOrigins: newOrigins ? some(newOrigins) : none(),
+    newRequireUserPresence:
+      newRequireUserPresence !== undefined
+        ? some(newRequireUserPresence)
+        : none(),
+    newRequireUserVerification:
+      newRequireUserVerification !== undefined
+        ? some(newRequireUserVerification)
+        : none(),
     newAuthority,
     userAccount: newAuthority
       ? await getUserAddress(newAuthority.address)
@@ -1,4 +1,4 @@
-import type { Address, TransactionSigner } from "@solana/kit";
+import { none, type Address, type TransactionSigner } from "@solana/kit";
 import { getTransactionBufferCloseInstruction } from "../../generated";
 import { SignedSecp256r1Key } from "../../types";
 import { extractSecp256r1VerificationArgs } from "../../utils/transaction/internal";
@@ -39,6 +39,7 @@ export function closeTransactionBuffer({
       settings,
       payer,
       secp256r1VerifyArgs: verifyArgs,
+      secp256k1VerifyArgs: none(),
       remainingAccounts: [],
     }),
   );
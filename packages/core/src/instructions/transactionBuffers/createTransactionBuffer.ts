@@ -1,5 +1,6 @@
-import type { Address, TransactionSigner } from "@solana/kit";
+import { none, type Address, type TransactionSigner } from "@solana/kit";
 import {
+  ExpiryMode,
   getTransactionBufferCreateInstruction,
   type ExpectedSignerArgs,
 } from "../../generated";
@@ -10,13 +11,13 @@ import { getSecp256r1VerifyInstruction } from "../secp256r1Verify";
 export function createTransactionBuffer({
   payer,
   creator,
-  bufferIndex,
   settings,
   transactionBufferAddress,
   finalBufferHash,
   finalBufferSize,
   preauthorizeExecution,
   bufferExtendHashes,
+  chunkSize,
   expectedSigners,
 }: {
   finalBufferHash: Uint8Array<ArrayBuffer>;
@@ -24,10 +25,10 @@ export function createTransactionBuffer({
   payer: TransactionSigner;
   creator: TransactionSigner | SignedSecp256r1Key;
   settings: Address;
-  bufferIndex: number;
   transactionBufferAddress: Address;
   preauthorizeExecution: boolean;
   bufferExtendHashes: Uint8Array<ArrayBuffer>[];
+  chunkSize: number;
   expectedSigners: ExpectedSignerArgs[];
 }) {
   const { domainConfig, verifyArgs, message, signature, publicKey } =
@@ -51,14 +52,20 @@ export function createTransactionBuffer({
       transactionBuffer: transactionBufferAddress,
       payer,
       secp256r1VerifyArgs: verifyArgs,
+      secp256k1VerifyArgs: none(),
       creator: creator instanceof SignedSecp256r1Key ? undefined : creator,
       domainConfig,
-      bufferIndex,
+      vaultIndex: 0,
+      messageCount: 1,
       finalBufferHash,
       finalBufferSize,
       bufferExtendHashes,
+      chunkSize,
       preauthorizeExecution,
       expectedSigners,
+      expiry: none(),
+      notBefore: none(),
+      expiryMode: ExpiryMode.Timestamp,
       remainingAccounts: [],
     }),
   );
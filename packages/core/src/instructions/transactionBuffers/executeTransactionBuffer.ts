@@ -1,4 +1,4 @@
-import type { Address, TransactionSigner } from "@solana/kit";
+import { none, type Address, type TransactionSigner } from "@solana/kit";
 import { getTransactionBufferExecuteInstruction } from "../../generated";
 import { SignedSecp256r1Key } from "../../types";
 import { extractSecp256r1VerificationArgs } from "../../utils/transaction/internal";
@@ -33,6 +33,7 @@ export function executeTransactionBuffer({
       settings,
       transactionBuffer: transactionBufferAddress,
       secp256r1VerifyArgs: verifyArgs,
+      secp256k1VerifyArgs: none(),
       domainConfig,
       executor: executor instanceof SignedSecp256r1Key ? undefined : executor,
       remainingAccounts: [],
@@ -1,17 +1,23 @@
-import type { Address } from "@solana/kit";
+import type { Address, TransactionSigner } from "@solana/kit";
 import { getTransactionBufferExtendInstruction } from "../../generated";
 
 export function extendTransactionBuffer({
+  payer,
+  chunkIndex,
   transactionMessageBytes,
   transactionBufferAddress,
   settings,
 }: {
+  payer: TransactionSigner;
+  chunkIndex: number;
   transactionMessageBytes: Uint8Array<ArrayBuffer>;
   transactionBufferAddress: Address;
   settings: Address;
 }) {
   return getTransactionBufferExtendInstruction({
     transactionBuffer: transactionBufferAddress,
+    payer,
+    chunkIndex,
     buffer: transactionMessageBytes,
     settings,
     remainingAccounts: [],
@@ -1,4 +1,9 @@
-import type { Address, Instruction, TransactionSigner } from "@solana/kit";
+import {
+  none,
+  type Address,
+  type Instruction,
+  type TransactionSigner,
+} from "@solana/kit";
 import { getTransactionBufferVoteInstruction } from "../../generated";
 import { SignedSecp256r1Key } from "../../types";
 import { extractSecp256r1VerificationArgs } from "../../utils/transaction/internal";
@@ -33,6 +38,7 @@ export function voteTransactionBuffer({
       settings,
       transactionBuffer: transactionBufferAddress,
       secp256r1VerifyArgs: verifyArgs,
+      secp256k1VerifyArgs: none(),
       domainConfig,
       voter: voter instanceof SignedSecp256r1Key ? undefined : voter,
       remainingAccounts: [],
@@ -5,6 +5,7 @@ import type {
   Instruction,
   TransactionSigner,
 } from "@solana/kit";
+import { fetchSettings } from "../generated";
 import {
   createTransactionBuffer,
   executeTransaction,
@@ -15,7 +16,11 @@ import {
 } from "../instructions";
 import { SignedSecp256r1Key } from "../types";
 import type { TransactionDetails } from "../types/transaction";
-import { getSecp256r1MessageHash, getTransactionBufferAddress } from "../utils";
+import {
+  getSecp256r1MessageHash,
+  getSolanaRpc,
+  getTransactionBufferAddress,
+} from "../utils";
 import {
   convertPubkeyToMemberkey,
   getDeduplicatedSigners,
@@ -48,11 +53,10 @@ export async function prepareTransactionBundle({
   additionalSigners = [],
   chunkSize = Math.ceil(transactionMessageBytes.length / 2),
 }: CreateTransactionBundleArgs): Promise<TransactionDetails[]> {
-  const bufferIndex = Math.floor(Math.random() * 255);
+  const { data } = await fetchSettings(getSolanaRpc(), settings);
   const transactionBufferAddress = await getTransactionBufferAddress(
     settings,
-    creator instanceof SignedSecp256r1Key ? creator : creator.address,
-    bufferIndex,
+    data.transactionIndex,
   );
 
   const chunks: Uint8Array<ArrayBuffer>[] = [];
@@ -81,19 +85,20 @@ export async function prepareTransactionBundle({
   const createIxs = createTransactionBuffer({
     finalBufferHash,
     finalBufferSize: transactionMessageBytes.length,
-    bufferIndex,
     payer,
     transactionBufferAddress,
     settings,
     creator,
     preauthorizeExecution: !executor,
     bufferExtendHashes: chunksHash,
-
+    chunkSize,
     expectedSigners,
   });
 
-  const extendIxs = chunks.map((bytes) =>
+  const extendIxs = chunks.map((bytes, chunkIndex) =>
     extendTransactionBuffer({
+      payer,
+      chunkIndex,
       transactionMessageBytes: bytes,
       transactionBufferAddress,
       settings,
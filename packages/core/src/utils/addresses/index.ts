@@ -3,14 +3,13 @@ import {
   getAddressEncoder,
   getProgramDerivedAddress,
   getU128Encoder,
-  getU8Encoder,
+  getU64Encoder,
   getUtf8Encoder,
   type Address,
 } from "@solana/kit";
 import { ValidationError } from "../../errors";
 import { MULTI_WALLET_PROGRAM_ADDRESS } from "../../generated";
 import { Secp256r1Key } from "../../types";
-import { requireInRange } from "../validation";
 
 export async function getUserAddress(user: Address | Secp256r1Key) {
   const [userAddress] = await getProgramDerivedAddress({
@@ -61,20 +60,15 @@ export async function getGlobalCounterAddress() {
 
 export async function getTransactionBufferAddress(
   settings: Address,
-  creator: Address | Secp256r1Key,
-  buffer_index: number,
+  transactionIndex: number | bigint,
 ): Promise<Address> {
-  requireInRange(buffer_index, 0, 255, "buffer_index");
   const [transactionBuffer] = await getProgramDerivedAddress({
     programAddress: MULTI_WALLET_PROGRAM_ADDRESS,
     seeds: [
       getUtf8Encoder().encode("multi_wallet"),
       getAddressEncoder().encode(settings),
       getUtf8Encoder().encode("transaction_buffer"),
-      creator instanceof Secp256r1Key
-        ? creator.toTruncatedBuffer()
-        : getAddressEncoder().encode(creator),
-      getU8Encoder().encode(buffer_index),
+      getU64Encoder().encode(transactionIndex),
     ],
   });
 
@@ -132,7 +132,7 @@ pub enum MultisigError {
     #[msg("Declared buffer size does not match the actual serialized size.")]
     FinalBufferSizeMismatch,
 
-    #[msg("This transaction has expired. It must be executed within 3 minutes of approval.")]
+    #[msg("This transaction has expired. It must be executed within the wallet's transaction expiry window.")]
     TransactionHasExpired,
 
     #[msg("This transaction has not yet reached the required approval threshold.")]
@@ -212,4 +212,130 @@ pub enum MultisigError {
 
     #[msg("Missing new authority account. Required for domain configuration updates.")]
     MissingNewAuthority,
+
+    #[msg("Invalid member weight: members with vote permission must have a weight of at least 1.")]
+    InvalidMemberWeight,
+
+    #[msg("A config timelock is active. Configuration changes must go through a config proposal.")]
+    ConfigTimelockActive,
+
+    #[msg("The config proposal timelock has not elapsed yet.")]
+    ConfigProposalTimelockNotElapsed,
+
+    #[msg("The config proposal timelock has elapsed. It can no longer be cancelled.")]
+    ConfigProposalTimelockElapsed,
+
+    #[msg("Config actions hash mismatch: the stored config actions may be corrupted or altered.")]
+    ConfigActionsHashMismatch,
+
+    #[msg("Invalid guardian configuration: guardians cannot hold permissions or be delegates.")]
+    InvalidGuardianConfig,
+
+    #[msg("Invalid guardian threshold: it cannot exceed the number of guardians.")]
+    InvalidGuardianThreshold,
+
+    #[msg("Recovery delay is below the minimum required while recovery is enabled.")]
+    RecoveryDelayTooShort,
+
+    #[msg("Recovery is not enabled for this wallet. Set a guardian threshold first.")]
+    RecoveryNotEnabled,
+
+    #[msg("Insufficient guardian signers to initiate recovery.")]
+    InsufficientGuardianSigners,
+
+    #[msg("The recovery delay has not elapsed yet.")]
+    RecoveryDelayNotElapsed,
+
+    #[msg("The wallet configuration changed after this transaction buffer was created. Create a new transaction buffer.")]
+    ConfigVersionMismatch,
+
+    #[msg("Invalid multi-wallet member configuration: a nested wallet must be a regular, non-delegated member.")]
+    InvalidMultiWalletMemberConfig,
+
+    #[msg("Invalid transaction expiry: it must be non-zero and within the expiry window.")]
+    InvalidTransactionExpiry,
+
+    #[msg("A member cannot both approve and reject the same transaction.")]
+    ConflictingVote,
+
+    #[msg("The signer has no vote on this transaction to withdraw.")]
+    VoterNotFound,
+
+    #[msg("Not every chunk of the transaction buffer has been written yet.")]
+    BufferChunksMissing,
+
+    #[msg("This chunk of the transaction buffer has already been written.")]
+    BufferChunkAlreadyWritten,
+
+    #[msg("This transaction has not expired and still matches the wallet configuration, so it cannot be closed without the creator's approval.")]
+    TransactionNotExpired,
+
+    #[msg("This transaction is scheduled and cannot be executed before its not-before time.")]
+    TransactionNotYetExecutable,
+
+    #[msg("The referenced instruction is not an Ed25519 signature verification instruction.")]
+    InvalidEd25519Instruction,
+
+    #[msg("The Ed25519 signed message does not match the expected challenge for this action.")]
+    Ed25519ChallengeMismatch,
+
+    #[msg("The referenced instruction is not a secp256k1 signature verification instruction.")]
+    InvalidSecp256k1Instruction,
+
+    #[msg("The secp256k1 signed message is not the EIP-191 wrapping of the expected challenge for this action.")]
+    Secp256k1ChallengeMismatch,
+
+    #[msg("Invalid Ethereum key member configuration: it must be a regular member without delegation.")]
+    InvalidSecp256k1MemberConfig,
+
+    #[msg("The raw secp256r1 signed message does not match the expected challenge.")]
+    Secp256r1ChallengeMismatch,
+
+    #[msg("The authenticatorData in the signed message is malformed.")]
+    InvalidAuthenticatorData,
+
+    #[msg("This domain requires user presence, but the assertion lacks the UP flag.")]
+    UserPresenceRequired,
+
+    #[msg("This domain requires user verification, but the assertion lacks the UV flag.")]
+    UserVerificationRequired,
+
+    #[msg("The passkey signature counter did not increase, the key may have been cloned.")]
+    SignCountNotIncreased,
+
+    #[msg("The user account is not the signer's writable user account.")]
+    UserAccountKeyMismatch,
+
+    #[msg("A member key can only be rotated to a key of the same type.")]
+    MemberKeyTypeMismatch,
+
+    #[msg("The wallet still has open transaction buffers, config proposals or a recovery.")]
+    WalletHasOpenAccounts,
+
+    #[msg("A vault token account must be emptied before the wallet can be closed.")]
+    VaultTokenAccountNotEmpty,
+
+    #[msg("The transaction batch has started executing and can only run to completion.")]
+    TransactionBatchStarted,
+
+    #[msg("Unknown member key type.")]
+    InvalidKeyType,
+
+    #[msg("Invalid raw P-256 key member configuration: it must be a regular member without delegation.")]
+    InvalidSecp256r1RawMemberConfig,
+
+    #[msg("This account already uses the current layout.")]
+    AccountAlreadyMigrated,
+
+    #[msg("The config proposal still matches the wallet configuration.")]
+    ConfigProposalNotStale,
+
+    #[msg("The recovery delay has elapsed. It can no longer be vetoed.")]
+    RecoveryVetoWindowElapsed,
+
+    #[msg("A single extend can grow the transaction buffer by at most 10 KiB. Write the earlier chunks first.")]
+    BufferGrowthExceeded,
+
+    #[msg("The same transaction buffer was passed more than once.")]
+    DuplicateTransactionBuffer,
 }
@@ -9,6 +9,8 @@ use sha2::{Digest, Sha256};
 pub struct CreateDomainConfigArgs {
     rp_id: String,
     origins: Vec<String>,
+    require_user_presence: bool,
+    require_user_verification: bool,
 }
 
 #[derive(Accounts)]
@@ -57,6 +59,7 @@ impl<'info> CreateDomainConfig<'info> {
         user.role = UserRole::Administrator;
         user.wallets = Vec::new();
         user.transports = None;
+        user.sign_count = 0;
         user.credential_id = None;
         user.domain_config = Some(ctx.accounts.domain_config.key());
         user.transaction_manager_url = None;
@@ -71,6 +74,8 @@ impl<'info> CreateDomainConfig<'info> {
         domain_config.authority = authority_key;
         domain_config.bump = ctx.bumps.domain_config;
         domain_config.is_disabled = 0;
+        domain_config.require_user_presence = u8::from(args.require_user_presence);
+        domain_config.require_user_verification = u8::from(args.require_user_verification);
 
         Ok(())
     }
@@ -1,10 +1,10 @@
 use crate::{
-    state::{Settings, User, UserWalletOperation},
+    state::{Settings, User},
     utils::{
-        resize_account_if_necessary, MultisigSettings, TransactionActionType,
-        TransactionSyncSigners,
+        resize_account_if_necessary, ChallengeArgs, MultisigSettings, SyncSignerArgs,
+        TransactionActionType, TransactionSyncSigners,
     },
-    ConfigAction,
+    ConfigAction, MultisigError,
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
 use sha2::{Digest, Sha256};
@@ -42,6 +42,12 @@ impl<'info> ChangeConfig<'info> {
             ..
         } = self;
 
+        // Once a timelock is configured, changes must go through a config proposal
+        require!(
+            settings.config_timelock == 0,
+            MultisigError::ConfigTimelockActive
+        );
+
         let mut writer = Vec::new();
         config_actions.serialize(&mut writer)?;
         let message_hash =
@@ -49,14 +55,17 @@ impl<'info> ChangeConfig<'info> {
 
         TransactionSyncSigners::verify(
             signers,
-            ctx.remaining_accounts,
-            instructions_sysvar,
-            slot_hash_sysvar,
-            settings.get_members()?,
-            settings.get_threshold()?,
-            ctx.accounts.settings.key(),
-            message_hash,
-            TransactionActionType::ChangeConfig,
+            &SyncSignerArgs {
+                remaining_accounts: ctx.remaining_accounts,
+                instructions_sysvar,
+                slot_hash_sysvar,
+                settings,
+            },
+            ChallengeArgs {
+                account: ctx.accounts.settings.key(),
+                message_hash,
+                action_type: TransactionActionType::ChangeConfig,
+            },
         )?;
 
         Ok(())
@@ -70,25 +79,8 @@ impl<'info> ChangeConfig<'info> {
     ) -> Result<()> {
         let settings = &mut ctx.accounts.settings;
 
-        let mut wallet_operations: Vec<UserWalletOperation> = Vec::new();
-        for action in config_actions {
-            match action {
-                ConfigAction::EditPermissions(members) => {
-                    settings.edit_permissions(members)?;
-                }
-                ConfigAction::AddMembers(members) => {
-                    let ops = settings.add_members(members, ctx.remaining_accounts)?;
-                    wallet_operations.extend(ops.into_iter().map(UserWalletOperation::Add));
-                }
-                ConfigAction::RemoveMembers(members) => {
-                    let ops = settings.remove_members(members)?;
-                    wallet_operations.extend(ops.into_iter().map(UserWalletOperation::Remove));
-                }
-                ConfigAction::SetThreshold(new_threshold) => {
-                    settings.set_threshold(new_threshold)?;
-                }
-            }
-        }
+        let wallet_operations =
+            settings.apply_config_actions(config_actions, ctx.remaining_accounts)?;
 
         resize_account_if_necessary(
             settings.as_ref(),
@@ -0,0 +1,282 @@
+use crate::{
+    state::{ConfigProposal, Settings, TransactionBuffer, User},
+    utils::{
+        ChallengeArgs, SyncSignerArgs, TransactionSyncSigners, UserRole, VaultSignerSeeds,
+        DEFAULT_VAULT_INDEX,
+    },
+    MultisigError, TransactionActionType, SEED_MULTISIG, SEED_RECOVERY, SEED_VAULT,
+};
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::SysvarId,
+    system_program::{transfer, Transfer},
+    Discriminator,
+};
+use anchor_spl::{
+    token, token_2022,
+    token_interface::{close_account, CloseAccount, TokenAccount},
+};
+use sha2::{Digest, Sha256};
+
+#[derive(Accounts)]
+pub struct CloseWallet<'info> {
+    #[account(
+        mut,
+        close = destination,
+    )]
+    pub settings: Account<'info, Settings>,
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [
+            SEED_MULTISIG,
+            settings.key().as_ref(),
+            SEED_VAULT,
+        ],
+        bump = settings.multi_wallet_bump,
+    )]
+    pub vault: UncheckedAccount<'info>,
+    /// CHECK: must not exist, a pending recovery has to be executed or vetoed first
+    #[account(
+        seeds = [
+            SEED_MULTISIG,
+            settings.key().as_ref(),
+            SEED_RECOVERY,
+        ],
+        bump,
+    )]
+    pub recovery: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(
+        address = SlotHashes::id()
+    )]
+    pub slot_hash_sysvar: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    #[account(
+        address = Instructions::id(),
+    )]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseWallet<'info> {
+    fn validate(
+        &self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        sub_vault_indices: &[u8],
+        signers: &[TransactionSyncSigners],
+    ) -> Result<()> {
+        let Self {
+            settings,
+            recovery,
+            destination,
+            slot_hash_sysvar,
+            instructions_sysvar,
+            ..
+        } = self;
+
+        require!(
+            !sub_vault_indices.contains(&DEFAULT_VAULT_INDEX),
+            MultisigError::InvalidArguments
+        );
+        require!(
+            recovery.data_is_empty(),
+            MultisigError::WalletHasOpenAccounts
+        );
+        // Closing is as final as any config change, so it cannot bypass the wallet's timelock
+        require!(
+            settings.config_timelock == 0,
+            MultisigError::ConfigTimelockActive
+        );
+
+        let mut buffer = Vec::with_capacity(32 + sub_vault_indices.len());
+        buffer.extend_from_slice(destination.key().as_ref());
+        buffer.extend_from_slice(sub_vault_indices);
+        let message_hash = Sha256::digest(&buffer).into();
+
+        TransactionSyncSigners::verify(
+            signers,
+            &SyncSignerArgs {
+                remaining_accounts,
+                instructions_sysvar,
+                slot_hash_sysvar,
+                settings,
+            },
+            ChallengeArgs {
+                account: settings.key(),
+                message_hash,
+                action_type: TransactionActionType::CloseWallet,
+            },
+        )?;
+
+        // A permanent member can only be removed by closing the wallet if they agreed to it
+        if let Some(permanent_member) = settings
+            .members
+            .iter()
+            .find(|m| UserRole::from(m.role).eq(&UserRole::PermanentMember))
+        {
+            let signer_member_keys =
+                TransactionSyncSigners::resolve(signers, remaining_accounts, instructions_sysvar)?;
+            require!(
+                signer_member_keys
+                    .iter()
+                    .any(|(member_key, _)| member_key.eq(&permanent_member.pubkey)),
+                MultisigError::PermanentMember
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Token accounts owned by the vaults cannot be found on chain, so they have to be passed in the
+    /// remaining accounts, emptied beforehand, to be closed along with the wallet.
+    #[access_control(ctx.accounts.validate(ctx.remaining_accounts, &sub_vault_indices, &signers))]
+    pub fn process(
+        ctx: Context<'info, Self>,
+        sub_vault_indices: Vec<u8>,
+        signers: Vec<TransactionSyncSigners>,
+    ) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+        let settings_key = settings.key();
+        let multi_wallet_bump = settings.multi_wallet_bump;
+
+        let slot_numbers = TransactionSyncSigners::collect_slot_numbers(&signers);
+        settings.latest_slot_number_check(&slot_numbers, &ctx.accounts.slot_hash_sysvar)?;
+
+        // Transaction buffers and config proposals can no longer be used, so they are closed back to their
+        // payers. Every open one has to be passed in, otherwise its rent would be stranded.
+        for account_info in ctx.remaining_accounts.iter() {
+            if !account_info.owner.eq(&crate::ID) {
+                continue;
+            }
+            let data = account_info.try_borrow_data()?;
+            let is_transaction_buffer = data.starts_with(TransactionBuffer::DISCRIMINATOR);
+            let is_config_proposal = data.starts_with(ConfigProposal::DISCRIMINATOR);
+            drop(data);
+
+            if is_transaction_buffer {
+                let transaction_buffer = Account::<TransactionBuffer>::try_from(account_info)?;
+                require!(
+                    transaction_buffer.multi_wallet_settings.eq(&settings_key),
+                    MultisigError::InvalidAccount
+                );
+                let payer = find_payer(ctx.remaining_accounts, &transaction_buffer.payer)?;
+                transaction_buffer.close(payer.clone())?;
+                settings.remove_open_transaction_buffer()?;
+            } else if is_config_proposal {
+                let config_proposal = Account::<ConfigProposal>::try_from(account_info)?;
+                require!(
+                    config_proposal.multi_wallet_settings.eq(&settings_key),
+                    MultisigError::InvalidAccount
+                );
+                let payer = find_payer(ctx.remaining_accounts, &config_proposal.payer)?;
+                config_proposal.close(payer.clone())?;
+                settings.remove_open_config_proposal()?;
+            }
+        }
+
+        require!(
+            !settings.has_open_accounts(),
+            MultisigError::WalletHasOpenAccounts
+        );
+
+        let mut vaults = vec![(
+            VaultSignerSeeds::new(settings_key, DEFAULT_VAULT_INDEX, multi_wallet_bump),
+            ctx.accounts.vault.to_account_info(),
+        )];
+        for vault_index in sub_vault_indices {
+            let vault_signer_seeds =
+                VaultSignerSeeds::new(settings_key, vault_index, multi_wallet_bump);
+            let vault_address = vault_signer_seeds.address()?;
+            let vault = ctx
+                .remaining_accounts
+                .iter()
+                .find(|f| f.key.eq(&vault_address))
+                .ok_or(MultisigError::MissingAccount)?;
+            vaults.push((vault_signer_seeds, vault.clone()));
+        }
+
+        for account_info in ctx.remaining_accounts.iter() {
+            if !account_info.owner.eq(&token::ID) && !account_info.owner.eq(&token_2022::ID) {
+                continue;
+            }
+            let token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+            let Some((vault_signer_seeds, vault)) = vaults
+                .iter()
+                .find(|(_, vault)| vault.key.eq(&token_account.owner))
+            else {
+                continue;
+            };
+            require!(
+                token_account.amount == 0,
+                MultisigError::VaultTokenAccountNotEmpty
+            );
+            ctx.accounts
+                .close_vault_token_account(account_info, vault, vault_signer_seeds)?;
+        }
+
+        for (vault_signer_seeds, vault) in &vaults {
+            ctx.accounts.sweep_vault(vault, vault_signer_seeds)?;
+        }
+
+        let settings = &ctx.accounts.settings;
+        User::detach_wallet_from_users(&settings.members, settings.index, ctx.remaining_accounts)?;
+
+        Ok(())
+    }
+
+    fn close_vault_token_account(
+        &self,
+        token_account: &AccountInfo<'info>,
+        vault: &AccountInfo<'info>,
+        vault_signer_seeds: &VaultSignerSeeds,
+    ) -> Result<()> {
+        close_account(
+            CpiContext::new(
+                *token_account.owner,
+                CloseAccount {
+                    account: token_account.to_account_info(),
+                    destination: self.destination.to_account_info(),
+                    authority: vault.to_account_info(),
+                },
+            )
+            .with_signer(&[&vault_signer_seeds.seeds()]),
+        )
+    }
+
+    fn sweep_vault(
+        &self,
+        vault: &AccountInfo<'info>,
+        vault_signer_seeds: &VaultSignerSeeds,
+    ) -> Result<()> {
+        let vault_lamports = vault.lamports();
+        if vault_lamports == 0 {
+            return Ok(());
+        }
+
+        transfer(
+            CpiContext::new(
+                self.system_program.key(),
+                Transfer {
+                    from: vault.to_account_info(),
+                    to: self.destination.to_account_info(),
+                },
+            )
+            .with_signer(&[&vault_signer_seeds.seeds()]),
+            vault_lamports,
+        )
+    }
+}
+
+fn find_payer<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    payer: &Pubkey,
+) -> Result<&'a AccountInfo<'info>> {
+    Ok(remaining_accounts
+        .iter()
+        .find(|f| f.key.eq(payer))
+        .ok_or(MultisigError::PayerMismatch)?)
+}
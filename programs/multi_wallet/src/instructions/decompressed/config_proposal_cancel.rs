@@ -0,0 +1,104 @@
+use crate::{
+    state::{ConfigProposal, DomainConfig, Settings},
+    utils::{
+        ChallengeArgs, MultisigSettings, Secp256k1VerifyArgs, Secp256r1VerifyArgs, SignerArgs,
+        TransactionActionType, TransactionBufferSigners, VetoSigners,
+    },
+    MultisigError,
+};
+use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
+
+#[derive(Accounts)]
+pub struct ConfigProposalCancel<'info> {
+    #[account(
+        mut,
+        address = config_proposal.multi_wallet_settings,
+    )]
+    pub settings: Account<'info, Settings>,
+    /// CHECK:
+    #[account(
+        mut,
+        constraint = payer.key() == config_proposal.payer @MultisigError::PayerMismatch
+    )]
+    pub payer: UncheckedAccount<'info>,
+    pub domain_config: Option<AccountLoader<'info, DomainConfig>>,
+    /// CHECK: the signer's user account, validated when a passkey signature updates its counter
+    #[account(mut)]
+    pub user_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        close = payer,
+    )]
+    pub config_proposal: Account<'info, ConfigProposal>,
+    pub member: Option<Signer<'info>>,
+    /// CHECK:
+    #[account(
+        address = SlotHashes::id(),
+    )]
+    pub slot_hash_sysvar: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    #[account(
+        address = Instructions::id(),
+    )]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl ConfigProposalCancel<'_> {
+    fn validate(
+        &mut self,
+        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: &Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
+        let Self {
+            settings,
+            domain_config,
+            user_account,
+            config_proposal,
+            member,
+            slot_hash_sysvar,
+            instructions_sysvar,
+            ..
+        } = self;
+
+        require!(
+            !config_proposal.is_executable(Clock::get()?.unix_timestamp as u64),
+            MultisigError::ConfigProposalTimelockElapsed
+        );
+
+        VetoSigners::verify(
+            &SignerArgs {
+                signer: member,
+                secp256r1_verify_args,
+                secp256k1_verify_args,
+                instructions_sysvar: Some(instructions_sysvar),
+                slot_hash_sysvar,
+                domain_config,
+                user_account,
+            },
+            settings.get_members()?,
+            ChallengeArgs {
+                account: config_proposal.multi_wallet_settings,
+                message_hash: config_proposal.config_actions_hash,
+                action_type: TransactionActionType::CancelConfigProposal,
+            },
+        )?;
+
+        let slot_numbers = TransactionBufferSigners::collect_slot_numbers(
+            secp256r1_verify_args,
+            secp256k1_verify_args,
+        );
+        settings.latest_slot_number_check(&slot_numbers, slot_hash_sysvar)?;
+        settings.invariant()?;
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&secp256r1_verify_args, &secp256k1_verify_args))]
+    pub fn process(
+        ctx: Context<Self>,
+        secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
+        ctx.accounts.settings.remove_open_config_proposal()
+    }
+}
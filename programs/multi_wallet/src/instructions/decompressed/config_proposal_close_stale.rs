@@ -0,0 +1,42 @@
+use crate::{
+    state::{ConfigProposal, Settings},
+    MultisigError,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ConfigProposalCloseStale<'info> {
+    #[account(
+        mut,
+        address = config_proposal.multi_wallet_settings,
+    )]
+    pub settings: Account<'info, Settings>,
+    /// CHECK:
+    #[account(
+        mut,
+        constraint = payer.key() == config_proposal.payer @MultisigError::PayerMismatch
+    )]
+    pub payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = payer,
+    )]
+    pub config_proposal: Account<'info, ConfigProposal>,
+}
+
+impl<'info> ConfigProposalCloseStale<'info> {
+    /// A stale proposal can never execute, and its address is taken until it is closed.
+    fn validate(&self) -> Result<()> {
+        require!(
+            self.config_proposal.is_stale(self.settings.config_version),
+            MultisigError::ConfigProposalNotStale
+        );
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn process(ctx: Context<'info, Self>) -> Result<()> {
+        ctx.accounts.settings.remove_open_config_proposal()
+    }
+}
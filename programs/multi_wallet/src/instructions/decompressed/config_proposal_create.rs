@@ -0,0 +1,106 @@
+use crate::{
+    state::{ConfigProposal, Settings},
+    utils::{
+        ChallengeArgs, SyncSignerArgs, TransactionActionType, TransactionSyncSigners,
+        SEED_CONFIG_PROPOSAL,
+    },
+    ConfigAction, SEED_MULTISIG,
+};
+use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
+
+#[derive(Accounts)]
+#[instruction(config_actions: Vec<ConfigAction>)]
+pub struct ConfigProposalCreate<'info> {
+    #[account(mut)]
+    pub settings: Account<'info, Settings>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = ConfigProposal::size(ConfigProposal::serialize_config_actions(&config_actions)?.len()),
+        seeds = [
+            SEED_MULTISIG,
+            settings.key().as_ref(),
+            SEED_CONFIG_PROPOSAL,
+            // Sliced rather than `.as_ref()` so the IDL build leaves this PDA to clients instead of
+            // trying to evaluate the hash itself
+            &ConfigProposal::hash_config_actions(&config_actions)?[..],
+        ],
+        bump
+    )]
+    pub config_proposal: Account<'info, ConfigProposal>,
+    pub system_program: Program<'info, System>,
+    /// CHECK:
+    #[account(
+        address = SlotHashes::id()
+    )]
+    pub slot_hash_sysvar: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    #[account(
+        address = Instructions::id(),
+    )]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> ConfigProposalCreate<'info> {
+    fn validate(
+        &self,
+        ctx: &Context<'info, Self>,
+        config_actions: &[ConfigAction],
+        signers: &[TransactionSyncSigners],
+    ) -> Result<()> {
+        let Self {
+            settings,
+            slot_hash_sysvar,
+            instructions_sysvar,
+            ..
+        } = self;
+
+        let message_hash = ConfigProposal::hash_config_actions(config_actions)?;
+
+        TransactionSyncSigners::verify(
+            signers,
+            &SyncSignerArgs {
+                remaining_accounts: ctx.remaining_accounts,
+                instructions_sysvar,
+                slot_hash_sysvar,
+                settings,
+            },
+            ChallengeArgs {
+                account: ctx.accounts.settings.key(),
+                message_hash,
+                action_type: TransactionActionType::ProposeConfig,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&ctx, &config_actions, &signers))]
+    pub fn process(
+        ctx: Context<'info, Self>,
+        config_actions: Vec<ConfigAction>,
+        signers: Vec<TransactionSyncSigners>,
+    ) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        ctx.accounts.config_proposal.init(
+            settings.key(),
+            ctx.accounts.payer.key(),
+            ConfigProposal::serialize_config_actions(&config_actions)?,
+            settings.config_timelock,
+            settings.config_version,
+            ctx.bumps.config_proposal,
+        )?;
+
+        settings.add_open_config_proposal()?;
+
+        let slot_numbers = TransactionSyncSigners::collect_slot_numbers(&signers);
+        settings.latest_slot_number_check(&slot_numbers, &ctx.accounts.slot_hash_sysvar)?;
+
+        settings.invariant()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    state::{ConfigProposal, Settings, User},
+    utils::{resize_account_if_necessary, MultisigSettings},
+    MultisigError,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ConfigProposalExecute<'info> {
+    #[account(
+        mut,
+        address = config_proposal.multi_wallet_settings,
+    )]
+    pub settings: Account<'info, Settings>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK:
+    #[account(
+        mut,
+        constraint = rent_payer.key() == config_proposal.payer @MultisigError::PayerMismatch
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = rent_payer,
+    )]
+    pub config_proposal: Account<'info, ConfigProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ConfigProposalExecute<'info> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            config_proposal,
+            settings,
+            ..
+        } = self;
+
+        config_proposal.check_config_version(settings.config_version)?;
+
+        require!(
+            config_proposal.is_executable(Clock::get()?.unix_timestamp as u64),
+            MultisigError::ConfigProposalTimelockNotElapsed
+        );
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn process(ctx: Context<'info, Self>) -> Result<()> {
+        let config_actions = ctx.accounts.config_proposal.config_actions()?;
+        let settings = &mut ctx.accounts.settings;
+        settings.remove_open_config_proposal()?;
+
+        let wallet_operations =
+            settings.apply_config_actions(config_actions, ctx.remaining_accounts)?;
+
+        resize_account_if_necessary(
+            settings.as_ref(),
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            Settings::size(settings.get_members()?.len()),
+        )?;
+
+        settings.invariant()?;
+
+        User::process_user_wallet_operations(
+            wallet_operations,
+            settings.index,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            ctx.remaining_accounts,
+        )?;
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    utils::{MultisigSettings, TransactionSyncSigners},
-    Settings, TransactionActionType, SEED_MULTISIG, SEED_VAULT,
+    utils::ChallengeArgs, Settings, SyncSignerArgs, TransactionActionType, TransactionSyncSigners,
+    VaultSignerSeeds,
 };
 use anchor_lang::{
     prelude::*,
@@ -10,6 +10,7 @@ use anchor_lang::{
 use sha2::{Digest, Sha256};
 
 #[derive(Accounts)]
+#[instruction(amount: u64, vault_index: u8)]
 pub struct NativeTransferIntent<'info> {
     #[account(mut)]
     pub settings: Account<'info, Settings>,
@@ -28,12 +29,7 @@ pub struct NativeTransferIntent<'info> {
     /// CHECK:
     #[account(
         mut,
-        seeds = [
-            SEED_MULTISIG,
-            settings.key().as_ref(),
-            SEED_VAULT,
-        ],
-        bump = settings.multi_wallet_bump,
+        address = VaultSignerSeeds::new(settings.key(), vault_index, settings.multi_wallet_bump).address()?,
     )]
     pub source: UncheckedAccount<'info>,
 
@@ -48,6 +44,7 @@ impl<'info> NativeTransferIntent<'info> {
     fn validate(
         &self,
         amount: u64,
+        vault_index: u8,
         remaining_accounts: &'info [AccountInfo<'info>],
         signers: &[TransactionSyncSigners],
     ) -> Result<()> {
@@ -60,42 +57,43 @@ impl<'info> NativeTransferIntent<'info> {
             ..
         } = &self;
 
-        let mut buffer = Vec::with_capacity(72);
+        let mut buffer = Vec::with_capacity(73);
         buffer.extend_from_slice(amount.to_le_bytes().as_ref());
         buffer.extend_from_slice(destination.key().as_ref());
         buffer.extend_from_slice(system_program.key().as_ref());
+        buffer.push(vault_index);
         let message_hash =
             Sha256::digest(&buffer).into();
 
         TransactionSyncSigners::verify(
             signers,
-            remaining_accounts,
-            instructions_sysvar,
-            slot_hash_sysvar,
-            settings.get_members()?,
-            settings.get_threshold()?,
-            system_program.key(),
-            message_hash,
-            TransactionActionType::TransferIntent,
+            &SyncSignerArgs {
+                remaining_accounts,
+                instructions_sysvar,
+                slot_hash_sysvar,
+                settings,
+            },
+            ChallengeArgs {
+                account: system_program.key(),
+                message_hash,
+                action_type: TransactionActionType::TransferIntent,
+            },
         )?;
 
         Ok(())
     }
 
-    #[access_control(ctx.accounts.validate(amount, &ctx.remaining_accounts, &signers))]
+    #[access_control(ctx.accounts.validate(amount, vault_index, &ctx.remaining_accounts, &signers))]
     pub fn process(
         ctx: Context<'info, Self>,
         amount: u64,
+        vault_index: u8,
         signers: Vec<TransactionSyncSigners>,
     ) -> Result<()> {
         let settings = &mut ctx.accounts.settings;
-        let settings_key = settings.key();
-        let signer_seeds: &[&[u8]] = &[
-            SEED_MULTISIG,
-            settings_key.as_ref(),
-            SEED_VAULT,
-            &[settings.multi_wallet_bump],
-        ];
+        let vault_signer_seeds =
+            VaultSignerSeeds::new(settings.key(), vault_index, settings.multi_wallet_bump);
+        let signer_seeds: &[&[u8]] = &vault_signer_seeds.seeds();
 
         transfer(
             CpiContext::new(
@@ -1,6 +1,6 @@
 use crate::{
-    utils::TransactionSyncSigners, Settings, TransactionActionType, SEED_MULTISIG,
-    SEED_VAULT,
+    utils::ChallengeArgs, Settings, SyncSignerArgs, TransactionActionType, TransactionSyncSigners,
+    VaultSignerSeeds,
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
 use anchor_spl::{
@@ -10,6 +10,7 @@ use anchor_spl::{
 use sha2::{Digest, Sha256};
 
 #[derive(Accounts)]
+#[instruction(amount: u64, vault_index: u8)]
 pub struct TokenTransferIntent<'info> {
     #[account(mut)]
     pub settings: Account<'info, Settings>,
@@ -27,12 +28,7 @@ pub struct TokenTransferIntent<'info> {
     pub instructions_sysvar: UncheckedAccount<'info>,
     /// CHECK:
     #[account(
-        seeds = [
-            SEED_MULTISIG,
-            settings.key().as_ref(),
-            SEED_VAULT,
-        ],
-        bump = settings.multi_wallet_bump,
+        address = VaultSignerSeeds::new(settings.key(), vault_index, settings.multi_wallet_bump).address()?,
     )]
     pub source: UncheckedAccount<'info>,
     /// CHECK:
@@ -74,6 +70,7 @@ impl<'info> TokenTransferIntent<'info> {
     fn validate(
         &self,
         amount: u64,
+        vault_index: u8,
         remaining_accounts: &'info [AccountInfo<'info>],
         signers: &Vec<TransactionSyncSigners>,
     ) -> Result<()> {
@@ -91,37 +88,41 @@ impl<'info> TokenTransferIntent<'info> {
         buffer.extend_from_slice(amount.to_le_bytes().as_ref());
         buffer.extend_from_slice(destination.key().as_ref());
         buffer.extend_from_slice(mint.key().as_ref());
+        buffer.push(vault_index);
         let message_hash =
             Sha256::digest(&buffer).into();
 
         TransactionSyncSigners::verify(
             signers,
-            remaining_accounts,
-            instructions_sysvar,
-            slot_hash_sysvar,
-            &settings.members,
-            settings.threshold,
-            token_program.key(),
-            message_hash,
-            TransactionActionType::TransferIntent,
+            &SyncSignerArgs {
+                remaining_accounts,
+                instructions_sysvar,
+                slot_hash_sysvar,
+                settings,
+            },
+            ChallengeArgs {
+                account: token_program.key(),
+                message_hash,
+                action_type: TransactionActionType::TransferIntent,
+            },
         )?;
 
         Ok(())
     }
 
-    #[access_control(ctx.accounts.validate(amount, &ctx.remaining_accounts, &signers))]
+    #[access_control(ctx.accounts.validate(amount, vault_index, &ctx.remaining_accounts, &signers))]
     pub fn process(
         ctx: Context<'info, Self>,
         amount: u64,
+        vault_index: u8,
         signers: Vec<TransactionSyncSigners>,
     ) -> Result<()> {
-        let settings_key = &ctx.accounts.settings.key();
-        let signer_seeds: &[&[u8]] = &[
-            SEED_MULTISIG,
-            settings_key.as_ref(),
-            SEED_VAULT,
-            &[ctx.accounts.settings.multi_wallet_bump],
-        ];
+        let vault_signer_seeds = VaultSignerSeeds::new(
+            ctx.accounts.settings.key(),
+            vault_index,
+            ctx.accounts.settings.multi_wallet_bump,
+        );
+        let signer_seeds: &[&[u8]] = &vault_signer_seeds.seeds();
 
         associated_token::create_idempotent(CpiContext::new_with_signer(
             ctx.accounts.associated_token_program.key(),
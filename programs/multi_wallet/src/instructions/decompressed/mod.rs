@@ -22,5 +22,38 @@ pub use transaction_execute_sync::*;
 pub mod transaction_execute;
 pub use transaction_execute::*;
 
+pub mod config_proposal_create;
+pub use config_proposal_create::*;
+
+pub mod config_proposal_execute;
+pub use config_proposal_execute::*;
+
+pub mod config_proposal_cancel;
+pub use config_proposal_cancel::*;
+
+pub mod config_proposal_close_stale;
+pub use config_proposal_close_stale::*;
+
+pub mod recovery_initiate;
+pub use recovery_initiate::*;
+
+pub mod recovery_execute;
+pub use recovery_execute::*;
+
+pub mod recovery_veto;
+pub use recovery_veto::*;
+
+pub mod close_wallet;
+pub use close_wallet::*;
+
+pub mod transaction_buffer_reject;
+pub use transaction_buffer_reject::*;
+
+pub mod transaction_buffer_unvote;
+pub use transaction_buffer_unvote::*;
+
+pub mod transaction_buffer_close_expired;
+pub use transaction_buffer_close_expired::*;
+
 pub mod intents;
 pub use intents::*;
@@ -0,0 +1,75 @@
+use crate::{
+    state::{Recovery, Settings, User},
+    utils::{resize_account_if_necessary, MultisigSettings},
+    MultisigError,
+};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RecoveryExecute<'info> {
+    #[account(
+        mut,
+        address = recovery.multi_wallet_settings,
+    )]
+    pub settings: Account<'info, Settings>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK:
+    #[account(
+        mut,
+        constraint = rent_payer.key() == recovery.payer @MultisigError::PayerMismatch
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = rent_payer,
+    )]
+    pub recovery: Account<'info, Recovery>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RecoveryExecute<'info> {
+    fn validate(&self) -> Result<()> {
+        let Self {
+            recovery, settings, ..
+        } = self;
+
+        recovery.check_config_version(settings.config_version)?;
+
+        require!(
+            recovery.is_executable(Clock::get()?.unix_timestamp as u64),
+            MultisigError::RecoveryDelayNotElapsed
+        );
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn process(ctx: Context<'info, Self>) -> Result<()> {
+        let new_members = std::mem::take(&mut ctx.accounts.recovery.new_members);
+        let threshold = ctx.accounts.recovery.threshold;
+        let settings = &mut ctx.accounts.settings;
+
+        let wallet_operations =
+            settings.apply_recovery(new_members, threshold, ctx.remaining_accounts)?;
+
+        resize_account_if_necessary(
+            settings.as_ref(),
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            Settings::size(settings.get_members()?.len()),
+        )?;
+
+        settings.invariant()?;
+
+        User::process_user_wallet_operations(
+            wallet_operations,
+            settings.index,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            ctx.remaining_accounts,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{
+    state::{Recovery, Settings},
+    utils::{SyncSignerArgs, TransactionSyncSigners, SEED_RECOVERY},
+    AddMemberArgs, SEED_MULTISIG,
+};
+use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
+
+#[derive(Accounts)]
+#[instruction(new_members: Vec<AddMemberArgs>)]
+pub struct RecoveryInitiate<'info> {
+    #[account(mut)]
+    pub settings: Account<'info, Settings>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = Recovery::size(new_members.len()),
+        seeds = [
+            SEED_MULTISIG,
+            settings.key().as_ref(),
+            SEED_RECOVERY,
+        ],
+        bump
+    )]
+    pub recovery: Account<'info, Recovery>,
+    pub system_program: Program<'info, System>,
+    /// CHECK:
+    #[account(
+        address = SlotHashes::id()
+    )]
+    pub slot_hash_sysvar: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    #[account(
+        address = Instructions::id(),
+    )]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl<'info> RecoveryInitiate<'info> {
+    fn validate(
+        &self,
+        ctx: &Context<'info, Self>,
+        new_members: &[AddMemberArgs],
+        threshold: u8,
+        signers: &[TransactionSyncSigners],
+    ) -> Result<()> {
+        let Self {
+            settings,
+            slot_hash_sysvar,
+            instructions_sysvar,
+            ..
+        } = self;
+
+        let message_hash = Recovery::hash_recovery_args(new_members, threshold)?;
+
+        TransactionSyncSigners::verify_guardians(
+            signers,
+            &SyncSignerArgs {
+                remaining_accounts: ctx.remaining_accounts,
+                instructions_sysvar,
+                slot_hash_sysvar,
+                settings,
+            },
+            message_hash,
+        )?;
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&ctx, &new_members, threshold, &signers))]
+    pub fn process(
+        ctx: Context<'info, Self>,
+        new_members: Vec<AddMemberArgs>,
+        threshold: u8,
+        signers: Vec<TransactionSyncSigners>,
+    ) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+
+        ctx.accounts.recovery.init(
+            settings.key(),
+            ctx.accounts.payer.key(),
+            new_members,
+            threshold,
+            settings,
+            ctx.bumps.recovery,
+        )?;
+
+        let slot_numbers = TransactionSyncSigners::collect_slot_numbers(&signers);
+        settings.latest_slot_number_check(&slot_numbers, &ctx.accounts.slot_hash_sysvar)?;
+
+        settings.invariant()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    state::{DomainConfig, Recovery, Settings},
+    utils::{
+        ChallengeArgs, MultisigSettings, Secp256k1VerifyArgs, Secp256r1VerifyArgs, SignerArgs,
+        TransactionActionType, TransactionBufferSigners, VetoSigners,
+    },
+    MultisigError,
+};
+use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
+
+#[derive(Accounts)]
+pub struct RecoveryVeto<'info> {
+    #[account(
+        mut,
+        address = recovery.multi_wallet_settings,
+    )]
+    pub settings: Account<'info, Settings>,
+    /// CHECK:
+    #[account(
+        mut,
+        constraint = payer.key() == recovery.payer @MultisigError::PayerMismatch
+    )]
+    pub payer: UncheckedAccount<'info>,
+    pub domain_config: Option<AccountLoader<'info, DomainConfig>>,
+    /// CHECK: the signer's user account, validated when a passkey signature updates its counter
+    #[account(mut)]
+    pub user_account: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        close = payer,
+    )]
+    pub recovery: Account<'info, Recovery>,
+    pub member: Option<Signer<'info>>,
+    /// CHECK:
+    #[account(
+        address = SlotHashes::id(),
+    )]
+    pub slot_hash_sysvar: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    #[account(
+        address = Instructions::id(),
+    )]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+impl RecoveryVeto<'_> {
+    fn validate(
+        &mut self,
+        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: &Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
+        let Self {
+            settings,
+            domain_config,
+            user_account,
+            recovery,
+            member,
+            slot_hash_sysvar,
+            instructions_sysvar,
+            ..
+        } = self;
+
+        recovery.check_vetoable(Clock::get()?.unix_timestamp as u64, settings.config_version)?;
+
+        VetoSigners::verify(
+            &SignerArgs {
+                signer: member,
+                secp256r1_verify_args,
+                secp256k1_verify_args,
+                instructions_sysvar: Some(instructions_sysvar),
+                slot_hash_sysvar,
+                domain_config,
+                user_account,
+            },
+            settings.get_members()?,
+            ChallengeArgs {
+                account: recovery.multi_wallet_settings,
+                message_hash: recovery.message_hash()?,
+                action_type: TransactionActionType::VetoRecovery,
+            },
+        )?;
+
+        let slot_numbers = TransactionBufferSigners::collect_slot_numbers(
+            secp256r1_verify_args,
+            secp256k1_verify_args,
+        );
+        settings.latest_slot_number_check(&slot_numbers, slot_hash_sysvar)?;
+        settings.invariant()?;
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate(&secp256r1_verify_args, &secp256k1_verify_args))]
+    pub fn process(
+        ctx: Context<Self>,
+        secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
-    utils::TransactionBufferSigners, DomainConfig, MultisigError, Secp256r1VerifyArgs, Settings,
-    TransactionBuffer,
+    utils::{MultisigSettings, SignerArgs, TransactionBufferSigners},
+    DomainConfig, MultisigError, Secp256k1VerifyArgs, Secp256r1VerifyArgs, Settings,
+    TransactionActionType, TransactionBuffer,
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
 
@@ -18,6 +19,9 @@ pub struct TransactionBufferClose<'info> {
         )]
     pub payer: UncheckedAccount<'info>,
     pub domain_config: Option<AccountLoader<'info, DomainConfig>>,
+    /// CHECK: the signer's user account, validated when a passkey signature updates its counter
+    #[account(mut)]
+    pub user_account: Option<UncheckedAccount<'info>>,
     #[account(
         mut,
         close = payer,
@@ -37,41 +41,65 @@ pub struct TransactionBufferClose<'info> {
 }
 
 impl TransactionBufferClose<'_> {
-    fn validate(&mut self, secp256r1_verify_args: &Option<Secp256r1VerifyArgs>) -> Result<()> {
+    fn validate(
+        &mut self,
+        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: &Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
         let Self {
             closer,
             transaction_buffer,
             domain_config,
+            user_account,
             slot_hash_sysvar,
             instructions_sysvar,
             settings,
             ..
         } = self;
-        TransactionBufferSigners::verify_close(
-            closer,
-            secp256r1_verify_args,
-            instructions_sysvar,
-            slot_hash_sysvar,
-            domain_config,
-            &transaction_buffer.creator,
-            &transaction_buffer.payer,
-            transaction_buffer.multi_wallet_settings,
-            transaction_buffer.final_buffer_hash,
-            transaction_buffer.valid_till,
-        )?;
 
-        let slot_numbers = TransactionBufferSigners::collect_slot_numbers(&secp256r1_verify_args);
+        transaction_buffer.check_not_started()?;
+
+        // Anyone may close a buffer once rejections make its approval threshold unreachable
+        let is_rejected = transaction_buffer.is_rejected(
+            settings.get_members()?,
+            settings.get_threshold_for_action(TransactionActionType::Execute)?,
+        );
+
+        if !is_rejected {
+            TransactionBufferSigners::verify_close(
+                &SignerArgs {
+                    signer: closer,
+                    secp256r1_verify_args,
+                    secp256k1_verify_args,
+                    instructions_sysvar: instructions_sysvar.as_ref(),
+                    slot_hash_sysvar,
+                    domain_config,
+                    user_account,
+                },
+                &transaction_buffer.creator,
+                &transaction_buffer.payer,
+                transaction_buffer.multi_wallet_settings,
+                transaction_buffer.message_hash(),
+                transaction_buffer.is_expired(transaction_buffer.current_time()?),
+            )?;
+        }
+
+        let slot_numbers = TransactionBufferSigners::collect_slot_numbers(
+            &secp256r1_verify_args,
+            secp256k1_verify_args,
+        );
         settings.latest_slot_number_check(&slot_numbers, &slot_hash_sysvar)?;
         settings.invariant()?;
 
         Ok(())
     }
 
-    #[access_control(ctx.accounts.validate(&secp256r1_verify_args))]
+    #[access_control(ctx.accounts.validate(&secp256r1_verify_args, &secp256k1_verify_args))]
     pub fn process(
         ctx: Context<Self>,
         secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
     ) -> Result<()> {
-        Ok(())
+        ctx.accounts.settings.remove_open_transaction_buffer()
     }
 }
@@ -0,0 +1,83 @@
+use crate::{MultisigError, Settings, TransactionBuffer};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct TransactionBufferCloseExpired<'info> {
+    #[account(
+        mut,
+        address = transaction_buffer.multi_wallet_settings,
+    )]
+    pub settings: Account<'info, Settings>,
+    /// CHECK:
+    #[account(
+        mut,
+        constraint = payer.key() == transaction_buffer.payer @MultisigError::PayerMismatch
+    )]
+    pub payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = payer,
+    )]
+    pub transaction_buffer: Account<'info, TransactionBuffer>,
+}
+
+impl<'info> TransactionBufferCloseExpired<'info> {
+    fn validate(&self) -> Result<()> {
+        let transaction_buffer = &self.transaction_buffer;
+        require!(
+            transaction_buffer.is_abandoned(
+                transaction_buffer.current_time()?,
+                self.settings.config_version
+            ),
+            MultisigError::TransactionNotExpired
+        );
+
+        Ok(())
+    }
+
+    #[access_control(ctx.accounts.validate())]
+    pub fn process(ctx: Context<'info, Self>) -> Result<()> {
+        let settings = &mut ctx.accounts.settings;
+        settings.remove_open_transaction_buffer()?;
+
+        // Extra buffers of the same wallet are passed as [transaction_buffer, payer] pairs
+        let remaining_accounts = ctx.remaining_accounts.chunks_exact(2);
+        require!(
+            remaining_accounts.remainder().is_empty(),
+            MultisigError::InvalidNumberOfAccounts
+        );
+        TransactionBuffer::check_distinct_buffers(
+            &ctx.accounts.transaction_buffer.key(),
+            remaining_accounts.clone().map(|pair| pair[0].key),
+        )?;
+
+        for pair in remaining_accounts {
+            let (account_info, payer) = (&pair[0], &pair[1]);
+            require!(
+                account_info.owner.eq(&crate::ID),
+                MultisigError::InvalidAccount
+            );
+            require!(account_info.is_writable, MultisigError::InvalidAccount);
+
+            let transaction_buffer = Account::<TransactionBuffer>::try_from(account_info)?;
+            require!(
+                transaction_buffer.multi_wallet_settings.eq(&settings.key()),
+                MultisigError::InvalidAccount
+            );
+            require!(
+                transaction_buffer
+                    .is_abandoned(transaction_buffer.current_time()?, settings.config_version),
+                MultisigError::TransactionNotExpired
+            );
+            require!(
+                payer.key.eq(&transaction_buffer.payer),
+                MultisigError::PayerMismatch
+            );
+
+            transaction_buffer.close(payer.clone())?;
+            settings.remove_open_transaction_buffer()?;
+        }
+
+        Ok(())
+    }
+}
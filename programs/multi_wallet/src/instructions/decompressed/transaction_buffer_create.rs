@@ -1,7 +1,8 @@
-use crate::utils::TransactionBufferSigners;
+use crate::utils::{SignerArgs, TransactionBufferSigners};
 use crate::{
-    DomainConfig, MemberKey, MultisigError, Permission, Secp256r1VerifyArgs, Settings,
-    TransactionBuffer, TransactionBufferCreateArgs, SEED_MULTISIG, SEED_TRANSACTION_BUFFER,
+    DomainConfig, Member, MemberKey, MultisigError, Permission, Secp256k1VerifyArgs,
+    Secp256r1VerifyArgs, Settings, TransactionBuffer, TransactionBufferCreateArgs, SEED_MULTISIG,
+    SEED_TRANSACTION_BUFFER,
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
 use std::collections::HashSet;
@@ -9,20 +10,23 @@ use std::collections::HashSet;
 #[derive(Accounts)]
 #[instruction(args: TransactionBufferCreateArgs, secp256r1_verify_args: Option<Secp256r1VerifyArgs> )]
 pub struct TransactionBufferCreate<'info> {
+    #[account(mut)]
     pub settings: Account<'info, Settings>,
     #[account(mut)]
     pub payer: Signer<'info>,
     pub domain_config: Option<AccountLoader<'info, DomainConfig>>,
+    /// CHECK: the signer's user account, validated when a passkey signature updates its counter
+    #[account(mut)]
+    pub user_account: Option<UncheckedAccount<'info>>,
     #[account(
         init,
         payer = payer,
-        space = TransactionBuffer::size(args.final_buffer_size, args.buffer_extend_hashes.len(), args.expected_signers.len())?,
+        space = TransactionBuffer::size(0, args.buffer_extend_hashes.len(), args.expected_signers.len())?,
         seeds = [
             SEED_MULTISIG,
             settings.key().as_ref(),
             SEED_TRANSACTION_BUFFER,
-            {&MemberKey::get_signer(&creator, &secp256r1_verify_args, Some(&instructions_sysvar))?.get_seed()?},
-            args.buffer_index.to_le_bytes().as_ref(),
+            settings.transaction_index.to_le_bytes().as_ref(),
         ],
         bump
     )]
@@ -46,28 +50,46 @@ impl TransactionBufferCreate<'_> {
         &self,
         args: &TransactionBufferCreateArgs,
         secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: &Option<Secp256k1VerifyArgs>,
     ) -> Result<()> {
         let Self {
             settings,
             creator,
             domain_config,
+            user_account,
             instructions_sysvar,
             slot_hash_sysvar,
             ..
         } = self;
 
         TransactionBufferSigners::verify_create(
-            creator,
-            secp256r1_verify_args,
-            instructions_sysvar,
-            slot_hash_sysvar,
-            domain_config,
+            &SignerArgs {
+                signer: creator,
+                secp256r1_verify_args,
+                secp256k1_verify_args,
+                instructions_sysvar: Some(instructions_sysvar),
+                slot_hash_sysvar,
+                domain_config,
+                user_account,
+            },
             &settings.members,
             settings.key(),
-            args.final_buffer_hash,
+            TransactionBuffer::compute_message_hash(args.final_buffer_hash, args.vault_index),
             args.preauthorize_execution,
         )?;
 
+        // The account grows on every extend, so only check that the final message will fit
+        TransactionBuffer::size(
+            args.final_buffer_size,
+            args.buffer_extend_hashes.len(),
+            args.expected_signers.len(),
+        )?;
+        TransactionBuffer::validate_chunk_layout(
+            args.final_buffer_size,
+            args.chunk_size,
+            args.buffer_extend_hashes.len(),
+        )?;
+
         let member_keys: HashSet<_> = settings
             .members
             .iter()
@@ -83,38 +105,41 @@ impl TransactionBufferCreate<'_> {
         Ok(())
     }
 
-    #[access_control(ctx.accounts.validate(&args, &secp256r1_verify_args))]
+    #[access_control(ctx.accounts.validate(&args, &secp256r1_verify_args, &secp256k1_verify_args))]
     pub fn process(
         ctx: Context<Self>,
         args: TransactionBufferCreateArgs,
         secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
     ) -> Result<()> {
+        let transaction_index = ctx.accounts.settings.allocate_transaction_index()?;
+        ctx.accounts.settings.add_open_transaction_buffer()?;
+
         let transaction_buffer = &mut ctx.accounts.transaction_buffer;
         let settings = &ctx.accounts.settings;
         let signer: MemberKey = MemberKey::get_signer(
             &ctx.accounts.creator,
             &secp256r1_verify_args,
+            &secp256k1_verify_args,
             Some(&ctx.accounts.instructions_sysvar),
         )?;
 
         transaction_buffer.init(
             ctx.accounts.settings.key(),
-            settings.multi_wallet_bump,
+            settings,
             ctx.accounts.payer.key(),
+            transaction_index,
             args,
             ctx.bumps.transaction_buffer,
         )?;
 
-        transaction_buffer.add_initiator(signer)?;
-
-        let member = settings
-            .members
-            .iter()
-            .find(|x| x.pubkey.eq(&signer))
+        let member = Member::find_signer(&settings.members, &signer)
             .ok_or(MultisigError::InvalidArguments)?;
 
+        transaction_buffer.add_initiator(member.pubkey)?;
+
         if member.permissions.has(Permission::VoteTransaction) {
-            transaction_buffer.add_voter(&signer)?;
+            transaction_buffer.add_voter(&member.pubkey)?;
         }
 
         transaction_buffer.invariant()?;
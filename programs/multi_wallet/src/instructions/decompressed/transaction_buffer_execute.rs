@@ -1,6 +1,7 @@
 use crate::{
-    utils::{MultisigSettings, TransactionBufferSigners},
-    DomainConfig, MemberKey, MultisigError, Secp256r1VerifyArgs, Settings, TransactionBuffer,
+    utils::{MultisigSettings, SignerArgs, TransactionBufferSigners},
+    DomainConfig, Member, MemberKey, MultisigError, Secp256k1VerifyArgs, Secp256r1VerifyArgs,
+    Settings, TransactionActionType, TransactionBuffer,
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
 
@@ -12,6 +13,9 @@ pub struct TransactionBufferExecute<'info> {
     )]
     pub settings: Account<'info, Settings>,
     pub domain_config: Option<AccountLoader<'info, DomainConfig>>,
+    /// CHECK: the signer's user account, validated when a passkey signature updates its counter
+    #[account(mut)]
+    pub user_account: Option<UncheckedAccount<'info>>,
     pub executor: Option<Signer<'info>>,
     #[account(mut)]
     pub transaction_buffer: Account<'info, TransactionBuffer>,
@@ -31,49 +35,59 @@ impl<'info> TransactionBufferExecute<'info> {
     fn validate(
         &mut self,
         secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: &Option<Secp256k1VerifyArgs>,
     ) -> Result<Option<MemberKey>> {
         let Self {
             settings,
             transaction_buffer,
             executor,
             domain_config,
+            user_account,
             slot_hash_sysvar,
             instructions_sysvar,
             ..
         } = self;
 
+        transaction_buffer.check_config_version(settings.config_version)?;
+
         let members = settings.get_members()?;
         if transaction_buffer.preauthorize_execution {
-            let vote_count = members
+            let vote_weight: u32 = members
                 .iter()
-                .filter(|x| {
-                    x.permissions.has(crate::Permission::VoteTransaction)
-                        && (transaction_buffer.voters.contains(&x.pubkey))
-                })
-                .count();
+                .filter(|x| transaction_buffer.voters.contains(&x.pubkey))
+                .map(Member::vote_weight)
+                .sum();
 
             require!(
-                vote_count >= settings.get_threshold()? as usize,
+                vote_weight
+                    >= settings.get_threshold_for_action(TransactionActionType::Execute)? as u32,
                 MultisigError::InsufficientSignersWithVotePermission
             );
             return Ok(None);
         }
 
         let signer = TransactionBufferSigners::verify_execute(
-            executor,
-            secp256r1_verify_args,
-            instructions_sysvar,
-            slot_hash_sysvar,
-            domain_config,
+            &SignerArgs {
+                signer: executor,
+                secp256r1_verify_args,
+                secp256k1_verify_args,
+                instructions_sysvar: instructions_sysvar.as_ref(),
+                slot_hash_sysvar,
+                domain_config,
+                user_account,
+            },
             members,
-            settings.get_threshold()?,
+            settings.get_threshold_for_action(TransactionActionType::Execute)?,
             transaction_buffer.multi_wallet_settings,
-            transaction_buffer.final_buffer_hash,
+            transaction_buffer.message_hash(),
             transaction_buffer.voters.as_ref(),
             &transaction_buffer.expected_signers,
         )?;
 
-        let slot_numbers = TransactionBufferSigners::collect_slot_numbers(&secp256r1_verify_args);
+        let slot_numbers = TransactionBufferSigners::collect_slot_numbers(
+            &secp256r1_verify_args,
+            secp256k1_verify_args,
+        );
         settings.latest_slot_number_check(&slot_numbers, &slot_hash_sysvar)?;
         settings.invariant()?;
 
@@ -83,8 +97,11 @@ impl<'info> TransactionBufferExecute<'info> {
     pub fn process(
         ctx: Context<'info, Self>,
         secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
     ) -> Result<()> {
-        let maybe_signer = ctx.accounts.validate(&secp256r1_verify_args)?;
+        let maybe_signer = ctx
+            .accounts
+            .validate(&secp256r1_verify_args, &secp256k1_verify_args)?;
 
         if let Some(signer) = maybe_signer {
             ctx.accounts.transaction_buffer.add_executor(signer)?;
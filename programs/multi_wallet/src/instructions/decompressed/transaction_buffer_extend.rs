@@ -1,4 +1,4 @@
-use crate::{state::Settings, TransactionBuffer};
+use crate::{resize_account_if_necessary, state::Settings, MultisigError, TransactionBuffer};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -7,25 +7,47 @@ pub struct TransactionBufferExtend<'info> {
         address = transaction_buffer.multi_wallet_settings
     )]
     pub settings: Account<'info, Settings>,
+    #[account(
+        mut,
+        constraint = payer.key() == transaction_buffer.payer @MultisigError::PayerMismatch
+    )]
+    pub payer: Signer<'info>,
     #[account(mut)]
     pub transaction_buffer: Account<'info, TransactionBuffer>,
+    pub system_program: Program<'info, System>,
 }
 
 impl TransactionBufferExtend<'_> {
-    fn validate(&self, buffer: &[u8]) -> Result<()> {
-        self.transaction_buffer.validate_extend_chunk(buffer)
+    fn validate(&self, chunk_index: u16, buffer: &[u8]) -> Result<()> {
+        self.transaction_buffer
+            .validate_extend_chunk(chunk_index, buffer)
     }
 
-    #[access_control(ctx.accounts.validate(&buffer))]
-    pub fn process(ctx: Context<Self>, buffer: Vec<u8>) -> Result<()> {
+    #[access_control(ctx.accounts.validate(chunk_index, &buffer))]
+    pub fn process(ctx: Context<Self>, chunk_index: u16, buffer: Vec<u8>) -> Result<()> {
         let transaction_buffer = &mut ctx.accounts.transaction_buffer;
 
-        transaction_buffer.buffer.extend_from_slice(&buffer);
+        // Grow the account up to the end of the chunk, the rent payer funds the extra space.
+        // A chunk written ahead of the others can only grow it as far as one realloc allows.
+        let chunk_end = transaction_buffer.chunk_range(chunk_index)?.end;
+        let new_size = TransactionBuffer::size(
+            u32::try_from(transaction_buffer.buffer.len().max(chunk_end))
+                .map_err(|_| MultisigError::FinalBufferSizeExceeded)?,
+            transaction_buffer.buffer_extend_hashes.len(),
+            transaction_buffer.expected_signers.len(),
+        )?;
+        TransactionBuffer::check_account_growth(
+            transaction_buffer.to_account_info().data_len(),
+            new_size,
+        )?;
+        resize_account_if_necessary(
+            &transaction_buffer.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_size,
+        )?;
 
-        // Remove the first hash that was just consumed
-        if !transaction_buffer.buffer_extend_hashes.is_empty() {
-            transaction_buffer.buffer_extend_hashes.remove(0);
-        }
+        transaction_buffer.write_chunk(chunk_index, &buffer)?;
 
         transaction_buffer.invariant()?;
 
@@ -0,0 +1,99 @@
+use crate::{
+    utils::{MultisigSettings, SignerArgs, TransactionBufferSigners},
+    DomainConfig, MemberKey, Secp256k1VerifyArgs, Secp256r1VerifyArgs, Settings, TransactionBuffer,
+};
+use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
+
+#[derive(Accounts)]
+pub struct TransactionBufferReject<'info> {
+    #[account(
+        mut,
+        address = transaction_buffer.multi_wallet_settings,
+    )]
+    pub settings: Account<'info, Settings>,
+    pub domain_config: Option<AccountLoader<'info, DomainConfig>>,
+    /// CHECK: the signer's user account, validated when a passkey signature updates its counter
+    #[account(mut)]
+    pub user_account: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub transaction_buffer: Account<'info, TransactionBuffer>,
+    pub rejecter: Option<Signer<'info>>,
+    pub system_program: Program<'info, System>,
+    /// CHECK:
+    #[account(
+        address = SlotHashes::id()
+    )]
+    pub slot_hash_sysvar: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    #[account(
+        address = Instructions::id(),
+    )]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+impl TransactionBufferReject<'_> {
+    fn validate(
+        &mut self,
+        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: &Option<Secp256k1VerifyArgs>,
+    ) -> Result<MemberKey> {
+        let Self {
+            settings,
+            rejecter,
+            transaction_buffer,
+            domain_config,
+            user_account,
+            slot_hash_sysvar,
+            instructions_sysvar,
+            ..
+        } = self;
+
+        transaction_buffer.check_config_version(settings.config_version)?;
+        transaction_buffer.check_not_started()?;
+        transaction_buffer.validate_hash()?;
+        transaction_buffer.validate_size()?;
+
+        let signer = TransactionBufferSigners::verify_reject(
+            &SignerArgs {
+                signer: rejecter,
+                secp256r1_verify_args,
+                secp256k1_verify_args,
+                instructions_sysvar: instructions_sysvar.as_ref(),
+                slot_hash_sysvar,
+                domain_config,
+                user_account,
+            },
+            settings.get_members()?,
+            transaction_buffer.multi_wallet_settings,
+            transaction_buffer.message_hash(),
+            &transaction_buffer.expected_signers,
+        )?;
+
+        // Slot numbers only move forward, so a rejection assertion cannot be replayed
+        let slot_numbers = TransactionBufferSigners::collect_slot_numbers(
+            secp256r1_verify_args,
+            secp256k1_verify_args,
+        );
+        settings.latest_slot_number_check(&slot_numbers, slot_hash_sysvar)?;
+        settings.invariant()?;
+
+        Ok(signer)
+    }
+
+    pub fn process(
+        ctx: Context<Self>,
+        secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
+        let signer = ctx
+            .accounts
+            .validate(&secp256r1_verify_args, &secp256k1_verify_args)?;
+        let transaction_buffer = &mut ctx.accounts.transaction_buffer;
+
+        transaction_buffer.add_rejecter(&signer)?;
+
+        transaction_buffer.invariant()?;
+
+        Ok(())
+    }
+}
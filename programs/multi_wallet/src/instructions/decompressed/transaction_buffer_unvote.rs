@@ -0,0 +1,98 @@
+use crate::{
+    utils::{ChallengeArgs, SignerArgs, TransactionBufferSigners},
+    DomainConfig, MemberKey, Secp256k1VerifyArgs, Secp256r1VerifyArgs, Settings,
+    TransactionActionType, TransactionBuffer,
+};
+use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
+
+#[derive(Accounts)]
+pub struct TransactionBufferUnvote<'info> {
+    #[account(
+        mut,
+        address = transaction_buffer.multi_wallet_settings,
+    )]
+    pub settings: Account<'info, Settings>,
+    pub domain_config: Option<AccountLoader<'info, DomainConfig>>,
+    /// CHECK: the signer's user account, validated when a passkey signature updates its counter
+    #[account(mut)]
+    pub user_account: Option<UncheckedAccount<'info>>,
+    #[account(mut)]
+    pub transaction_buffer: Account<'info, TransactionBuffer>,
+    pub voter: Option<Signer<'info>>,
+    /// CHECK:
+    #[account(
+        address = SlotHashes::id()
+    )]
+    pub slot_hash_sysvar: Option<UncheckedAccount<'info>>,
+    /// CHECK:
+    #[account(
+        address = Instructions::id(),
+    )]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+}
+
+impl TransactionBufferUnvote<'_> {
+    fn validate(
+        &mut self,
+        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: &Option<Secp256k1VerifyArgs>,
+    ) -> Result<MemberKey> {
+        let Self {
+            settings,
+            voter,
+            transaction_buffer,
+            domain_config,
+            user_account,
+            slot_hash_sysvar,
+            instructions_sysvar,
+            ..
+        } = self;
+
+        transaction_buffer.check_not_started()?;
+
+        let signer = TransactionBufferSigners::verify_unvote(
+            &SignerArgs {
+                signer: voter,
+                secp256r1_verify_args,
+                secp256k1_verify_args,
+                instructions_sysvar: instructions_sysvar.as_ref(),
+                slot_hash_sysvar,
+                domain_config,
+                user_account,
+            },
+            &transaction_buffer.voters,
+            ChallengeArgs {
+                account: transaction_buffer.multi_wallet_settings,
+                message_hash: transaction_buffer.message_hash(),
+                action_type: TransactionActionType::Unvote,
+            },
+        )?;
+
+        // Slot numbers only move forward, so a withdrawn and recast vote cannot be withdrawn again by replay
+        let slot_numbers = TransactionBufferSigners::collect_slot_numbers(
+            secp256r1_verify_args,
+            secp256k1_verify_args,
+        );
+        settings.latest_slot_number_check(&slot_numbers, slot_hash_sysvar)?;
+        settings.invariant()?;
+
+        Ok(signer)
+    }
+
+    pub fn process(
+        ctx: Context<Self>,
+        secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
+        let signer = ctx
+            .accounts
+            .validate(&secp256r1_verify_args, &secp256k1_verify_args)?;
+        let transaction_buffer = &mut ctx.accounts.transaction_buffer;
+
+        transaction_buffer.remove_voter(&signer)?;
+
+        transaction_buffer.invariant()?;
+
+        Ok(())
+    }
+}
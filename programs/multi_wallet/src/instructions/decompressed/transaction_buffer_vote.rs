@@ -1,16 +1,20 @@
 use crate::{
-    utils::{MultisigSettings, TransactionBufferSigners},
-    DomainConfig, MemberKey, Secp256r1VerifyArgs, Settings, TransactionBuffer,
+    utils::{MultisigSettings, SignerArgs, TransactionBufferSigners},
+    DomainConfig, MemberKey, Secp256k1VerifyArgs, Secp256r1VerifyArgs, Settings, TransactionBuffer,
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
 
 #[derive(Accounts)]
 pub struct TransactionBufferVote<'info> {
     #[account(
+        mut,
         address = transaction_buffer.multi_wallet_settings,
     )]
     pub settings: Account<'info, Settings>,
     pub domain_config: Option<AccountLoader<'info, DomainConfig>>,
+    /// CHECK: the signer's user account, validated when a passkey signature updates its counter
+    #[account(mut)]
+    pub user_account: Option<UncheckedAccount<'info>>,
     #[account(mut)]
     pub transaction_buffer: Account<'info, TransactionBuffer>,
     pub voter: Option<Signer<'info>>,
@@ -28,40 +32,61 @@ pub struct TransactionBufferVote<'info> {
 }
 
 impl TransactionBufferVote<'_> {
-    fn validate(&self, secp256r1_verify_args: &Option<Secp256r1VerifyArgs>) -> Result<MemberKey> {
+    fn validate(
+        &mut self,
+        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: &Option<Secp256k1VerifyArgs>,
+    ) -> Result<MemberKey> {
         let Self {
             settings,
             voter,
             transaction_buffer,
             domain_config,
+            user_account,
             slot_hash_sysvar,
             instructions_sysvar,
             ..
         } = self;
 
+        transaction_buffer.check_config_version(settings.config_version)?;
         transaction_buffer.validate_hash()?;
         transaction_buffer.validate_size()?;
 
         let signer = TransactionBufferSigners::verify_vote(
-            voter,
-            secp256r1_verify_args,
-            instructions_sysvar,
-            slot_hash_sysvar,
-            domain_config,
+            &SignerArgs {
+                signer: voter,
+                secp256r1_verify_args,
+                secp256k1_verify_args,
+                instructions_sysvar: instructions_sysvar.as_ref(),
+                slot_hash_sysvar,
+                domain_config,
+                user_account,
+            },
             settings.get_members()?,
             transaction_buffer.multi_wallet_settings,
-            transaction_buffer.final_buffer_hash,
+            transaction_buffer.message_hash(),
             &transaction_buffer.expected_signers,
         )?;
 
+        // Slot numbers only move forward, so a vote withdrawn through unvote cannot be recast by replay
+        let slot_numbers = TransactionBufferSigners::collect_slot_numbers(
+            secp256r1_verify_args,
+            secp256k1_verify_args,
+        );
+        settings.latest_slot_number_check(&slot_numbers, slot_hash_sysvar)?;
+        settings.invariant()?;
+
         Ok(signer)
     }
 
     pub fn process(
         ctx: Context<Self>,
         secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
     ) -> Result<()> {
-        let signer = ctx.accounts.validate(&secp256r1_verify_args)?;
+        let signer = ctx
+            .accounts
+            .validate(&secp256r1_verify_args, &secp256k1_verify_args)?;
         let transaction_buffer = &mut ctx.accounts.transaction_buffer;
 
         transaction_buffer.add_voter(&signer)?;
@@ -1,6 +1,6 @@
 use crate::{
-    id, state::Settings, utils::SEED_MULTISIG, ExecutableTransactionMessage, MultisigError,
-    TransactionBuffer, VaultTransactionMessage, SEED_VAULT,
+    state::Settings, ExecutableTransactionMessage, MultisigError, TransactionBuffer,
+    VaultSignerSeeds,
 };
 use anchor_lang::prelude::*;
 
@@ -17,28 +17,27 @@ pub struct TransactionExecute<'info> {
         constraint = payer.key() == transaction_buffer.payer @MultisigError::PayerMismatch
     )]
     pub payer: UncheckedAccount<'info>,
-    #[account(
-        mut,
-        close = payer,
-    )]
+    #[account(mut)]
     pub transaction_buffer: Account<'info, TransactionBuffer>,
 }
 
 impl<'info> TransactionExecute<'info> {
     fn validate(&self) -> Result<()> {
         let Self {
-            transaction_buffer, ..
+            transaction_buffer,
+            settings,
+            ..
         } = self;
 
         require!(
             transaction_buffer.can_execute,
             MultisigError::TransactionNotApproved
         );
+        transaction_buffer.check_config_version(settings.config_version)?;
 
-        require!(
-            Clock::get()?.unix_timestamp as u64 <= transaction_buffer.valid_till,
-            MultisigError::TransactionHasExpired
-        );
+        let now = transaction_buffer.current_time()?;
+        transaction_buffer.check_not_before(now)?;
+        transaction_buffer.check_expiry(now)?;
 
         Ok(())
     }
@@ -46,8 +45,7 @@ impl<'info> TransactionExecute<'info> {
     #[access_control(ctx.accounts.validate())]
     pub fn process(ctx: Context<'info, Self>) -> Result<()> {
         let transaction_buffer = &ctx.accounts.transaction_buffer;
-        let vault_transaction_message =
-            VaultTransactionMessage::deserialize(&mut transaction_buffer.buffer.as_slice())?;
+        let vault_transaction_message = transaction_buffer.next_message()?;
         vault_transaction_message.validate()?;
         let num_lookups = vault_transaction_message.address_table_lookups.len();
         let message_end_index = num_lookups + vault_transaction_message.num_all_account_keys();
@@ -62,15 +60,13 @@ impl<'info> TransactionExecute<'info> {
             .get(num_lookups..message_end_index)
             .ok_or(MultisigError::InvalidNumberOfAccounts)?;
 
-        let vault_signer_seed: &[&[u8]] = &[
-            SEED_MULTISIG,
-            transaction_buffer.multi_wallet_settings.as_ref(),
-            SEED_VAULT,
-            &[transaction_buffer.multi_wallet_bump],
-        ];
+        let vault_signer_seeds = VaultSignerSeeds::new(
+            transaction_buffer.multi_wallet_settings,
+            transaction_buffer.vault_index,
+            transaction_buffer.multi_wallet_bump,
+        );
 
-        let vault_pubkey =
-            Pubkey::create_program_address(vault_signer_seed, &id()).map_err(ProgramError::from)?;
+        let vault_pubkey = vault_signer_seeds.address()?;
 
         let executable_message = ExecutableTransactionMessage::new_validated(
             vault_transaction_message,
@@ -81,7 +77,14 @@ impl<'info> TransactionExecute<'info> {
 
         let protected_accounts = &[transaction_buffer.key(), transaction_buffer.payer];
 
-        executable_message.execute_message(vault_signer_seed, protected_accounts)?;
+        executable_message.execute_message(&vault_signer_seeds, protected_accounts)?;
+
+        // A failed step reverts the whole instruction, so the batch never moves past it
+        let transaction_buffer = &mut ctx.accounts.transaction_buffer;
+        if transaction_buffer.advance_batch()? {
+            transaction_buffer.close(ctx.accounts.payer.to_account_info())?;
+            ctx.accounts.settings.remove_open_transaction_buffer()?;
+        }
 
         Ok(())
     }
@@ -1,9 +1,7 @@
 use crate::{
-    id,
-    state::Settings,
-    utils::{MultisigSettings, TransactionSyncSigners},
-    ExecutableTransactionMessage, MultisigError, TransactionActionType, TransactionMessage,
-    SEED_MULTISIG, SEED_VAULT,
+    state::Settings, utils::ChallengeArgs, ExecutableTransactionMessage, MultisigError,
+    SyncSignerArgs, TransactionActionType, TransactionMessage, TransactionSyncSigners,
+    VaultSignerSeeds,
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId};
 use sha2::{Digest, Sha256};
@@ -42,14 +40,17 @@ impl<'info> TransactionExecuteSync<'info> {
 
         TransactionSyncSigners::verify(
             signers,
-            remaining_accounts,
-            instructions_sysvar,
-            slot_hash_sysvar,
-            settings.get_members()?,
-            settings.get_threshold()?,
-            ctx.accounts.settings.key(),
-            message_hash,
-            TransactionActionType::Sync,
+            &SyncSignerArgs {
+                remaining_accounts,
+                instructions_sysvar,
+                slot_hash_sysvar,
+                settings,
+            },
+            ChallengeArgs {
+                account: ctx.accounts.settings.key(),
+                message_hash,
+                action_type: TransactionActionType::Sync,
+            },
         )?;
 
         Ok(())
@@ -58,6 +59,7 @@ impl<'info> TransactionExecuteSync<'info> {
     pub fn process(
         ctx: Context<'info, Self>,
         transaction_message: TransactionMessage,
+        vault_index: u8,
         signers: Vec<TransactionSyncSigners>,
     ) -> Result<()> {
         let vault_transaction_message =
@@ -65,6 +67,7 @@ impl<'info> TransactionExecuteSync<'info> {
         vault_transaction_message.validate()?;
         let mut writer = Vec::new();
         vault_transaction_message.serialize(&mut writer)?;
+        writer.push(vault_index);
         let message_hash =
             Sha256::digest(&writer).into();
         ctx.accounts.verify_signers(&ctx, &signers, message_hash)?;
@@ -82,16 +85,10 @@ impl<'info> TransactionExecuteSync<'info> {
             .ok_or(MultisigError::InvalidNumberOfAccounts)?;
 
         let settings = &ctx.accounts.settings;
-        let settings_key = settings.key();
-        let vault_signer_seed: &[&[u8]] = &[
-            SEED_MULTISIG,
-            settings_key.as_ref(),
-            SEED_VAULT,
-            &[settings.multi_wallet_bump],
-        ];
+        let vault_signer_seeds =
+            VaultSignerSeeds::new(settings.key(), vault_index, settings.multi_wallet_bump);
 
-        let vault_pubkey =
-            Pubkey::create_program_address(vault_signer_seed, &id()).map_err(ProgramError::from)?;
+        let vault_pubkey = vault_signer_seeds.address()?;
 
         let executable_message = ExecutableTransactionMessage::new_validated(
             vault_transaction_message,
@@ -102,7 +99,7 @@ impl<'info> TransactionExecuteSync<'info> {
 
         let protected_accounts = &[];
 
-        executable_message.execute_message(vault_signer_seed, protected_accounts)?;
+        executable_message.execute_message(&vault_signer_seeds, protected_accounts)?;
 
         let slot_numbers = TransactionSyncSigners::collect_slot_numbers(&signers);
         let settings = &mut ctx.accounts.settings;
@@ -110,6 +110,7 @@ impl<'info> CreateDomainUserAccount<'info> {
         user.role = args.role;
         user.wallets = wallets;
         user.transports = Some(args.transports);
+        user.sign_count = 0;
         user.credential_id = Some(args.credential_id);
         user.domain_config = Some(ctx.accounts.domain_config.key());
         user.transaction_manager_url = None;
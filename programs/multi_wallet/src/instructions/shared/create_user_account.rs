@@ -34,6 +34,7 @@ impl<'info> CreateUserAccount<'info> {
         user.role = args.role;
         user.wallets = Vec::new();
         user.transports = None;
+        user.sign_count = 0;
         user.credential_id = None;
         user.domain_config = None;
         user.transaction_manager_url = args.transaction_manager_url;
@@ -8,6 +8,8 @@ use anchor_lang::prelude::*;
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct EditDomainConfigArgs {
     new_origins: Option<Vec<String>>,
+    new_require_user_presence: Option<bool>,
+    new_require_user_verification: Option<bool>,
 }
 
 #[derive(Accounts)]
@@ -50,6 +52,7 @@ impl<'info> EditDomainConfig<'info> {
             user.role = UserRole::Administrator;
             user.wallets = Vec::new();
             user.transports = None;
+            user.sign_count = 0;
             user.credential_id = None;
             user.domain_config = Some(ctx.accounts.domain_config.key());
             user.transaction_manager_url = None;
@@ -67,6 +70,14 @@ impl<'info> EditDomainConfig<'info> {
             domain_config.write_origins(&new_origins)?;
         }
 
+        if let Some(require_user_presence) = args.new_require_user_presence {
+            domain_config.require_user_presence = u8::from(require_user_presence);
+        }
+
+        if let Some(require_user_verification) = args.new_require_user_verification {
+            domain_config.require_user_verification = u8::from(require_user_verification);
+        }
+
         Ok(())
     }
 }
@@ -21,6 +21,7 @@ pub struct EditUserDelegate<'info> {
             let signer: MemberKey = MemberKey::get_signer(
                 &signer,
                 &secp256r1_verify_args,
+                &None,
                 instructions_sysvar.as_ref(),
             )?;
             &signer.get_seed()?
@@ -111,6 +112,7 @@ impl<'info> EditUserDelegate<'info> {
         let signer: MemberKey = MemberKey::get_signer(
             &ctx.accounts.signer,
             &secp256r1_verify_args,
+            &None,
             ctx.accounts.instructions_sysvar.as_ref(),
         )?;
 
@@ -124,7 +126,7 @@ impl<'info> EditUserDelegate<'info> {
             MultisigError::UnexpectedSigner
         );
 
-        if signer.get_type().eq(&crate::utils::KeyType::Secp256r1) {
+        if signer.get_type()?.eq(&crate::utils::KeyType::Secp256r1) {
             let secp256r1_verify_data = secp256r1_verify_args
                 .as_ref()
                 .ok_or(MultisigError::InvalidSecp256r1VerifyArg)?;
@@ -171,9 +173,11 @@ impl<'info> EditUserDelegate<'info> {
             let message_hash =
                 Sha256::digest(&buffer).into();
 
+            let user_account_info = user_account.to_account_info();
             secp256r1_verify_data.verify_webauthn(
                 &ctx.accounts.slot_hash_sysvar,
                 &ctx.accounts.domain_config,
+                Some(&user_account_info),
                 instructions_sysvar,
                 ChallengeArgs {
                     account: account.ok_or(MultisigError::InvalidArguments)?,
@@ -182,6 +186,9 @@ impl<'info> EditUserDelegate<'info> {
                 },
                 &[],
             )?;
+
+            // The signature counter was written straight to the account data
+            user_account.reload()?;
         }
 
         user_account.wallets.iter_mut().for_each(|f| {
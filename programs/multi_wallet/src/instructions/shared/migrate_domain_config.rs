@@ -0,0 +1,46 @@
+use crate::{error::MultisigError, state::DomainConfig, utils::resize_account_if_necessary, ID};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateDomainConfig<'info> {
+    /// CHECK: a domain config from before the authenticator flags, checked in `process`
+    #[account(
+        mut,
+        owner = ID,
+    )]
+    pub domain_config: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateDomainConfig<'info> {
+    pub fn process(ctx: Context<'info, Self>) -> Result<()> {
+        let domain_config = ctx.accounts.domain_config.to_account_info();
+        require!(
+            domain_config
+                .try_borrow_data()?
+                .starts_with(DomainConfig::DISCRIMINATOR),
+            MultisigError::InvalidAccount
+        );
+
+        let old_size = domain_config.data_len();
+        let new_size = DomainConfig::size();
+        if old_size >= new_size {
+            return Ok(());
+        }
+
+        resize_account_if_necessary(
+            &domain_config,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            new_size,
+        )?;
+
+        // Zeroed flags keep the domain's behaviour unchanged: no user presence or verification is
+        // required until the authority opts in through `edit_domain_config`
+        domain_config.try_borrow_mut_data()?[old_size..].fill(0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use crate::{state::Settings, utils::resize_account_if_necessary, ID};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateSettings<'info> {
+    /// CHECK: a settings account from the first deployment, parsed with the legacy layout in `process`
+    #[account(
+        mut,
+        owner = ID,
+    )]
+    pub settings: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateSettings<'info> {
+    pub fn process(ctx: Context<'info, Self>) -> Result<()> {
+        let settings_info = ctx.accounts.settings.to_account_info();
+
+        let settings = {
+            let data = settings_info.try_borrow_data()?;
+            Settings::from_legacy_data(&data, settings_info.key)?
+        };
+
+        resize_account_if_necessary(
+            &settings_info,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            Settings::size(settings.members.len()),
+        )?;
+
+        settings.invariant()?;
+
+        {
+            let mut data = settings_info.try_borrow_mut_data()?;
+            settings.try_serialize(&mut &mut data[..])?;
+        }
+
+        Ok(())
+    }
+}
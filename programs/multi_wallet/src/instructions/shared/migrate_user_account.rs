@@ -0,0 +1,51 @@
+use crate::{state::User, utils::resize_account_if_necessary, ID};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MigrateUserAccount<'info> {
+    /// CHECK: a user account from before `sign_count`, parsed with the legacy layout in `process`
+    #[account(
+        mut,
+        owner = ID,
+    )]
+    pub user_account: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateUserAccount<'info> {
+    pub fn process(ctx: Context<'info, Self>) -> Result<()> {
+        let user_account = ctx.accounts.user_account.to_account_info();
+
+        // Bytes past the legacy layout may be left over from removed wallets, so the counter starts
+        // from zero instead of being read from them
+        let user = {
+            let data = user_account.try_borrow_data()?;
+            User::from_legacy_data(&data)?
+        };
+
+        let new_size = User::size(
+            user.credential_id.as_ref().map_or(0, |f| f.len()),
+            user.transports.as_ref().map_or(0, |f| f.len()),
+            user.transaction_manager_url.as_ref().map_or(0, |f| f.len()),
+            user.wallets.len(),
+        );
+
+        resize_account_if_necessary(
+            &user_account,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            new_size,
+        )?;
+
+        user.invariant()?;
+
+        {
+            let mut data = user_account.try_borrow_mut_data()?;
+            user.try_serialize(&mut &mut data[..])?;
+        }
+
+        Ok(())
+    }
+}
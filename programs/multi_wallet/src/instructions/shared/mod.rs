@@ -13,5 +13,14 @@ pub use edit_user_delegate::*;
 pub mod edit_domain_config;
 pub use edit_domain_config::*;
 
+pub mod migrate_domain_config;
+pub use migrate_domain_config::*;
+
+pub mod migrate_user_account;
+pub use migrate_user_account::*;
+
+pub mod migrate_settings;
+pub use migrate_settings::*;
+
 pub mod create_wallet;
 pub use create_wallet::*;
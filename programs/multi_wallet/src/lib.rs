@@ -97,13 +97,16 @@ pub mod multi_wallet {
     }
 
     /// Creates a new transaction buffer to stage a transaction before execution.
+    /// The account starts without room for the message and grows on every extend, up to
+    /// `MAX_ACCOUNT_SIZE` (64 KiB) including its other fields.
     #[instruction(discriminator = 9)]
     pub fn transaction_buffer_create<'info>(
         ctx: Context<'info, TransactionBufferCreate<'info>>,
         args: TransactionBufferCreateArgs,
         secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
     ) -> Result<()> {
-        TransactionBufferCreate::process(ctx, args, secp256r1_verify_args)
+        TransactionBufferCreate::process(ctx, args, secp256r1_verify_args, secp256k1_verify_args)
     }
 
     /// Signs a transaction buffer to register approval.
@@ -111,17 +114,21 @@ pub mod multi_wallet {
     pub fn transaction_buffer_vote<'info>(
         ctx: Context<'info, TransactionBufferVote<'info>>,
         secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
     ) -> Result<()> {
-        TransactionBufferVote::process(ctx, secp256r1_verify_args)
+        TransactionBufferVote::process(ctx, secp256r1_verify_args, secp256k1_verify_args)
     }
 
-    /// Extends an existing transaction buffer to allow for updated data or additional time.
+    /// Writes a chunk of a transaction buffer at its offset, growing the account to fit it.
+    /// Chunks can be written in any order, as long as a single write grows the account by at most
+    /// 10 KiB, the most an instruction can reallocate.
     #[instruction(discriminator = 11)]
     pub fn transaction_buffer_extend<'info>(
         ctx: Context<'info, TransactionBufferExtend<'info>>,
+        chunk_index: u16,
         buffer: Vec<u8>,
     ) -> Result<()> {
-        TransactionBufferExtend::process(ctx, buffer)
+        TransactionBufferExtend::process(ctx, chunk_index, buffer)
     }
 
     /// Closes and cleans up a transaction buffer.
@@ -129,8 +136,9 @@ pub mod multi_wallet {
     pub fn transaction_buffer_close<'info>(
         ctx: Context<'info, TransactionBufferClose<'info>>,
         secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
     ) -> Result<()> {
-        TransactionBufferClose::process(ctx, secp256r1_verify_args)
+        TransactionBufferClose::process(ctx, secp256r1_verify_args, secp256k1_verify_args)
     }
 
     /// Executes a previously approved transaction buffer.
@@ -138,11 +146,12 @@ pub mod multi_wallet {
     pub fn transaction_buffer_execute<'info>(
         ctx: Context<'info, TransactionBufferExecute<'info>>,
         secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
     ) -> Result<()> {
-        TransactionBufferExecute::process(ctx, secp256r1_verify_args)
+        TransactionBufferExecute::process(ctx, secp256r1_verify_args, secp256k1_verify_args)
     }
 
-    /// Executes a staged transaction from a buffer.
+    /// Executes the next message of a staged transaction from a buffer, closing the buffer after the last one.
     #[instruction(discriminator = 14)]
     pub fn transaction_execute<'info>(
         ctx: Context<'info, TransactionExecute<'info>>,
@@ -155,9 +164,10 @@ pub mod multi_wallet {
     pub fn transaction_execute_sync<'info>(
         ctx: Context<'info, TransactionExecuteSync<'info>>,
         transaction_message: TransactionMessage,
+        vault_index: u8,
         signers: Vec<TransactionSyncSigners>,
     ) -> Result<()> {
-        TransactionExecuteSync::process(ctx, transaction_message, signers)
+        TransactionExecuteSync::process(ctx, transaction_message, vault_index, signers)
     }
 
     /// Creates a new multi-wallet with the specified permissions and ownership.
@@ -175,9 +185,10 @@ pub mod multi_wallet {
     pub fn native_transfer_intent<'info>(
         ctx: Context<'info, NativeTransferIntent<'info>>,
         amount: u64,
+        vault_index: u8,
         signers: Vec<TransactionSyncSigners>,
     ) -> Result<()> {
-        NativeTransferIntent::process(ctx, amount, signers)
+        NativeTransferIntent::process(ctx, amount, vault_index, signers)
     }
 
     /// Creates a token transfer intent with on chain settings.
@@ -186,12 +197,145 @@ pub mod multi_wallet {
     pub fn token_transfer_intent<'info>(
         ctx: Context<'info, TokenTransferIntent<'info>>,
         amount: u64,
+        vault_index: u8,
         signers: Vec<TransactionSyncSigners>,
     ) -> Result<()> {
         TokenTransferIntent::process(
             ctx, 
             amount,
+            vault_index,
             signers,
         )
     }
+
+    /// Proposes configuration changes that can be applied once the wallet's config timelock has elapsed.
+    #[instruction(discriminator = 19)]
+    pub fn config_proposal_create<'info>(
+        ctx: Context<'info, ConfigProposalCreate<'info>>,
+        config_actions: Vec<ConfigAction>,
+        signers: Vec<TransactionSyncSigners>,
+    ) -> Result<()> {
+        ConfigProposalCreate::process(ctx, config_actions, signers)
+    }
+
+    /// Applies a config proposal after its timelock has elapsed. Callable by anyone.
+    #[instruction(discriminator = 20)]
+    pub fn config_proposal_execute<'info>(
+        ctx: Context<'info, ConfigProposalExecute<'info>>,
+    ) -> Result<()> {
+        ConfigProposalExecute::process(ctx)
+    }
+
+    /// Cancels a pending config proposal. Any member with vote permission may cancel before the timelock elapses.
+    #[instruction(discriminator = 21)]
+    pub fn config_proposal_cancel<'info>(
+        ctx: Context<'info, ConfigProposalCancel<'info>>,
+        secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
+        ConfigProposalCancel::process(ctx, secp256r1_verify_args, secp256k1_verify_args)
+    }
+
+    /// Starts a recovery that replaces every non-guardian member. Requires the wallet's guardian threshold to sign.
+    #[instruction(discriminator = 22)]
+    pub fn recovery_initiate<'info>(
+        ctx: Context<'info, RecoveryInitiate<'info>>,
+        new_members: Vec<AddMemberArgs>,
+        threshold: u8,
+        signers: Vec<TransactionSyncSigners>,
+    ) -> Result<()> {
+        RecoveryInitiate::process(ctx, new_members, threshold, signers)
+    }
+
+    /// Applies a pending recovery once its delay has elapsed. Callable by anyone.
+    #[instruction(discriminator = 23)]
+    pub fn recovery_execute<'info>(ctx: Context<'info, RecoveryExecute<'info>>) -> Result<()> {
+        RecoveryExecute::process(ctx)
+    }
+
+    /// Vetoes a pending recovery. Any member with vote permission may veto.
+    #[instruction(discriminator = 24)]
+    pub fn recovery_veto<'info>(
+        ctx: Context<'info, RecoveryVeto<'info>>,
+        secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
+        RecoveryVeto::process(ctx, secp256r1_verify_args, secp256k1_verify_args)
+    }
+
+    /// Closes a wallet: sweeps the vault and the listed sub-vaults to `destination`, detaches it from its members
+    /// and closes the settings and any transaction buffers passed in. A permanent member, if present, must sign.
+    /// Needs the strictest of the wallet's thresholds and is unavailable while a config timelock is set.
+    #[instruction(discriminator = 25)]
+    pub fn close_wallet<'info>(
+        ctx: Context<'info, CloseWallet<'info>>,
+        sub_vault_indices: Vec<u8>,
+        signers: Vec<TransactionSyncSigners>,
+    ) -> Result<()> {
+        CloseWallet::process(ctx, sub_vault_indices, signers)
+    }
+
+    /// Records a member's rejection of a transaction buffer. Once the approval threshold can no
+    /// longer be reached, anyone can close the buffer.
+    #[instruction(discriminator = 26)]
+    pub fn transaction_buffer_reject<'info>(
+        ctx: Context<'info, TransactionBufferReject<'info>>,
+        secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
+        TransactionBufferReject::process(ctx, secp256r1_verify_args, secp256k1_verify_args)
+    }
+
+    /// Withdraws a member's vote, including the creator's own, from a transaction buffer.
+    #[instruction(discriminator = 27)]
+    pub fn transaction_buffer_unvote<'info>(
+        ctx: Context<'info, TransactionBufferUnvote<'info>>,
+        secp256r1_verify_args: Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: Option<Secp256k1VerifyArgs>,
+    ) -> Result<()> {
+        TransactionBufferUnvote::process(ctx, secp256r1_verify_args, secp256k1_verify_args)
+    }
+
+    /// Lets anyone close expired transaction buffers, or ones made stale by a config change, refunding
+    /// each one's payer. Batches that started executing can be closed this way too.
+    /// Further buffers can be closed in the same call by passing `[buffer, payer]` pairs as remaining accounts.
+    #[instruction(discriminator = 28)]
+    pub fn transaction_buffer_close_expired<'info>(
+        ctx: Context<'info, TransactionBufferCloseExpired<'info>>,
+    ) -> Result<()> {
+        TransactionBufferCloseExpired::process(ctx)
+    }
+
+    /// Grows a domain config created before the authenticator flags were added to the current layout.
+    /// Anyone can pay for it; the new flags start disabled.
+    #[instruction(discriminator = 29)]
+    pub fn migrate_domain_config<'info>(
+        ctx: Context<'info, MigrateDomainConfig<'info>>,
+    ) -> Result<()> {
+        MigrateDomainConfig::process(ctx)
+    }
+
+    /// Rewrites a user account created before passkey signature counters were tracked, starting its counter at zero.
+    /// Accounts that already load with the current layout cannot be migrated again.
+    #[instruction(discriminator = 30)]
+    pub fn migrate_user_account<'info>(
+        ctx: Context<'info, MigrateUserAccount<'info>>,
+    ) -> Result<()> {
+        MigrateUserAccount::process(ctx)
+    }
+
+    /// Rewrites a settings account from the first deployment into the current layout. Anyone can pay for it;
+    /// every new field starts at the value that keeps the wallet behaving as before.
+    #[instruction(discriminator = 31)]
+    pub fn migrate_settings<'info>(ctx: Context<'info, MigrateSettings<'info>>) -> Result<()> {
+        MigrateSettings::process(ctx)
+    }
+
+    /// Lets anyone close a config proposal made stale by a later config change, refunding its payer.
+    #[instruction(discriminator = 32)]
+    pub fn config_proposal_close_stale<'info>(
+        ctx: Context<'info, ConfigProposalCloseStale<'info>>,
+    ) -> Result<()> {
+        ConfigProposalCloseStale::process(ctx)
+    }
 }
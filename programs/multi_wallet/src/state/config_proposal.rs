@@ -0,0 +1,165 @@
+use crate::{ConfigAction, MultisigError};
+use anchor_lang::prelude::*;
+use sha2::{Digest, Sha256};
+
+#[account]
+pub struct ConfigProposal {
+    /// The multisig settings this belongs to.
+    pub multi_wallet_settings: Pubkey,
+    /// Payer for the config proposal
+    pub payer: Pubkey,
+    /// proposal bump
+    pub bump: u8,
+    /// Unix timestamp after which the config actions may be applied
+    pub executable_after: u64,
+    /// Settings config version the proposal was created against
+    pub config_version: u64,
+    /// Hash of the serialized config actions
+    pub config_actions_hash: [u8; 32],
+    /// Borsh serialized `Vec<ConfigAction>`
+    pub config_actions: Vec<u8>,
+}
+
+impl ConfigProposal {
+    pub fn size(config_actions_len: usize) -> usize {
+        8  +  // anchor account discriminator
+        32 +  // multisig
+        32 +  // rent_payer
+        1  +  // bump
+        8  +  // executable after
+        8  +  // config_version
+        32 +  // config_actions_hash
+        4 + config_actions_len // config actions
+    }
+
+    pub fn serialize_config_actions(config_actions: &[ConfigAction]) -> Result<Vec<u8>> {
+        let mut writer = Vec::new();
+        config_actions.serialize(&mut writer)?;
+        Ok(writer)
+    }
+
+    pub fn hash_config_actions(config_actions: &[ConfigAction]) -> Result<[u8; 32]> {
+        Ok(Sha256::digest(Self::serialize_config_actions(config_actions)?).into())
+    }
+
+    pub fn init(
+        &mut self,
+        settings_key: Pubkey,
+        payer: Pubkey,
+        config_actions: Vec<u8>,
+        config_timelock: u64,
+        config_version: u64,
+        bump: u8,
+    ) -> Result<()> {
+        self.multi_wallet_settings = settings_key;
+        self.payer = payer;
+        self.bump = bump;
+        self.config_version = config_version;
+        self.config_actions_hash = Sha256::digest(&config_actions).into();
+        self.config_actions = config_actions;
+        self.executable_after = u64::try_from(Clock::get()?.unix_timestamp)
+            .ok()
+            .and_then(|ts| ts.checked_add(config_timelock))
+            .ok_or(MultisigError::InvalidArguments)?;
+        Ok(())
+    }
+
+    pub fn is_executable(&self, now: u64) -> bool {
+        now >= self.executable_after
+    }
+
+    /// A proposal only applies to the config it was created against, any config change in between makes it stale.
+    pub fn is_stale(&self, config_version: u64) -> bool {
+        self.config_version != config_version
+    }
+
+    pub fn check_config_version(&self, config_version: u64) -> Result<()> {
+        require!(
+            !self.is_stale(config_version),
+            MultisigError::ConfigVersionMismatch
+        );
+        Ok(())
+    }
+
+    pub fn config_actions(&self) -> Result<Vec<ConfigAction>> {
+        let computed_hash: [u8; 32] = Sha256::digest(&self.config_actions).into();
+        require!(
+            computed_hash == self.config_actions_hash,
+            MultisigError::ConfigActionsHashMismatch
+        );
+        Ok(Vec::<ConfigAction>::deserialize(
+            &mut self.config_actions.as_slice(),
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_proposal(config_actions: Vec<ConfigAction>, executable_after: u64) -> ConfigProposal {
+        let mut writer = Vec::new();
+        config_actions.serialize(&mut writer).unwrap();
+        ConfigProposal {
+            multi_wallet_settings: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+            bump: 0,
+            executable_after,
+            config_version: 0,
+            config_actions_hash: Sha256::digest(&writer).into(),
+            config_actions: writer,
+        }
+    }
+
+    #[test]
+    fn test_config_proposal_is_executable() {
+        let proposal = mk_proposal(vec![ConfigAction::SetThreshold(2)], 100);
+        assert!(!proposal.is_executable(99));
+        assert!(proposal.is_executable(100));
+        assert!(proposal.is_executable(101));
+    }
+
+    #[test]
+    fn test_config_proposal_config_actions_roundtrip() {
+        let proposal = mk_proposal(
+            vec![
+                ConfigAction::SetThreshold(2),
+                ConfigAction::SetConfigTimelock(60),
+            ],
+            0,
+        );
+        let actions = proposal.config_actions().unwrap();
+        assert_eq!(actions.len(), 2);
+        assert!(matches!(actions[0], ConfigAction::SetThreshold(2)));
+        assert!(matches!(actions[1], ConfigAction::SetConfigTimelock(60)));
+    }
+
+    #[test]
+    fn test_config_proposal_tampered_actions_fail() {
+        let mut proposal = mk_proposal(vec![ConfigAction::SetThreshold(2)], 0);
+        let last = proposal.config_actions.len() - 1;
+        proposal.config_actions[last] = 3;
+        assert!(proposal.config_actions().is_err());
+    }
+
+    #[test]
+    fn test_config_proposal_check_config_version() {
+        let mut proposal = mk_proposal(vec![ConfigAction::SetThreshold(2)], 0);
+        proposal.config_version = 3;
+        assert!(proposal.check_config_version(3).is_ok());
+        assert!(proposal.check_config_version(4).is_err());
+        assert!(!proposal.is_stale(3));
+        assert!(proposal.is_stale(4));
+    }
+
+    #[test]
+    fn test_config_proposal_size() {
+        let proposal = mk_proposal(vec![ConfigAction::SetThreshold(2)], 0);
+        let mut writer = Vec::new();
+        proposal.try_serialize(&mut writer).unwrap();
+        assert_eq!(
+            writer.len(),
+            ConfigProposal::size(proposal.config_actions.len())
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use crate::error::MultisigError;
+use crate::{error::MultisigError, utils::AuthenticatorData};
 use anchor_lang::prelude::*;
 use std::str::from_utf8;
 
@@ -15,11 +15,26 @@ pub struct DomainConfig {
     pub num_origins: u8,
     pub rp_id: [u8; MAX_RP_ID_LEN],
     pub origins: [u8; MAX_ORIGINS_LEN],
+    pub require_user_presence: u8,
+    pub require_user_verification: u8,
 }
 
 impl DomainConfig {
     pub fn size() -> usize {
-        8 + 32 + 32 + 1 + 1 + 1 + 1 + MAX_RP_ID_LEN + MAX_ORIGINS_LEN
+        8 + 32 + 32 + 1 + 1 + 1 + 1 + MAX_RP_ID_LEN + MAX_ORIGINS_LEN + 1 + 1
+    }
+
+    /// Rejects assertions missing the user presence or user verification this domain requires.
+    pub fn check_authenticator_flags(&self, authenticator_data: &AuthenticatorData) -> Result<()> {
+        require!(
+            self.require_user_presence.eq(&0) || authenticator_data.user_present(),
+            MultisigError::UserPresenceRequired
+        );
+        require!(
+            self.require_user_verification.eq(&0) || authenticator_data.user_verified(),
+            MultisigError::UserVerificationRequired
+        );
+        Ok(())
     }
 
     pub fn write_rp_id(&mut self, rp_id: impl AsRef<str>) -> Result<()> {
@@ -120,6 +135,7 @@ impl DomainConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::{FLAG_USER_PRESENT, FLAG_USER_VERIFIED};
 
     fn create_test_domain_config() -> DomainConfig {
         DomainConfig {
@@ -131,9 +147,56 @@ mod tests {
             num_origins: 0,
             rp_id: [0u8; MAX_RP_ID_LEN],
             origins: [0u8; MAX_ORIGINS_LEN],
+            require_user_presence: 0,
+            require_user_verification: 0,
         }
     }
 
+    fn authenticator_data(flags: u8) -> AuthenticatorData {
+        AuthenticatorData {
+            rp_id_hash: [0u8; 32],
+            flags,
+            sign_count: 0,
+        }
+    }
+
+    #[test]
+    fn test_size_matches_layout() {
+        // `migrate_domain_config` grows older accounts to exactly this size before they are loaded
+        assert_eq!(
+            DomainConfig::size(),
+            8 + std::mem::size_of::<DomainConfig>()
+        );
+    }
+
+    #[test]
+    fn test_check_authenticator_flags() {
+        let mut config = create_test_domain_config();
+        config
+            .check_authenticator_flags(&authenticator_data(0))
+            .unwrap();
+
+        config.require_user_presence = 1;
+        let err = config
+            .check_authenticator_flags(&authenticator_data(0))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("UserPresenceRequired"));
+        config
+            .check_authenticator_flags(&authenticator_data(FLAG_USER_PRESENT))
+            .unwrap();
+
+        config.require_user_verification = 1;
+        let err = config
+            .check_authenticator_flags(&authenticator_data(FLAG_USER_PRESENT))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("UserVerificationRequired"));
+        config
+            .check_authenticator_flags(&authenticator_data(FLAG_USER_PRESENT | FLAG_USER_VERIFIED))
+            .unwrap();
+    }
+
     #[test]
     fn test_write_and_parse_rp_id() {
         let mut config = create_test_domain_config();
@@ -12,3 +12,9 @@ pub use domain_config::*;
 
 pub mod global_counter;
 pub use global_counter::*;
+
+pub mod config_proposal;
+pub use config_proposal::*;
+
+pub mod recovery;
+pub use recovery::*;
@@ -0,0 +1,160 @@
+use crate::{AddMemberArgs, MultisigError, Settings};
+use anchor_lang::prelude::*;
+use sha2::{Digest, Sha256};
+
+#[account]
+pub struct Recovery {
+    /// The multisig settings this belongs to.
+    pub multi_wallet_settings: Pubkey,
+    /// Payer for the recovery
+    pub payer: Pubkey,
+    /// recovery bump
+    pub bump: u8,
+    /// Unix timestamp after which the recovery may be executed
+    pub executable_after: u64,
+    /// Settings config version the recovery was initiated against
+    pub config_version: u64,
+    /// Threshold to apply once the member set is replaced
+    pub threshold: u8,
+    /// Members that replace every non-guardian member
+    pub new_members: Vec<AddMemberArgs>,
+}
+
+impl Recovery {
+    pub fn size(new_members_len: usize) -> usize {
+        8  +  // anchor account discriminator
+        32 +  // multisig
+        32 +  // rent_payer
+        1  +  // bump
+        8  +  // executable after
+        8  +  // config_version
+        1  +  // threshold
+        4 + (new_members_len * AddMemberArgs::INIT_SPACE) // new members
+    }
+
+    pub fn hash_recovery_args(new_members: &[AddMemberArgs], threshold: u8) -> Result<[u8; 32]> {
+        let mut writer = Vec::new();
+        new_members.serialize(&mut writer)?;
+        threshold.serialize(&mut writer)?;
+        Ok(Sha256::digest(&writer).into())
+    }
+
+    pub fn init(
+        &mut self,
+        settings_key: Pubkey,
+        payer: Pubkey,
+        new_members: Vec<AddMemberArgs>,
+        threshold: u8,
+        settings: &Settings,
+        bump: u8,
+    ) -> Result<()> {
+        self.multi_wallet_settings = settings_key;
+        self.payer = payer;
+        self.bump = bump;
+        self.config_version = settings.config_version;
+        self.threshold = threshold;
+        self.new_members = new_members;
+        self.executable_after = u64::try_from(Clock::get()?.unix_timestamp)
+            .ok()
+            .and_then(|ts| ts.checked_add(settings.recovery_config.recovery_delay))
+            .ok_or(MultisigError::InvalidArguments)?;
+        Ok(())
+    }
+
+    pub fn is_executable(&self, now: u64) -> bool {
+        now >= self.executable_after
+    }
+
+    /// A recovery only applies to the members and guardians it was initiated under, any config change
+    /// in between makes it stale.
+    pub fn is_stale(&self, config_version: u64) -> bool {
+        self.config_version != config_version
+    }
+
+    pub fn check_config_version(&self, config_version: u64) -> Result<()> {
+        require!(
+            !self.is_stale(config_version),
+            MultisigError::ConfigVersionMismatch
+        );
+        Ok(())
+    }
+
+    /// Vetoes are accepted until the delay elapses. A stale recovery can never execute, so it can be
+    /// vetoed at any time to free the wallet's recovery address.
+    pub fn check_vetoable(&self, now: u64, config_version: u64) -> Result<()> {
+        require!(
+            !self.is_executable(now) || self.is_stale(config_version),
+            MultisigError::RecoveryVetoWindowElapsed
+        );
+        Ok(())
+    }
+
+    pub fn message_hash(&self) -> Result<[u8; 32]> {
+        Self::hash_recovery_args(&self.new_members, self.threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{KeyType, MemberKey, Permissions};
+
+    fn mk_recovery(new_members_len: u8, executable_after: u64) -> Recovery {
+        Recovery {
+            multi_wallet_settings: Pubkey::new_unique(),
+            payer: Pubkey::new_unique(),
+            bump: 0,
+            executable_after,
+            config_version: 3,
+            threshold: 1,
+            new_members: (0..new_members_len)
+                .map(|i| AddMemberArgs {
+                    member_key: MemberKey::new(KeyType::Ed25519, [i; 33]).unwrap(),
+                    permissions: Permissions { mask: 7 },
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_recovery_is_executable() {
+        let recovery = mk_recovery(1, 100);
+        assert!(!recovery.is_executable(99));
+        assert!(recovery.is_executable(100));
+    }
+
+    #[test]
+    fn test_recovery_check_config_version() {
+        let recovery = mk_recovery(1, 100);
+        assert!(recovery.check_config_version(3).is_ok());
+        let err = recovery.check_config_version(4).unwrap_err().to_string();
+        assert!(err.contains("ConfigVersionMismatch"));
+    }
+
+    #[test]
+    fn test_recovery_check_vetoable() {
+        let recovery = mk_recovery(1, 100);
+        assert!(recovery.check_vetoable(99, 3).is_ok());
+        let err = recovery.check_vetoable(100, 3).unwrap_err().to_string();
+        assert!(err.contains("RecoveryVetoWindowElapsed"));
+
+        // A stale recovery stays vetoable after the delay
+        assert!(recovery.check_vetoable(100, 4).is_ok());
+    }
+
+    #[test]
+    fn test_recovery_message_hash_binds_threshold() {
+        let mut recovery = mk_recovery(2, 0);
+        let hash = recovery.message_hash().unwrap();
+        recovery.threshold = 2;
+        assert_ne!(hash, recovery.message_hash().unwrap());
+    }
+
+    #[test]
+    fn test_recovery_size() {
+        let recovery = mk_recovery(3, 0);
+        let mut writer = Vec::new();
+        recovery.try_serialize(&mut writer).unwrap();
+        assert_eq!(writer.len(), Recovery::size(3));
+    }
+}
@@ -1,10 +1,13 @@
 use crate::{
-    error::MultisigError, AddMemberArgs, EditMemberArgs, Member, MemberKey, MultisigSettings,
-    RemoveMemberArgs, SEED_MULTISIG,
+    error::MultisigError, state::UserWalletOperation, utils::UserRole, AddMemberArgs, ConfigAction,
+    EditMemberArgs, EditMemberWeightArgs, Member, MemberKey, MultisigSettings, Permissions,
+    RemoveMemberArgs, DEFAULT_MEMBER_WEIGHT, MAX_TRANSACTION_EXPIRY, SEED_MULTISIG,
+    TRANSACTION_TIME_LIMIT,
 };
 use anchor_lang::prelude::*;
 use std::collections::HashSet;
 
+/// Accounts from the first deployment are upgraded by `migrate_settings`, see "Upgrading the program" in the README.
 #[account]
 pub struct Settings {
     pub index: u128,
@@ -14,6 +17,67 @@ pub struct Settings {
     pub bump: u8,
     pub _padding: u8,
     pub latest_slot_number: u64,
+    pub action_thresholds: ActionThresholds,
+    /// Delay in seconds between proposing and applying a config change. 0 allows immediate changes.
+    pub config_timelock: u64,
+    pub recovery_config: RecoveryConfig,
+    /// Incremented by every applied config action, so transaction buffers created under an older
+    /// member set or threshold can be told apart.
+    pub config_version: u64,
+    /// Seconds a transaction buffer stays executable after creation. 0 falls back to `TRANSACTION_TIME_LIMIT`.
+    pub transaction_expiry: u64,
+    /// Sequence number the next transaction buffer of this wallet is created with, which also seeds
+    /// its address.
+    pub transaction_index: u64,
+    /// Transaction buffers created for this wallet that have not been closed yet.
+    pub open_transaction_buffers: u32,
+    /// Config proposals created for this wallet that have not been executed or cancelled yet.
+    pub open_config_proposals: u32,
+}
+
+/// Per-action overrides of `Settings.threshold`. A value of 0 falls back to `Settings.threshold`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, PartialEq, Clone, Copy, Default, Debug)]
+pub struct ActionThresholds {
+    pub change_config: u8,
+    pub sync: u8,
+    pub execute: u8,
+    pub transfer_intent: u8,
+}
+
+/// Shortest recovery delay an enabled recovery config may use, so voters always get a window to veto.
+pub const MIN_RECOVERY_DELAY: u64 = 24 * 60 * 60;
+
+/// Guardian recovery parameters. A guardian threshold of 0 disables recovery.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, PartialEq, Clone, Copy, Default, Debug)]
+pub struct RecoveryConfig {
+    /// Number of guardians that must sign to initiate a recovery.
+    pub guardian_threshold: u8,
+    /// Delay in seconds before an initiated recovery can be executed, during which voters may veto.
+    pub recovery_delay: u64,
+}
+
+/// `Member` layout from before member weights were added.
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+struct LegacyMember {
+    pubkey: MemberKey,
+    role: u8,
+    permissions: Permissions,
+    _padding: u8,
+    is_delegate: u8,
+}
+
+/// `Settings` layout of the first deployment, read by `migrate_settings`.
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+struct LegacySettings {
+    index: u128,
+    members: Vec<LegacyMember>,
+    threshold: u8,
+    multi_wallet_bump: u8,
+    bump: u8,
+    _padding: u8,
+    latest_slot_number: u64,
 }
 
 impl Settings {
@@ -25,11 +89,25 @@ impl Settings {
         1  + // multi_wallet bump
         1  + // settings bump
         1  + // _padding
-        8 // latest slot number
+        8  + // latest slot number
+        ActionThresholds::INIT_SPACE + // action thresholds
+        8 + // config timelock
+        RecoveryConfig::INIT_SPACE + // recovery config
+        8 + // config version
+        8 + // transaction expiry
+        8 + // transaction index
+        4 + // open transaction buffers
+        4 // open config proposals
     }
     pub fn edit_permissions(&mut self, members: Vec<EditMemberArgs>) -> Result<()> {
         MultisigSettings::edit_permissions(self, members)
     }
+    pub fn edit_weights(&mut self, weights: Vec<EditMemberWeightArgs>) -> Result<()> {
+        MultisigSettings::edit_weights(self, weights)
+    }
+    pub fn rotate_member_key(&mut self, old: MemberKey, new: MemberKey) -> Result<()> {
+        MultisigSettings::rotate_member_key(self, old, new)
+    }
     pub fn add_members(
         &mut self,
         new_members: Vec<AddMemberArgs>,
@@ -49,11 +127,188 @@ impl Settings {
         MultisigSettings::set_threshold(self, value)
     }
 
+    pub fn set_action_thresholds(&mut self, value: ActionThresholds) -> Result<()> {
+        MultisigSettings::set_action_thresholds(self, value)
+    }
+
     pub fn set_members(&mut self, members: Vec<Member>) -> Result<()> {
         MultisigSettings::set_members(self, members)
     }
 
+    /// Applies config actions in order and returns the user wallet operations they imply.
+    pub fn apply_config_actions(
+        &mut self,
+        config_actions: Vec<ConfigAction>,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<Vec<UserWalletOperation>> {
+        let mut wallet_operations: Vec<UserWalletOperation> = Vec::new();
+        for action in config_actions {
+            self.bump_config_version()?;
+            match action {
+                ConfigAction::EditPermissions(members) => {
+                    self.edit_permissions(members)?;
+                }
+                ConfigAction::AddMembers(members) => {
+                    let ops = self.add_members(members, remaining_accounts)?;
+                    wallet_operations.extend(ops.into_iter().map(UserWalletOperation::Add));
+                }
+                ConfigAction::RemoveMembers(members) => {
+                    let ops = self.remove_members(members)?;
+                    wallet_operations.extend(ops.into_iter().map(UserWalletOperation::Remove));
+                }
+                ConfigAction::SetThreshold(new_threshold) => {
+                    self.set_threshold(new_threshold)?;
+                }
+                ConfigAction::EditWeights(weights) => {
+                    self.edit_weights(weights)?;
+                }
+                ConfigAction::SetActionThresholds(action_thresholds) => {
+                    self.set_action_thresholds(action_thresholds)?;
+                }
+                ConfigAction::SetConfigTimelock(config_timelock) => {
+                    self.config_timelock = config_timelock;
+                }
+                ConfigAction::SetRecoveryConfig(recovery_config) => {
+                    self.recovery_config = recovery_config;
+                }
+                ConfigAction::RotateMemberKey { old, new } => {
+                    self.rotate_member_key(old, new)?;
+                    wallet_operations.push(UserWalletOperation::Rotate { old, new });
+                }
+                ConfigAction::SetTransactionExpiry(transaction_expiry) => {
+                    self.transaction_expiry = transaction_expiry;
+                }
+            }
+        }
+        Ok(wallet_operations)
+    }
+
+    /// Replaces every non-guardian member with `new_members` and resets the thresholds,
+    /// returning the user wallet operations this implies. The permanent member is released rather than removed.
+    pub fn apply_recovery(
+        &mut self,
+        new_members: Vec<AddMemberArgs>,
+        threshold: u8,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<Vec<UserWalletOperation>> {
+        let recovered_members: Vec<RemoveMemberArgs> = self
+            .members
+            .iter()
+            .filter(|m| UserRole::from(m.role) != UserRole::Guardian)
+            .map(|m| RemoveMemberArgs {
+                member_key: m.pubkey,
+            })
+            .collect();
+
+        let permanent_member = self
+            .members
+            .iter()
+            .find(|m| UserRole::from(m.role) == UserRole::PermanentMember)
+            .map(|m| m.pubkey);
+
+        let mut wallet_operations: Vec<UserWalletOperation> = Vec::new();
+        let ops = self.remove_members(recovered_members)?;
+        wallet_operations.extend(ops.into_iter().map(|args| {
+            if permanent_member == Some(args.member_key) {
+                UserWalletOperation::Release(args)
+            } else {
+                UserWalletOperation::Remove(args)
+            }
+        }));
+        let ops = self.add_members(new_members, remaining_accounts)?;
+        wallet_operations.extend(ops.into_iter().map(UserWalletOperation::Add));
+
+        self.set_threshold(threshold)?;
+        self.set_action_thresholds(ActionThresholds::default())?;
+        self.bump_config_version()?;
+
+        Ok(wallet_operations)
+    }
+
+    fn bump_config_version(&mut self) -> Result<()> {
+        self.config_version = self
+            .config_version
+            .checked_add(1)
+            .ok_or(MultisigError::InvalidArguments)?;
+        Ok(())
+    }
+
+    /// Reserves the next transaction index for a new transaction buffer.
+    pub fn allocate_transaction_index(&mut self) -> Result<u64> {
+        let transaction_index = self.transaction_index;
+        self.transaction_index = transaction_index
+            .checked_add(1)
+            .ok_or(MultisigError::InvalidArguments)?;
+        Ok(transaction_index)
+    }
+
+    /// Counts a newly created transaction buffer, see `has_open_accounts`.
+    pub fn add_open_transaction_buffer(&mut self) -> Result<()> {
+        self.open_transaction_buffers = self
+            .open_transaction_buffers
+            .checked_add(1)
+            .ok_or(MultisigError::InvalidArguments)?;
+        Ok(())
+    }
+
+    /// Counts a transaction buffer that was closed, whether executed, cancelled or expired.
+    pub fn remove_open_transaction_buffer(&mut self) -> Result<()> {
+        self.open_transaction_buffers = self
+            .open_transaction_buffers
+            .checked_sub(1)
+            .ok_or(MultisigError::InvalidArguments)?;
+        Ok(())
+    }
+
+    /// Counts a newly created config proposal, see `has_open_accounts`.
+    pub fn add_open_config_proposal(&mut self) -> Result<()> {
+        self.open_config_proposals = self
+            .open_config_proposals
+            .checked_add(1)
+            .ok_or(MultisigError::InvalidArguments)?;
+        Ok(())
+    }
+
+    /// Counts a config proposal that was executed or cancelled.
+    pub fn remove_open_config_proposal(&mut self) -> Result<()> {
+        self.open_config_proposals = self
+            .open_config_proposals
+            .checked_sub(1)
+            .ok_or(MultisigError::InvalidArguments)?;
+        Ok(())
+    }
+
+    /// Whether any transaction buffer or config proposal still refers to this wallet. Closing the wallet
+    /// while one is open would strand its rent, since they can only be closed through the settings.
+    pub fn has_open_accounts(&self) -> bool {
+        self.open_transaction_buffers > 0 || self.open_config_proposals > 0
+    }
+
+    /// Expiry window for a new transaction buffer. A creator may ask for a shorter window than the wallet's.
+    pub fn get_transaction_expiry(&self, requested: Option<u64>) -> Result<u64> {
+        let window = if self.transaction_expiry == 0 {
+            TRANSACTION_TIME_LIMIT
+        } else {
+            self.transaction_expiry
+        };
+
+        match requested {
+            Some(requested) => {
+                require!(
+                    requested > 0 && requested <= window,
+                    MultisigError::InvalidTransactionExpiry
+                );
+                Ok(requested)
+            }
+            None => Ok(window),
+        }
+    }
+
     pub fn invariant(&self) -> Result<()> {
+        require!(
+            self.transaction_expiry <= MAX_TRANSACTION_EXPIRY,
+            MultisigError::InvalidTransactionExpiry
+        );
         MultisigSettings::invariant(self)
     }
 
@@ -73,6 +328,65 @@ impl Settings {
         Ok(pubkey)
     }
 
+    /// Stale bytes left by removed members can make a legacy account parse with the current layout, so
+    /// it only counts as migrated if the result is also valid settings stored at their own address.
+    fn uses_current_layout(data: &[u8], key: &Pubkey) -> bool {
+        Settings::try_deserialize(&mut &data[..]).is_ok_and(|settings| {
+            settings.invariant().is_ok()
+                && Settings::get_settings_key_from_index_with_bump(settings.index, settings.bump)
+                    .is_ok_and(|address| address.eq(key))
+        })
+    }
+
+    /// Reads a settings account from the first deployment. Every new field starts at the value that keeps
+    /// the wallet behaving as before: one vote per member, the plain threshold for every action, and no
+    /// timelock, recovery or custom expiry.
+    pub fn from_legacy_data(data: &[u8], key: &Pubkey) -> Result<Settings> {
+        require!(
+            data.starts_with(Settings::DISCRIMINATOR),
+            MultisigError::InvalidAccount
+        );
+        require!(
+            !Settings::uses_current_layout(data, key),
+            MultisigError::AccountAlreadyMigrated
+        );
+
+        let legacy = LegacySettings::deserialize(&mut &data[Settings::DISCRIMINATOR.len()..])?;
+        require!(
+            Settings::get_settings_key_from_index_with_bump(legacy.index, legacy.bump)?.eq(key),
+            MultisigError::InvalidAccount
+        );
+
+        Ok(Settings {
+            index: legacy.index,
+            members: legacy
+                .members
+                .into_iter()
+                .map(|member| Member {
+                    pubkey: member.pubkey,
+                    role: member.role,
+                    permissions: member.permissions,
+                    _padding: member._padding,
+                    is_delegate: member.is_delegate,
+                    weight: DEFAULT_MEMBER_WEIGHT,
+                })
+                .collect(),
+            threshold: legacy.threshold,
+            multi_wallet_bump: legacy.multi_wallet_bump,
+            bump: legacy.bump,
+            _padding: legacy._padding,
+            latest_slot_number: legacy.latest_slot_number,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        })
+    }
+
     pub fn get_settings_key_from_index(index: u128) -> Result<Pubkey> {
         let index_bytes = index.to_le_bytes();
         let signer_seeds: &[&[u8]] = &[SEED_MULTISIG, index_bytes.as_ref()];
@@ -104,6 +418,19 @@ impl MultisigSettings for Settings {
         Ok(self.threshold)
     }
 
+    fn set_action_thresholds(&mut self, value: ActionThresholds) -> Result<()> {
+        self.action_thresholds = value;
+        Ok(())
+    }
+
+    fn get_action_thresholds(&self) -> Result<ActionThresholds> {
+        Ok(self.action_thresholds)
+    }
+
+    fn get_recovery_config(&self) -> Result<RecoveryConfig> {
+        Ok(self.recovery_config)
+    }
+
     fn get_members(&self) -> Result<&[Member]> {
         Ok(self.members.as_slice())
     }
@@ -131,7 +458,9 @@ impl MultisigSettings for Settings {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{KeyType, Permission, Permissions, UserRole};
+    use crate::{
+        KeyType, Permission, Permissions, Secp256r1Pubkey, TransactionActionType, UserRole,
+    };
 
     fn mk_ed25519_member(
         idx: u8,
@@ -152,6 +481,7 @@ mod tests {
             permissions: Permissions::from_permissions(perms),
             _padding: 0,
             is_delegate: if is_delegate { 1 } else { 0 },
+            weight: 1,
         }
     }
 
@@ -169,9 +499,79 @@ mod tests {
             permissions: Permissions::from_permissions(perms),
             _padding: 0,
             is_delegate: 0,
+            weight: 1,
         }
     }
 
+    fn mk_legacy_settings_data(stale_bytes: usize) -> (Vec<u8>, Pubkey) {
+        let index = 7u128;
+        let (key, bump) =
+            Pubkey::find_program_address(&[SEED_MULTISIG, &index.to_le_bytes()], &crate::ID);
+        let member = mk_ed25519_member(
+            1,
+            vec![
+                Permission::InitiateTransaction,
+                Permission::VoteTransaction,
+                Permission::ExecuteTransaction,
+            ],
+            UserRole::Member,
+            false,
+        );
+        let legacy = LegacySettings {
+            index,
+            members: vec![LegacyMember {
+                pubkey: member.pubkey,
+                role: member.role,
+                permissions: member.permissions,
+                _padding: 0,
+                is_delegate: 0,
+            }],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump,
+            _padding: 0,
+            latest_slot_number: 5,
+        };
+        let mut data = Settings::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(data.len() + stale_bytes, 0);
+        (data, key)
+    }
+
+    #[test]
+    fn test_from_legacy_data() {
+        let (data, key) = mk_legacy_settings_data(0);
+        let settings = Settings::from_legacy_data(&data, &key).unwrap();
+        assert_eq!(settings.members[0].weight, DEFAULT_MEMBER_WEIGHT);
+        assert_eq!(settings.threshold, 1);
+        assert_eq!(settings.latest_slot_number, 5);
+        assert!(settings.invariant().is_ok());
+
+        // Spare bytes after the legacy content do not make it look migrated
+        let (data, key) = mk_legacy_settings_data(200);
+        assert!(Settings::from_legacy_data(&data, &key).is_ok());
+
+        let err = Settings::from_legacy_data(&data, &Pubkey::new_unique())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("InvalidAccount"));
+    }
+
+    #[test]
+    fn test_from_legacy_data_twice_fails() {
+        let (data, key) = mk_legacy_settings_data(0);
+        let settings = Settings::from_legacy_data(&data, &key).unwrap();
+
+        let mut migrated = Vec::new();
+        settings.try_serialize(&mut migrated).unwrap();
+        let err = Settings::from_legacy_data(&migrated, &key)
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("AccountAlreadyMigrated"));
+    }
+
     #[test]
     fn test_invariant_valid_minimal() {
         let settings = Settings {
@@ -191,6 +591,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_ok());
     }
@@ -222,6 +630,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_ok());
     }
@@ -236,6 +652,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -259,6 +683,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -283,6 +715,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -306,6 +746,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -325,6 +773,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -344,6 +800,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -367,6 +831,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -390,6 +862,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_ok());
     }
@@ -413,6 +893,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -444,6 +932,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_ok());
     }
@@ -475,6 +971,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -505,6 +1009,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -540,6 +1052,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_ok());
     }
@@ -574,6 +1094,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -609,6 +1137,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -646,6 +1182,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
@@ -681,10 +1225,798 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        assert!(settings.invariant().is_err());
+    }
+
+    #[test]
+    fn test_invariant_threshold_uses_vote_weight() {
+        let mut heavy = mk_ed25519_member(
+            1,
+            vec![
+                Permission::InitiateTransaction,
+                Permission::VoteTransaction,
+                Permission::ExecuteTransaction,
+            ],
+            UserRole::Member,
+            false,
+        );
+        heavy.weight = 2;
+        let mut settings = Settings {
+            index: 0,
+            members: vec![
+                heavy,
+                mk_ed25519_member(
+                    2,
+                    vec![Permission::VoteTransaction],
+                    UserRole::Member,
+                    false,
+                ),
+            ],
+            threshold: 3,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        assert!(settings.invariant().is_ok());
+
+        settings.threshold = 4;
+        assert!(settings.invariant().is_err());
+    }
+
+    #[test]
+    fn test_invariant_zero_weight_voter_fails() {
+        let mut member = mk_ed25519_member(
+            1,
+            vec![
+                Permission::InitiateTransaction,
+                Permission::VoteTransaction,
+                Permission::ExecuteTransaction,
+            ],
+            UserRole::Member,
+            false,
+        );
+        member.weight = 0;
+        let settings = Settings {
+            index: 0,
+            members: vec![member],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         assert!(settings.invariant().is_err());
     }
 
+    #[test]
+    fn test_edit_weights() {
+        let member = mk_ed25519_member(
+            1,
+            vec![
+                Permission::InitiateTransaction,
+                Permission::VoteTransaction,
+                Permission::ExecuteTransaction,
+            ],
+            UserRole::Member,
+            false,
+        );
+        let mut settings = Settings {
+            index: 0,
+            members: vec![member],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        settings
+            .edit_weights(vec![EditMemberWeightArgs {
+                member_key: member.pubkey,
+                weight: 5,
+            }])
+            .unwrap();
+        assert_eq!(settings.members[0].weight, 5);
+
+        let unknown = mk_ed25519_member(9, vec![], UserRole::Member, false);
+        assert!(settings
+            .edit_weights(vec![EditMemberWeightArgs {
+                member_key: unknown.pubkey,
+                weight: 5,
+            }])
+            .is_err());
+    }
+
+    #[test]
+    fn test_rotate_member_key_keeps_member_attributes() {
+        let mut member = mk_secp256r1_member(
+            1,
+            vec![
+                Permission::InitiateTransaction,
+                Permission::VoteTransaction,
+                Permission::ExecuteTransaction,
+            ],
+            UserRole::PermanentMember,
+        );
+        member.is_delegate = 1;
+        member.weight = 3;
+        let other = mk_ed25519_member(2, vec![], UserRole::Guardian, false);
+        let mut settings = Settings {
+            index: 0,
+            members: vec![member, other],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+
+        let new_key = mk_secp256r1_member(7, vec![], UserRole::Member).pubkey;
+        settings.rotate_member_key(member.pubkey, new_key).unwrap();
+        assert_eq!(settings.members[0].pubkey, new_key);
+        assert_eq!(settings.members[0].permissions, member.permissions);
+        assert_eq!(settings.members[0].role, member.role);
+        assert_eq!(settings.members[0].is_delegate, 1);
+        assert_eq!(settings.members[0].weight, 3);
+        assert!(settings.invariant().is_ok());
+
+        // old key is gone, rotating onto an existing member is rejected
+        assert!(settings.rotate_member_key(member.pubkey, new_key).is_err());
+        assert!(settings.rotate_member_key(new_key, other.pubkey).is_err());
+
+        // the key type cannot change
+        let ed25519_key = mk_ed25519_member(8, vec![], UserRole::Member, false).pubkey;
+        let err = settings
+            .rotate_member_key(new_key, ed25519_key)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("MemberKeyTypeMismatch"));
+    }
+
+    #[test]
+    fn test_apply_config_actions_bumps_config_version() {
+        let mut settings = Settings {
+            index: 0,
+            members: vec![
+                mk_ed25519_member(
+                    1,
+                    vec![
+                        Permission::InitiateTransaction,
+                        Permission::VoteTransaction,
+                        Permission::ExecuteTransaction,
+                    ],
+                    UserRole::Member,
+                    false,
+                ),
+                mk_ed25519_member(
+                    2,
+                    vec![Permission::VoteTransaction],
+                    UserRole::Member,
+                    false,
+                ),
+            ],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+
+        settings
+            .apply_config_actions(
+                vec![
+                    ConfigAction::SetThreshold(2),
+                    ConfigAction::SetConfigTimelock(60),
+                ],
+                &[],
+            )
+            .unwrap();
+        assert_eq!(settings.config_version, 2);
+
+        settings
+            .apply_config_actions(vec![ConfigAction::SetThreshold(1)], &[])
+            .unwrap();
+        assert_eq!(settings.config_version, 3);
+    }
+
+    #[test]
+    fn test_get_transaction_expiry() {
+        let mut settings = Settings {
+            index: 0,
+            members: vec![],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        assert_eq!(
+            settings.get_transaction_expiry(None).unwrap(),
+            TRANSACTION_TIME_LIMIT
+        );
+
+        settings
+            .apply_config_actions(vec![ConfigAction::SetTransactionExpiry(86_400)], &[])
+            .unwrap();
+        assert_eq!(settings.get_transaction_expiry(None).unwrap(), 86_400);
+        assert_eq!(settings.get_transaction_expiry(Some(60)).unwrap(), 60);
+
+        for requested in [0, 86_401] {
+            let err = settings
+                .get_transaction_expiry(Some(requested))
+                .unwrap_err()
+                .to_string();
+            assert!(err.contains("InvalidTransactionExpiry"));
+        }
+    }
+
+    #[test]
+    fn test_invariant_transaction_expiry_above_maximum_fails() {
+        let mut settings = Settings {
+            index: 0,
+            members: vec![mk_ed25519_member(
+                1,
+                vec![
+                    Permission::InitiateTransaction,
+                    Permission::VoteTransaction,
+                    Permission::ExecuteTransaction,
+                ],
+                UserRole::Member,
+                false,
+            )],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: MAX_TRANSACTION_EXPIRY,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        assert!(settings.invariant().is_ok());
+
+        settings.transaction_expiry = MAX_TRANSACTION_EXPIRY + 1;
+        let err = settings.invariant().unwrap_err().to_string();
+        assert!(err.contains("InvalidTransactionExpiry"));
+    }
+
+    #[test]
+    fn test_allocate_transaction_index() {
+        let mut settings = Settings {
+            index: 0,
+            members: vec![],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        assert_eq!(settings.allocate_transaction_index().unwrap(), 0);
+        assert_eq!(settings.allocate_transaction_index().unwrap(), 1);
+        assert_eq!(settings.transaction_index, 2);
+
+        settings.transaction_index = u64::MAX;
+        assert!(settings.allocate_transaction_index().is_err());
+    }
+
+    #[test]
+    fn test_open_account_counters() {
+        let mut settings = Settings {
+            index: 0,
+            members: vec![],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        assert!(!settings.has_open_accounts());
+        assert!(settings.remove_open_transaction_buffer().is_err());
+        assert!(settings.remove_open_config_proposal().is_err());
+
+        settings.add_open_transaction_buffer().unwrap();
+        settings.add_open_config_proposal().unwrap();
+        assert!(settings.has_open_accounts());
+
+        settings.remove_open_transaction_buffer().unwrap();
+        assert!(settings.has_open_accounts());
+        settings.remove_open_config_proposal().unwrap();
+        assert!(!settings.has_open_accounts());
+    }
+
+    #[test]
+    fn test_get_threshold_for_action_falls_back_to_threshold() {
+        let mut settings = Settings {
+            index: 0,
+            members: vec![],
+            threshold: 2,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds {
+                change_config: 3,
+                sync: 0,
+                execute: 0,
+                transfer_intent: 1,
+            },
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        assert_eq!(
+            settings
+                .get_threshold_for_action(TransactionActionType::ChangeConfig)
+                .unwrap(),
+            3
+        );
+        assert_eq!(
+            settings
+                .get_threshold_for_action(TransactionActionType::TransferIntent)
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            settings
+                .get_threshold_for_action(TransactionActionType::Sync)
+                .unwrap(),
+            2
+        );
+        assert_eq!(
+            settings
+                .get_threshold_for_action(TransactionActionType::Execute)
+                .unwrap(),
+            2
+        );
+
+        settings.action_thresholds.execute = 1;
+        assert_eq!(
+            settings
+                .get_threshold_for_action(TransactionActionType::Execute)
+                .unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_get_threshold_for_close_wallet_uses_strictest_threshold() {
+        let mut settings = Settings {
+            index: 0,
+            members: vec![],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        assert_eq!(
+            settings
+                .get_threshold_for_action(TransactionActionType::CloseWallet)
+                .unwrap(),
+            1
+        );
+
+        // A quorum that cannot change the config cannot close the wallet either
+        settings.action_thresholds.change_config = 3;
+        settings.action_thresholds.transfer_intent = 2;
+        assert_eq!(
+            settings
+                .get_threshold_for_action(TransactionActionType::CloseWallet)
+                .unwrap(),
+            3
+        );
+
+        // Lower overrides never undercut the default threshold
+        settings.threshold = 4;
+        assert_eq!(
+            settings
+                .get_threshold_for_action(TransactionActionType::CloseWallet)
+                .unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_invariant_action_threshold_exceeds_vote_weight_fails() {
+        let mut settings = Settings {
+            index: 0,
+            members: vec![
+                mk_ed25519_member(
+                    1,
+                    vec![
+                        Permission::InitiateTransaction,
+                        Permission::VoteTransaction,
+                        Permission::ExecuteTransaction,
+                    ],
+                    UserRole::Member,
+                    false,
+                ),
+                mk_ed25519_member(
+                    2,
+                    vec![Permission::VoteTransaction],
+                    UserRole::Member,
+                    false,
+                ),
+            ],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds {
+                change_config: 2,
+                sync: 0,
+                execute: 0,
+                transfer_intent: 0,
+            },
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        assert!(settings.invariant().is_ok());
+
+        settings.action_thresholds.change_config = 3;
+        assert!(settings.invariant().is_err());
+    }
+
+    #[test]
+    fn test_invariant_guardian_valid() {
+        let mut settings = Settings {
+            index: 0,
+            members: vec![
+                mk_ed25519_member(
+                    1,
+                    vec![
+                        Permission::InitiateTransaction,
+                        Permission::VoteTransaction,
+                        Permission::ExecuteTransaction,
+                    ],
+                    UserRole::Member,
+                    false,
+                ),
+                mk_ed25519_member(2, vec![], UserRole::Guardian, false),
+                mk_secp256r1_member(3, vec![], UserRole::Guardian),
+            ],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig {
+                guardian_threshold: 2,
+                recovery_delay: 86400,
+            },
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        assert!(settings.invariant().is_ok());
+
+        settings.recovery_config.guardian_threshold = 3;
+        let err = settings.invariant().unwrap_err().to_string();
+        assert!(err.contains("InvalidGuardianThreshold"));
+    }
+
+    #[test]
+    fn test_invariant_recovery_delay_below_minimum_fails() {
+        let mut settings = Settings {
+            index: 0,
+            members: vec![
+                mk_ed25519_member(
+                    1,
+                    vec![
+                        Permission::InitiateTransaction,
+                        Permission::VoteTransaction,
+                        Permission::ExecuteTransaction,
+                    ],
+                    UserRole::Member,
+                    false,
+                ),
+                mk_ed25519_member(2, vec![], UserRole::Guardian, false),
+            ],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig {
+                guardian_threshold: 1,
+                recovery_delay: 0,
+            },
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        let err = settings.invariant().unwrap_err().to_string();
+        assert!(err.contains("RecoveryDelayTooShort"));
+
+        settings.recovery_config.recovery_delay = MIN_RECOVERY_DELAY;
+        assert!(settings.invariant().is_ok());
+
+        // A disabled recovery config does not need a delay
+        settings.recovery_config = RecoveryConfig::default();
+        assert!(settings.invariant().is_ok());
+    }
+
+    #[test]
+    fn test_apply_recovery_releases_permanent_member() {
+        let permanent_member = mk_secp256r1_member(
+            1,
+            vec![
+                Permission::InitiateTransaction,
+                Permission::VoteTransaction,
+                Permission::ExecuteTransaction,
+            ],
+            UserRole::PermanentMember,
+        );
+        let member = mk_ed25519_member(
+            2,
+            vec![Permission::VoteTransaction],
+            UserRole::Member,
+            false,
+        );
+        let guardian = mk_ed25519_member(3, vec![], UserRole::Guardian, false);
+        let mut settings = Settings {
+            index: 0,
+            members: vec![permanent_member, member, guardian],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig {
+                guardian_threshold: 1,
+                recovery_delay: MIN_RECOVERY_DELAY,
+            },
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+
+        let wallet_operations = settings.apply_recovery(vec![], 1, &[]).unwrap();
+        assert_eq!(settings.members, vec![guardian]);
+        assert_eq!(settings.config_version, 1);
+        assert_eq!(wallet_operations.len(), 2);
+        assert!(
+            wallet_operations.contains(&UserWalletOperation::Release(RemoveMemberArgs {
+                member_key: permanent_member.pubkey,
+            }))
+        );
+        assert!(
+            wallet_operations.contains(&UserWalletOperation::Remove(RemoveMemberArgs {
+                member_key: member.pubkey,
+            }))
+        );
+    }
+
+    #[test]
+    fn test_invariant_guardian_with_permissions_fails() {
+        let settings = Settings {
+            index: 0,
+            members: vec![
+                mk_ed25519_member(
+                    1,
+                    vec![
+                        Permission::InitiateTransaction,
+                        Permission::VoteTransaction,
+                        Permission::ExecuteTransaction,
+                    ],
+                    UserRole::Member,
+                    false,
+                ),
+                mk_ed25519_member(
+                    2,
+                    vec![Permission::VoteTransaction],
+                    UserRole::Guardian,
+                    false,
+                ),
+            ],
+            threshold: 1,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        let err = settings.invariant().unwrap_err().to_string();
+        assert!(err.contains("InvalidGuardianConfig"));
+    }
+
+    #[test]
+    fn test_invariant_delegated_multi_wallet_member_fails() {
+        let mut nested =
+            mk_ed25519_member(2, vec![Permission::VoteTransaction], UserRole::Member, true);
+        nested.pubkey = MemberKey::convert_multi_wallet(&Pubkey::new_unique()).unwrap();
+        let mut settings = Settings {
+            index: 0,
+            members: vec![
+                mk_ed25519_member(
+                    1,
+                    vec![
+                        Permission::InitiateTransaction,
+                        Permission::VoteTransaction,
+                        Permission::ExecuteTransaction,
+                    ],
+                    UserRole::Member,
+                    false,
+                ),
+                nested,
+            ],
+            threshold: 2,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        let err = settings.invariant().unwrap_err().to_string();
+        assert!(err.contains("InvalidMultiWalletMemberConfig"));
+
+        settings.members[1].is_delegate = 0;
+        assert!(settings.invariant().is_ok());
+    }
+
+    #[test]
+    fn test_invariant_delegated_secp256r1_raw_member_fails() {
+        let mut raw =
+            mk_ed25519_member(2, vec![Permission::VoteTransaction], UserRole::Member, true);
+        raw.pubkey = MemberKey::convert_secp256r1_raw(&Secp256r1Pubkey([2u8; 33])).unwrap();
+        let mut settings = Settings {
+            index: 0,
+            members: vec![
+                mk_ed25519_member(
+                    1,
+                    vec![
+                        Permission::InitiateTransaction,
+                        Permission::VoteTransaction,
+                        Permission::ExecuteTransaction,
+                    ],
+                    UserRole::Member,
+                    false,
+                ),
+                raw,
+            ],
+            threshold: 2,
+            multi_wallet_bump: 0,
+            bump: 0,
+            _padding: 0,
+            latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
+        };
+        let err = settings.invariant().unwrap_err().to_string();
+        assert!(err.contains("InvalidSecp256r1RawMemberConfig"));
+
+        settings.members[1].is_delegate = 0;
+        assert!(settings.invariant().is_ok());
+    }
+
     #[test]
     fn test_sort_members() {
         let mut settings = Settings {
@@ -708,6 +2040,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number: 0,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         };
         settings.sort_members().unwrap();
         assert_eq!(
@@ -1,24 +1,82 @@
-use crate::{MemberKey, MultisigError};
-use anchor_lang::prelude::*;
+use crate::{Member, MemberKey, MultisigError, Settings, VaultTransactionMessage};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{clock::DEFAULT_MS_PER_SLOT, entrypoint::MAX_PERMITTED_DATA_INCREASE},
+};
 use sha2::{Digest, Sha256};
 use std::collections::HashSet;
 
-// Maximum PDA allocation size in an inner ix is 10240 bytes.
-// 10240 - account contents = 10128 bytes
-pub const MAX_BUFFER_SIZE: usize = 10128;
+// Maximum size an account can grow to.
+// The buffer is created without room for the message and grows through reallocs on every extend,
+// so it is not bound by the 10240 bytes a PDA can be allocated with in an inner ix.
+// This is well below the 10 MiB account limit, which the buffer could never reach in practice:
+// the whole account is deserialized onto the heap by extend and execute, and execute holds the decoded
+// message next to it, so the cap keeps a few copies within the largest heap frame (256 KiB).
+// Buffers above roughly 8 KiB do not fit the default 32 KiB heap: transactions extending or executing
+// them must request a larger heap frame through the compute budget program.
+pub const MAX_ACCOUNT_SIZE: usize = 64 * 1024;
 
-// Maximum amount of time a transaction is considered valid for execution
+// The staged message can never be larger than the account holding it.
+pub const MAX_BUFFER_SIZE: usize = MAX_ACCOUNT_SIZE;
+
+// Default amount of time a transaction is considered valid for execution,
+// used when the wallet does not set its own expiry window
 // 3mins
 pub const TRANSACTION_TIME_LIMIT: u64 = 3 * 60;
 
+// Longest expiry window a wallet can set, 30 days
+pub const MAX_TRANSACTION_EXPIRY: u64 = 30 * 24 * 60 * 60;
+
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct TransactionBufferCreateArgs {
-    pub buffer_index: u8,
+    pub vault_index: u8,
+    /// Number of vault transaction messages stored back to back in the buffer, executed in order.
+    pub message_count: u8,
     pub preauthorize_execution: bool,
     pub buffer_extend_hashes: Vec<[u8; 32]>,
+    /// Size of every chunk but the last, which holds the remainder of the final buffer.
+    pub chunk_size: u32,
     pub final_buffer_hash: [u8; 32],
-    pub final_buffer_size: u16,
+    pub final_buffer_size: u32,
     pub expected_signers: Vec<ExpectedSigner>,
+    /// Expiry window in seconds, shorter than the wallet's. Defaults to the wallet's window.
+    pub expiry: Option<u64>,
+    /// Time before which the transaction cannot be executed, in the unit of `expiry_mode`. The expiry window starts from it.
+    pub not_before: Option<u64>,
+    /// Clock `valid_till` and `not_before` are measured against.
+    pub expiry_mode: ExpiryMode,
+}
+
+/// Clock a transaction buffer's expiry is measured against.
+#[derive(Default, AnchorDeserialize, AnchorSerialize, PartialEq, Clone, Copy, Debug)]
+pub enum ExpiryMode {
+    /// Unix timestamps, in seconds.
+    #[default]
+    Timestamp,
+    /// Slot numbers, the same clock passkey signatures are checked against through `SlotHashes`.
+    Slot,
+}
+
+impl ExpiryMode {
+    /// Current time in this mode's unit.
+    pub fn now(&self, clock: &Clock) -> Result<u64> {
+        match self {
+            ExpiryMode::Timestamp => u64::try_from(clock.unix_timestamp)
+                .map_err(|_| error!(MultisigError::InvalidArguments)),
+            ExpiryMode::Slot => Ok(clock.slot),
+        }
+    }
+
+    /// Converts an expiry window in seconds into this mode's unit.
+    pub fn window(&self, seconds: u64) -> Result<u64> {
+        match self {
+            ExpiryMode::Timestamp => Ok(seconds),
+            ExpiryMode::Slot => seconds
+                .checked_mul(1_000)
+                .map(|ms| ms / DEFAULT_MS_PER_SLOT)
+                .ok_or(error!(MultisigError::InvalidTransactionExpiry)),
+        }
+    }
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, InitSpace)]
@@ -39,24 +97,42 @@ pub struct TransactionBuffer {
     pub preauthorize_execution: bool,
     // Transaction valid till
     pub valid_till: u64,
+    /// Transaction cannot be executed before this time (0 when unscheduled)
+    pub not_before: u64,
+    /// Whether `valid_till` and `not_before` are unix timestamps or slot numbers
+    pub expiry_mode: ExpiryMode,
+    /// Settings config version at creation. Votes and execution are rejected once it changes.
+    pub config_version: u64,
     /// Payer for the transaction buffer
     pub payer: Pubkey,
     /// transaction bump
     pub bump: u8,
-    /// Index to seed address derivation
-    pub buffer_index: u8,
+    /// Wallet-wide sequence number of the transaction, used to seed address derivation
+    pub transaction_index: u64,
+    /// Index of the vault the transaction executes from
+    pub vault_index: u8,
+    /// Number of messages in the batch
+    pub message_count: u8,
+    /// Number of messages of the batch executed so far
+    pub executed_messages: u8,
     /// Hash of the final assembled transaction message.
     pub final_buffer_hash: [u8; 32],
     /// The size of the final assembled transaction message.
-    pub final_buffer_size: u16,
+    pub final_buffer_size: u32,
     /// Member of the Multisig who created the TransactionBuffer.
     pub creator: MemberKey,
     /// Member of the Multisig who executed the TransactionBuffer.
     pub executor: MemberKey,
     /// Buffer hash for all the buffer extend instruction
     pub buffer_extend_hashes: Vec<[u8; 32]>,
+    /// Size of every chunk but the last
+    pub chunk_size: u32,
+    /// Which chunks have been written, in the order of `buffer_extend_hashes`
+    pub written_chunks: Vec<bool>,
     /// Members that voted for this transaction
     pub voters: Vec<MemberKey>,
+    /// Members that rejected this transaction
+    pub rejecters: Vec<MemberKey>,
     /// All Signers that are expected to initiate / vote / execute this transaction (used for off-chain inspection by the transaction manager)
     pub expected_signers: Vec<ExpectedSigner>,
     /// The buffer of the transaction message.
@@ -64,63 +140,103 @@ pub struct TransactionBuffer {
 }
 
 impl TransactionBuffer {
+    /// Expects the buffer's transaction index to already be allocated on `settings`.
     pub fn init(
         &mut self,
         settings_key: Pubkey,
-        multi_wallet_bump: u8,
+        settings: &Settings,
         payer: Pubkey,
+        transaction_index: u64,
         args: TransactionBufferCreateArgs,
         bump: u8,
     ) -> Result<()> {
+        let expiry = args
+            .expiry_mode
+            .window(settings.get_transaction_expiry(args.expiry)?)?;
         let expected_signers = args.expected_signers;
         self.multi_wallet_settings = settings_key;
-        self.multi_wallet_bump = multi_wallet_bump;
+        self.multi_wallet_bump = settings.multi_wallet_bump;
+        self.config_version = settings.config_version;
         self.can_execute = false;
         self.preauthorize_execution = args.preauthorize_execution;
+        self.written_chunks = vec![false; args.buffer_extend_hashes.len()];
         self.buffer_extend_hashes = args.buffer_extend_hashes;
+        self.chunk_size = args.chunk_size;
         self.payer = payer;
-        self.buffer_index = args.buffer_index;
+        self.transaction_index = transaction_index;
+        self.vault_index = args.vault_index;
+        self.message_count = args.message_count;
+        self.executed_messages = 0;
         self.final_buffer_hash = args.final_buffer_hash;
         self.final_buffer_size = args.final_buffer_size;
-        self.buffer = Vec::with_capacity(usize::from(args.final_buffer_size));
+        self.buffer = Vec::new();
         self.bump = bump;
-        self.valid_till = Clock::get()?
-            .unix_timestamp
-            .checked_add(TRANSACTION_TIME_LIMIT as i64)
-            .and_then(|ts| u64::try_from(ts).ok())
-            .ok_or(MultisigError::InvalidArguments)?;
+        let now = args.expiry_mode.now(&Clock::get()?)?;
+        self.expiry_mode = args.expiry_mode;
+        self.not_before = args.not_before.unwrap_or(0);
+        self.valid_till = Self::compute_valid_till(now, self.not_before, expiry)?;
         self.voters = Vec::with_capacity(expected_signers.len());
+        self.rejecters = Vec::with_capacity(expected_signers.len());
         self.expected_signers = expected_signers;
         Ok(())
     }
 
+    /// A scheduled transaction's expiry window only starts once it becomes executable, and must leave
+    /// it a non-empty window to run in.
+    pub fn compute_valid_till(now: u64, not_before: u64, expiry: u64) -> Result<u64> {
+        let valid_till = now
+            .max(not_before)
+            .checked_add(expiry)
+            .ok_or(MultisigError::InvalidTransactionExpiry)?;
+        require!(
+            not_before < valid_till,
+            MultisigError::InvalidTransactionExpiry
+        );
+        Ok(valid_till)
+    }
+
+    /// Account size needed to hold `message_buffer_size` bytes of the message. Creation allocates room for
+    /// none of it, and every extend grows the account by the size of its chunk.
     pub fn size(
-        final_message_buffer_size: u16,
+        message_buffer_size: u32,
         number_of_extend_buffers: usize,
         number_of_expected_signers: usize,
     ) -> Result<usize> {
         // Make sure final size is not greater than MAX_BUFFER_SIZE bytes.
-        if (final_message_buffer_size as usize) > MAX_BUFFER_SIZE {
+        if (message_buffer_size as usize) > MAX_BUFFER_SIZE {
             return err!(MultisigError::FinalBufferSizeExceeded);
         }
-        Ok(
-            8  +  // anchor account discriminator
+        let size = 8  +  // anchor account discriminator
             32 +  // multisig
             1  +  // multi_wallet_bump
             1  +  // can execute
             1  +  // preauthorize_execution
             8  +  // transaction expiry
+            8  +  // not_before
+            1  +  // expiry_mode
+            8  +  // config version
             32 +  // rent_payer
             1  +  // bump
-            1  +  // buffer_index
+            8  +  // transaction_index
+            1  +  // vault_index
+            1  +  // message_count
+            1  +  // executed_messages
             32 +  // final_buffer_hash
-            2  +  // final_buffer_size
+            4  +  // final_buffer_size
             2 * MemberKey::INIT_SPACE +  // creator & executor
             (4 + number_of_extend_buffers * 32 ) + // extend buffer hash
+            4  +  // chunk_size
+            (4 + number_of_extend_buffers) + // written chunks
             (4 + number_of_expected_signers * MemberKey::INIT_SPACE)  +  // maximum number of voters 
+            (4 + number_of_expected_signers * MemberKey::INIT_SPACE)  +  // maximum number of rejecters
             (4 + number_of_expected_signers * ExpectedSigner::INIT_SPACE)  +  // maximum number of expected signers 
-            (4 + usize::from(final_message_buffer_size)), // buffer
-        )
+            (4 + message_buffer_size as usize); // buffer
+
+        // Make sure the account stays within the maximum account size.
+        if size > MAX_ACCOUNT_SIZE {
+            return err!(MultisigError::FinalBufferSizeExceeded);
+        }
+        Ok(size)
     }
 
     /// Checks that every expected signer is either creator, executor, or a voter.
@@ -145,19 +261,28 @@ impl TransactionBuffer {
     pub fn execute(&mut self) -> Result<()> {
         self.validate_hash()?;
         self.validate_size()?;
-        require!(
-            Clock::get()?.unix_timestamp as u64 <= self.valid_till,
-            MultisigError::TransactionHasExpired
-        );
+        self.check_expiry(self.current_time()?)?;
         self.check_expected_signers()?;
 
         self.can_execute = true;
         Ok(())
     }
 
+    /// Hash that members sign off on: the final buffer hash bound to the vault it executes from.
+    pub fn compute_message_hash(final_buffer_hash: [u8; 32], vault_index: u8) -> [u8; 32] {
+        let mut buffer = Vec::with_capacity(33);
+        buffer.extend_from_slice(&final_buffer_hash);
+        buffer.push(vault_index);
+        Sha256::digest(&buffer).into()
+    }
+
+    pub fn message_hash(&self) -> [u8; 32] {
+        Self::compute_message_hash(self.final_buffer_hash, self.vault_index)
+    }
+
     pub fn validate_hash(&self) -> Result<()> {
-        let message_buffer_hash :[u8; 32]=
-            Sha256::digest(&self.buffer).into();
+        self.check_chunks_written()?;
+        let message_buffer_hash: [u8; 32] = Sha256::digest(&self.buffer).into();
         require!(
             message_buffer_hash == self.final_buffer_hash,
             MultisigError::FinalBufferHashMismatch
@@ -165,6 +290,7 @@ impl TransactionBuffer {
         Ok(())
     }
     pub fn validate_size(&self) -> Result<()> {
+        self.check_chunks_written()?;
         require_eq!(
             self.buffer.len(),
             self.final_buffer_size as usize,
@@ -186,17 +312,229 @@ impl TransactionBuffer {
             self.buffer.len() <= self.final_buffer_size as usize,
             MultisigError::FinalBufferSizeMismatch
         );
+        require!(
+            self.written_chunks.len() == self.buffer_extend_hashes.len(),
+            MultisigError::InvalidBuffer
+        );
+        require!(
+            self.message_count > 0 && self.executed_messages <= self.message_count,
+            MultisigError::InvalidTransactionMessage
+        );
+
+        Ok(())
+    }
+
+    /// Deserializes every message of the batch, requiring exactly `message_count` of them to fill the buffer.
+    pub fn messages(&self) -> Result<Vec<VaultTransactionMessage>> {
+        let mut data = self.buffer.as_slice();
+        let mut messages = Vec::with_capacity(usize::from(self.message_count));
+        for _ in 0..self.message_count {
+            messages.push(VaultTransactionMessage::deserialize(&mut data)?);
+        }
+        require!(data.is_empty(), MultisigError::InvalidTransactionMessage);
+        Ok(messages)
+    }
+
+    /// Next message of the batch to execute. Steps only advance after the previous one succeeded.
+    pub fn next_message(&self) -> Result<VaultTransactionMessage> {
+        self.messages()?
+            .into_iter()
+            .nth(usize::from(self.executed_messages))
+            .ok_or(error!(MultisigError::InvalidTransactionMessage))
+    }
+
+    /// Records a successfully executed message, returning whether the whole batch has run.
+    pub fn advance_batch(&mut self) -> Result<bool> {
+        self.executed_messages = self
+            .executed_messages
+            .checked_add(1)
+            .ok_or(MultisigError::InvalidTransactionMessage)?;
+        require!(
+            self.executed_messages <= self.message_count,
+            MultisigError::InvalidTransactionMessage
+        );
+        Ok(self.executed_messages == self.message_count)
+    }
+
+    pub fn check_chunks_written(&self) -> Result<()> {
+        require!(
+            self.written_chunks.iter().all(|written| *written),
+            MultisigError::BufferChunksMissing
+        );
+        Ok(())
+    }
+
+    /// Checks that `number_of_chunks` chunks of `chunk_size` bytes cover the final buffer exactly,
+    /// with only the last one allowed to be shorter.
+    pub fn validate_chunk_layout(
+        final_buffer_size: u32,
+        chunk_size: u32,
+        number_of_chunks: usize,
+    ) -> Result<()> {
+        let final_buffer_size = u64::from(final_buffer_size);
+        let chunk_size = u64::from(chunk_size);
+        let number_of_chunks = number_of_chunks as u64;
+
+        if number_of_chunks == 0 {
+            require!(final_buffer_size == 0, MultisigError::InvalidArguments);
+            return Ok(());
+        }
+
+        require!(
+            chunk_size > 0
+                && (number_of_chunks - 1) * chunk_size < final_buffer_size
+                && final_buffer_size <= number_of_chunks * chunk_size,
+            MultisigError::InvalidArguments
+        );
+        Ok(())
+    }
+
+    /// Byte range of the final buffer that chunk `chunk_index` covers.
+    pub fn chunk_range(&self, chunk_index: u16) -> Result<std::ops::Range<usize>> {
+        require!(
+            usize::from(chunk_index) < self.buffer_extend_hashes.len(),
+            MultisigError::InvalidBuffer
+        );
+        let start = usize::from(chunk_index) * self.chunk_size as usize;
+        let end = (start + self.chunk_size as usize).min(self.final_buffer_size as usize);
+        require!(start < end, MultisigError::InvalidBuffer);
+        Ok(start..end)
+    }
+
+    /// Writes a validated chunk at its offset, growing the buffer with zeroes if earlier chunks are still missing.
+    pub fn write_chunk(&mut self, chunk_index: u16, chunk: &[u8]) -> Result<()> {
+        let range = self.chunk_range(chunk_index)?;
+        if self.buffer.len() < range.end {
+            self.buffer.resize(range.end, 0);
+        }
+        self.buffer[range].copy_from_slice(chunk);
+        self.written_chunks[usize::from(chunk_index)] = true;
+        Ok(())
+    }
+
+    /// Checks that growing the account from `current_size` to `new_size` stays within what a single
+    /// instruction can reallocate, so a chunk far past the others has to wait for the earlier ones.
+    pub fn check_account_growth(current_size: usize, new_size: usize) -> Result<()> {
+        require!(
+            new_size.saturating_sub(current_size) <= MAX_PERMITTED_DATA_INCREASE,
+            MultisigError::BufferGrowthExceeded
+        );
+        Ok(())
+    }
+
+    /// Current time in the unit of the buffer's expiry mode.
+    pub fn current_time(&self) -> Result<u64> {
+        self.expiry_mode.now(&Clock::get()?)
+    }
+
+    /// Checks that `now` is still within the expiry window the buffer was created with.
+    pub fn check_expiry(&self, now: u64) -> Result<()> {
+        require!(!self.is_expired(now), MultisigError::TransactionHasExpired);
+        Ok(())
+    }
+
+    /// Checks that a scheduled transaction has reached its `not_before` time.
+    pub fn check_not_before(&self, now: u64) -> Result<()> {
+        require!(
+            now >= self.not_before,
+            MultisigError::TransactionNotYetExecutable
+        );
+        Ok(())
+    }
+
+    /// Past its expiry window a transaction can no longer run, including the remaining steps of a
+    /// started batch.
+    pub fn is_expired(&self, now: u64) -> bool {
+        now > self.valid_till
+    }
+
+    /// A transaction approved under an older wallet configuration can never run.
+    pub fn is_stale(&self, config_version: u64) -> bool {
+        self.config_version != config_version
+    }
+
+    /// Expired and stale buffers can never run again, so anyone may close them to free their rent,
+    /// even halfway through a batch.
+    pub fn is_abandoned(&self, now: u64, config_version: u64) -> bool {
+        self.is_expired(now) || self.is_stale(config_version)
+    }
+
+    /// Checks that buffers closed together are all distinct, so none is refunded or taken off the
+    /// wallet's open buffer count twice.
+    pub fn check_distinct_buffers<'a>(
+        transaction_buffer: &Pubkey,
+        others: impl IntoIterator<Item = &'a Pubkey>,
+    ) -> Result<()> {
+        let mut seen = HashSet::from([*transaction_buffer]);
+        for key in others {
+            require!(seen.insert(*key), MultisigError::DuplicateTransactionBuffer);
+        }
+        Ok(())
+    }
+
+    /// Once a batch has started executing, members cannot close it, reject it or withdraw their votes
+    /// halfway through. It only closes early once it is abandoned.
+    pub fn check_not_started(&self) -> Result<()> {
+        require!(
+            self.executed_messages == 0,
+            MultisigError::TransactionBatchStarted
+        );
+        Ok(())
+    }
 
+    pub fn check_config_version(&self, config_version: u64) -> Result<()> {
+        require!(
+            !self.is_stale(config_version),
+            MultisigError::ConfigVersionMismatch
+        );
         Ok(())
     }
 
     pub fn add_voter(&mut self, voter: &MemberKey) -> Result<()> {
+        require!(
+            !self.rejecters.contains(voter),
+            MultisigError::ConflictingVote
+        );
         if !self.voters.contains(voter) {
             self.voters.push(*voter);
         }
         Ok(())
     }
 
+    /// Withdraws a vote. An approval already granted by execute is revoked too, so the
+    /// threshold has to be met again before the transaction can run.
+    pub fn remove_voter(&mut self, voter: &MemberKey) -> Result<()> {
+        let index = self
+            .voters
+            .iter()
+            .position(|v| v.eq(voter))
+            .ok_or(MultisigError::VoterNotFound)?;
+        self.voters.remove(index);
+        self.can_execute = false;
+        Ok(())
+    }
+
+    pub fn add_rejecter(&mut self, rejecter: &MemberKey) -> Result<()> {
+        require!(
+            !self.voters.contains(rejecter),
+            MultisigError::ConflictingVote
+        );
+        if !self.rejecters.contains(rejecter) {
+            self.rejecters.push(*rejecter);
+        }
+        Ok(())
+    }
+
+    /// Whether enough vote weight has rejected the transaction that the rest can no longer reach `threshold`.
+    pub fn is_rejected(&self, members: &[Member], threshold: u8) -> bool {
+        let remaining_weight: u32 = members
+            .iter()
+            .filter(|m| !self.rejecters.contains(&m.pubkey))
+            .map(Member::vote_weight)
+            .sum();
+        remaining_weight < u32::from(threshold)
+    }
+
     pub fn add_initiator(&mut self, creator: MemberKey) -> Result<()> {
         self.creator = creator;
         Ok(())
@@ -207,29 +545,24 @@ impl TransactionBuffer {
         Ok(())
     }
 
-    /// Validates that a chunk can be appended (size and hash). Used by extend instruction and unit tests.
-    pub fn validate_extend_chunk(&self, chunk: &[u8]) -> Result<()> {
-        let current_buffer_size =
-            u16::try_from(self.buffer.len()).map_err(|_| MultisigError::FinalBufferSizeExceeded)?;
-        let remaining_space = self
-            .final_buffer_size
-            .checked_sub(current_buffer_size)
-            .ok_or(MultisigError::FinalBufferSizeExceeded)?;
+    /// Validates that a chunk can be written at `chunk_index` (not yet written, size and hash). Used by extend instruction and unit tests.
+    pub fn validate_extend_chunk(&self, chunk_index: u16, chunk: &[u8]) -> Result<()> {
+        let range = self.chunk_range(chunk_index)?;
 
-        let new_data_size =
-            u16::try_from(chunk.len()).map_err(|_| MultisigError::FinalBufferSizeExceeded)?;
         require!(
-            new_data_size <= remaining_space,
-            MultisigError::FinalBufferSizeExceeded
+            !self.written_chunks[usize::from(chunk_index)],
+            MultisigError::BufferChunkAlreadyWritten
         );
 
-        let required_buffer_hash = self
-            .buffer_extend_hashes
-            .get(0)
-            .ok_or(MultisigError::InvalidBuffer)?;
+        require_eq!(
+            chunk.len(),
+            range.len(),
+            MultisigError::FinalBufferSizeMismatch
+        );
 
-        let current_buffer_hash :[u8; 32]=
-            Sha256::digest(chunk).into();
+        let required_buffer_hash = self.buffer_extend_hashes[usize::from(chunk_index)];
+
+        let current_buffer_hash: [u8; 32] = Sha256::digest(chunk).into();
 
         require!(
             required_buffer_hash.eq(&current_buffer_hash),
@@ -243,19 +576,50 @@ impl TransactionBuffer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Permission, Permissions, UserRole};
 
     #[test]
     fn test_transaction_buffer_size_exceeds_max() {
-        let result = TransactionBuffer::size(MAX_BUFFER_SIZE as u16 + 1, 0, 0);
+        let result = TransactionBuffer::size(MAX_BUFFER_SIZE as u32 + 1, 0, 0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_transaction_buffer_size_exceeds_max_account_size() {
+        let result = TransactionBuffer::size(MAX_BUFFER_SIZE as u32, 0, 0);
         assert!(result.is_err());
     }
 
     #[test]
     fn test_transaction_buffer_size_at_max() {
-        let result = TransactionBuffer::size(MAX_BUFFER_SIZE as u16, 0, 0);
+        let overhead = TransactionBuffer::size(0, 0, 0).unwrap();
+        let result = TransactionBuffer::size((MAX_ACCOUNT_SIZE - overhead) as u32, 0, 0);
+        assert_eq!(result.unwrap(), MAX_ACCOUNT_SIZE);
+    }
+
+    #[test]
+    fn test_transaction_buffer_size_beyond_single_allocation() {
+        // Larger than a PDA can be allocated with in an inner ix, reachable through reallocs
+        let result = TransactionBuffer::size(32_768, 0, 0);
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_check_account_growth() {
+        let current = TransactionBuffer::size(0, 4, 0).unwrap();
+        assert!(TransactionBuffer::check_account_growth(
+            current,
+            current + MAX_PERMITTED_DATA_INCREASE
+        )
+        .is_ok());
+        assert!(TransactionBuffer::check_account_growth(current + 1, current).is_ok());
+        assert!(TransactionBuffer::check_account_growth(
+            current,
+            current + MAX_PERMITTED_DATA_INCREASE + 1
+        )
+        .is_err());
+    }
+
     #[test]
     fn test_transaction_buffer_size_with_voters() {
         let size_no_voters = TransactionBuffer::size(100, 0, 0).unwrap();
@@ -268,18 +632,18 @@ mod tests {
         let size_no_extend = TransactionBuffer::size(100, 0, 0).unwrap();
         let size_with_extend = TransactionBuffer::size(100, 3, 0).unwrap();
         assert!(size_with_extend > size_no_extend);
-        assert_eq!(size_with_extend - size_no_extend, 3 * 32);
+        assert_eq!(size_with_extend - size_no_extend, 3 * (32 + 1));
     }
 
     #[test]
     fn test_transaction_buffer_size_with_expected_signers() {
-        // Size should increase with number_of_expected_signers (affects both voters and expected_signers capacity)
+        // Size should increase with number_of_expected_signers (affects voters, rejecters and expected_signers capacity)
         let size_0 = TransactionBuffer::size(100, 0, 0).unwrap();
         let size_1 = TransactionBuffer::size(100, 0, 1).unwrap();
         let size_2 = TransactionBuffer::size(100, 0, 2).unwrap();
         assert!(size_1 > size_0);
         assert!(size_2 > size_1);
-        let expected_increment_per_signer = MemberKey::INIT_SPACE + ExpectedSigner::INIT_SPACE;
+        let expected_increment_per_signer = 2 * MemberKey::INIT_SPACE + ExpectedSigner::INIT_SPACE;
         assert_eq!(size_1 - size_0, expected_increment_per_signer);
         assert_eq!(size_2 - size_1, expected_increment_per_signer);
     }
@@ -292,15 +656,24 @@ mod tests {
             can_execute: false,
             preauthorize_execution: false,
             valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
             final_buffer_hash: [0u8; 32],
             final_buffer_size: 0,
             creator: MemberKey::default(),
             executor: MemberKey::default(),
             buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
             voters: vec![],
+            rejecters: vec![],
             expected_signers: vec![],
             buffer: vec![],
         };
@@ -315,15 +688,24 @@ mod tests {
             can_execute: false,
             preauthorize_execution: false,
             valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
             final_buffer_hash: [0u8; 32],
             final_buffer_size: 100,
             creator: MemberKey::default(),
             executor: MemberKey::default(),
             buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
             voters: vec![],
+            rejecters: vec![],
             expected_signers: vec![],
             buffer: vec![1, 2, 3],
         };
@@ -338,15 +720,24 @@ mod tests {
             can_execute: false,
             preauthorize_execution: false,
             valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
             final_buffer_hash: [0u8; 32],
             final_buffer_size: 100,
             creator: MemberKey::default(),
             executor: MemberKey::default(),
             buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
             voters: vec![],
+            rejecters: vec![],
             expected_signers: vec![],
             buffer: vec![0u8; 50],
         };
@@ -361,21 +752,249 @@ mod tests {
             can_execute: false,
             preauthorize_execution: false,
             valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
             final_buffer_hash: [0u8; 32],
             final_buffer_size: 10,
             creator: MemberKey::default(),
             executor: MemberKey::default(),
             buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
             voters: vec![],
+            rejecters: vec![],
             expected_signers: vec![],
             buffer: vec![0u8; 50],
         };
         assert!(buffer.invariant().is_err());
     }
 
+    #[test]
+    fn test_transaction_buffer_check_config_version() {
+        let buffer = TransactionBuffer {
+            multi_wallet_settings: Pubkey::new_unique(),
+            multi_wallet_bump: 0,
+            can_execute: false,
+            preauthorize_execution: false,
+            valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 3,
+            payer: Pubkey::new_unique(),
+            bump: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
+            final_buffer_hash: [0u8; 32],
+            final_buffer_size: 0,
+            creator: MemberKey::default(),
+            executor: MemberKey::default(),
+            buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
+            voters: vec![],
+            rejecters: vec![],
+            expected_signers: vec![],
+            buffer: vec![],
+        };
+        assert!(buffer.check_config_version(3).is_ok());
+        assert!(buffer.check_config_version(4).is_err());
+    }
+
+    #[test]
+    fn test_transaction_buffer_check_expiry() {
+        let mut buffer = TransactionBuffer {
+            multi_wallet_settings: Pubkey::new_unique(),
+            multi_wallet_bump: 0,
+            can_execute: false,
+            preauthorize_execution: false,
+            valid_till: 1_000,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
+            payer: Pubkey::new_unique(),
+            bump: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
+            final_buffer_hash: [0u8; 32],
+            final_buffer_size: 0,
+            creator: MemberKey::default(),
+            executor: MemberKey::default(),
+            buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
+            voters: vec![],
+            rejecters: vec![],
+            expected_signers: vec![],
+            buffer: vec![],
+        };
+        assert!(buffer.check_expiry(1_000).is_ok());
+        let err = buffer.check_expiry(1_001).unwrap_err().to_string();
+        assert!(err.contains("TransactionHasExpired"));
+        assert!(!buffer.is_expired(1_000));
+        assert!(buffer.is_expired(1_001));
+        assert!(buffer.check_not_started().is_ok());
+
+        // Members cannot stop a started batch, but it still expires
+        buffer.executed_messages = 1;
+        let err = buffer.check_not_started().unwrap_err().to_string();
+        assert!(err.contains("TransactionBatchStarted"));
+        assert!(!buffer.is_abandoned(1_000, 0));
+        assert!(buffer.is_expired(1_001));
+        assert!(buffer.is_abandoned(1_001, 0));
+        let err = buffer.check_expiry(1_001).unwrap_err().to_string();
+        assert!(err.contains("TransactionHasExpired"));
+    }
+
+    #[test]
+    fn test_check_distinct_buffers() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let third = Pubkey::new_unique();
+
+        assert!(TransactionBuffer::check_distinct_buffers(&first, &[second, third]).is_ok());
+
+        let err = TransactionBuffer::check_distinct_buffers(&first, &[second, first])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("DuplicateTransactionBuffer"));
+
+        let err = TransactionBuffer::check_distinct_buffers(&first, &[second, third, second])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("DuplicateTransactionBuffer"));
+    }
+
+    #[test]
+    fn test_started_batch_abandoned_after_config_change() {
+        let mut buffer = TransactionBuffer {
+            multi_wallet_settings: Pubkey::new_unique(),
+            multi_wallet_bump: 0,
+            can_execute: true,
+            preauthorize_execution: false,
+            valid_till: 1_000,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 3,
+            payer: Pubkey::new_unique(),
+            bump: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 2,
+            executed_messages: 0,
+            final_buffer_hash: [0u8; 32],
+            final_buffer_size: 0,
+            creator: MemberKey::default(),
+            executor: MemberKey::default(),
+            buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
+            voters: vec![],
+            rejecters: vec![],
+            expected_signers: vec![],
+            buffer: vec![],
+        };
+        assert!(!buffer.is_abandoned(500, 3));
+
+        buffer.executed_messages = 1;
+        assert!(!buffer.is_abandoned(500, 3));
+        assert!(buffer.is_stale(4));
+        assert!(buffer.is_abandoned(500, 4));
+    }
+
+    #[test]
+    fn test_transaction_buffer_check_not_before() {
+        let buffer = TransactionBuffer {
+            multi_wallet_settings: Pubkey::new_unique(),
+            multi_wallet_bump: 0,
+            can_execute: false,
+            preauthorize_execution: false,
+            valid_till: 1_180,
+            not_before: 1_000,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
+            payer: Pubkey::new_unique(),
+            bump: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
+            final_buffer_hash: [0u8; 32],
+            final_buffer_size: 0,
+            creator: MemberKey::default(),
+            executor: MemberKey::default(),
+            buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
+            voters: vec![],
+            rejecters: vec![],
+            expected_signers: vec![],
+            buffer: vec![],
+        };
+        let err = buffer.check_not_before(999).unwrap_err().to_string();
+        assert!(err.contains("TransactionNotYetExecutable"));
+        assert!(buffer.check_not_before(1_000).is_ok());
+        assert!(buffer.check_expiry(1_180).is_ok());
+    }
+
+    #[test]
+    fn test_compute_valid_till() {
+        // Unscheduled transactions expire relative to creation
+        assert_eq!(
+            TransactionBuffer::compute_valid_till(100, 0, 50).unwrap(),
+            150
+        );
+        // Scheduled ones relative to their not_before time
+        assert_eq!(
+            TransactionBuffer::compute_valid_till(100, 1_000, 50).unwrap(),
+            1_050
+        );
+
+        // An empty window would never let the transaction run
+        let err = TransactionBuffer::compute_valid_till(100, 1_000, 0)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("InvalidTransactionExpiry"));
+        let err = TransactionBuffer::compute_valid_till(100, u64::MAX, 50)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("InvalidTransactionExpiry"));
+    }
+
+    #[test]
+    fn test_expiry_mode_units() {
+        let clock = Clock {
+            slot: 5_000,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+        assert_eq!(ExpiryMode::Timestamp.now(&clock).unwrap(), 1_700_000_000);
+        assert_eq!(ExpiryMode::Slot.now(&clock).unwrap(), 5_000);
+
+        assert_eq!(ExpiryMode::Timestamp.window(180).unwrap(), 180);
+        assert_eq!(ExpiryMode::Slot.window(180).unwrap(), 450);
+        assert!(ExpiryMode::Slot.window(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_transaction_buffer_message_hash_binds_vault_index() {
+        let final_buffer_hash = [7u8; 32];
+        assert_ne!(
+            TransactionBuffer::compute_message_hash(final_buffer_hash, 0),
+            TransactionBuffer::compute_message_hash(final_buffer_hash, 1)
+        );
+    }
+
     #[test]
     fn test_add_voter_ed25519() {
         let pubkey = Pubkey::new_unique();
@@ -386,15 +1005,24 @@ mod tests {
             can_execute: false,
             preauthorize_execution: false,
             valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
             final_buffer_hash: [0u8; 32],
             final_buffer_size: 0,
             creator: MemberKey::default(),
             executor: MemberKey::default(),
             buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
             voters: vec![],
+            rejecters: vec![],
             expected_signers: vec![],
             buffer: vec![],
         };
@@ -413,15 +1041,24 @@ mod tests {
             can_execute: false,
             preauthorize_execution: false,
             valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
             final_buffer_hash: [0u8; 32],
             final_buffer_size: 0,
             creator: MemberKey::default(),
             executor: MemberKey::default(),
             buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
             voters: vec![],
+            rejecters: vec![],
             expected_signers: vec![],
             buffer: vec![],
         };
@@ -430,6 +1067,139 @@ mod tests {
         assert_eq!(buffer.voters.len(), 1);
     }
 
+    #[test]
+    fn test_remove_voter_revokes_approval() {
+        let creator = MemberKey::convert_ed25519(&Pubkey::new_unique()).unwrap();
+        let mut buffer = TransactionBuffer {
+            multi_wallet_settings: Pubkey::new_unique(),
+            multi_wallet_bump: 0,
+            can_execute: true,
+            preauthorize_execution: false,
+            valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
+            payer: Pubkey::new_unique(),
+            bump: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
+            final_buffer_hash: [0u8; 32],
+            final_buffer_size: 0,
+            creator,
+            executor: MemberKey::default(),
+            buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
+            voters: vec![creator],
+            rejecters: vec![],
+            expected_signers: vec![],
+            buffer: vec![],
+        };
+        buffer.remove_voter(&creator).unwrap();
+        assert!(buffer.voters.is_empty());
+        assert!(!buffer.can_execute);
+
+        let err = buffer.remove_voter(&creator).unwrap_err().to_string();
+        assert!(err.contains("VoterNotFound"));
+
+        // Once withdrawn, the member is free to reject instead
+        buffer.add_rejecter(&creator).unwrap();
+    }
+
+    #[test]
+    fn test_add_rejecter_conflicts_with_vote() {
+        let voter = MemberKey::convert_ed25519(&Pubkey::new_unique()).unwrap();
+        let rejecter = MemberKey::convert_ed25519(&Pubkey::new_unique()).unwrap();
+        let mut buffer = TransactionBuffer {
+            multi_wallet_settings: Pubkey::new_unique(),
+            multi_wallet_bump: 0,
+            can_execute: false,
+            preauthorize_execution: false,
+            valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
+            payer: Pubkey::new_unique(),
+            bump: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
+            final_buffer_hash: [0u8; 32],
+            final_buffer_size: 0,
+            creator: MemberKey::default(),
+            executor: MemberKey::default(),
+            buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
+            voters: vec![],
+            rejecters: vec![],
+            expected_signers: vec![],
+            buffer: vec![],
+        };
+        buffer.add_voter(&voter).unwrap();
+        buffer.add_rejecter(&rejecter).unwrap();
+        buffer.add_rejecter(&rejecter).unwrap();
+        assert_eq!(buffer.rejecters, vec![rejecter]);
+
+        let err = buffer.add_rejecter(&voter).unwrap_err().to_string();
+        assert!(err.contains("ConflictingVote"));
+        let err = buffer.add_voter(&rejecter).unwrap_err().to_string();
+        assert!(err.contains("ConflictingVote"));
+    }
+
+    #[test]
+    fn test_is_rejected_once_threshold_unreachable() {
+        let voting = Permissions::from_permissions(vec![Permission::VoteTransaction]);
+        let members: Vec<Member> = (0..3)
+            .map(|_| {
+                Member::new(
+                    MemberKey::convert_ed25519(&Pubkey::new_unique()).unwrap(),
+                    UserRole::Member,
+                    voting,
+                    false,
+                )
+            })
+            .collect();
+        let mut buffer = TransactionBuffer {
+            multi_wallet_settings: Pubkey::new_unique(),
+            multi_wallet_bump: 0,
+            can_execute: false,
+            preauthorize_execution: false,
+            valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
+            payer: Pubkey::new_unique(),
+            bump: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
+            final_buffer_hash: [0u8; 32],
+            final_buffer_size: 0,
+            creator: MemberKey::default(),
+            executor: MemberKey::default(),
+            buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
+            voters: vec![],
+            rejecters: vec![],
+            expected_signers: vec![],
+            buffer: vec![],
+        };
+
+        // 2-of-3: one rejection leaves two members able to approve
+        buffer.add_rejecter(&members[0].pubkey).unwrap();
+        assert!(!buffer.is_rejected(&members, 2));
+
+        buffer.add_rejecter(&members[1].pubkey).unwrap();
+        assert!(buffer.is_rejected(&members, 2));
+        assert!(!buffer.is_rejected(&members, 1));
+    }
+
     #[test]
     fn test_add_initiator_ed25519() {
         let pubkey = Pubkey::new_unique();
@@ -440,15 +1210,24 @@ mod tests {
             can_execute: false,
             preauthorize_execution: false,
             valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
             final_buffer_hash: [0u8; 32],
             final_buffer_size: 0,
             creator: MemberKey::default(),
             executor: MemberKey::default(),
             buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
             voters: vec![],
+            rejecters: vec![],
             expected_signers: vec![],
             buffer: vec![],
         };
@@ -466,15 +1245,24 @@ mod tests {
             can_execute: false,
             preauthorize_execution: false,
             valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
             final_buffer_hash: [0u8; 32],
             final_buffer_size: 0,
             creator: MemberKey::default(),
             executor: MemberKey::default(),
             buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
             voters: vec![],
+            rejecters: vec![],
             expected_signers: vec![],
             buffer: vec![],
         };
@@ -496,15 +1284,24 @@ mod tests {
             can_execute: false,
             preauthorize_execution: false,
             valid_till: u64::MAX,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
             final_buffer_hash: [0u8; 32],
             final_buffer_size: 0,
             creator,
             executor,
             buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
             voters: vec![voter],
+            rejecters: vec![],
             expected_signers: vec![
                 ExpectedSigner {
                     member_key: creator,
@@ -533,15 +1330,24 @@ mod tests {
             can_execute: false,
             preauthorize_execution: false,
             valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
             final_buffer_hash: [0u8; 32],
             final_buffer_size: 0,
             creator,
             executor,
             buffer_extend_hashes: vec![],
+            chunk_size: 0,
+            written_chunks: vec![],
             voters: vec![voter],
+            rejecters: vec![],
             expected_signers: vec![
                 ExpectedSigner {
                     member_key: creator,
@@ -561,78 +1367,159 @@ mod tests {
         assert!(buffer.check_expected_signers().is_ok());
     }
 
-    #[test]
-    fn test_validate_extend_chunk_exceeds_remaining_space_fails() {
-        let chunk_hash = Sha256::digest(&[1u8; 10]).into();
-        let buffer = TransactionBuffer {
+    fn mk_chunked_buffer(chunks: &[&[u8]], chunk_size: u32) -> TransactionBuffer {
+        let final_buffer: Vec<u8> = chunks.concat();
+        TransactionBuffer {
             multi_wallet_settings: Pubkey::new_unique(),
             multi_wallet_bump: 0,
             can_execute: false,
             preauthorize_execution: false,
             valid_till: 0,
+            not_before: 0,
+            expiry_mode: ExpiryMode::Timestamp,
+            config_version: 0,
             payer: Pubkey::new_unique(),
             bump: 0,
-            buffer_index: 0,
-            final_buffer_hash: [0u8; 32],
-            final_buffer_size: 20,
+            transaction_index: 0,
+            vault_index: 0,
+            message_count: 1,
+            executed_messages: 0,
+            final_buffer_hash: Sha256::digest(&final_buffer).into(),
+            final_buffer_size: final_buffer.len() as u32,
             creator: MemberKey::default(),
             executor: MemberKey::default(),
-            buffer_extend_hashes: vec![chunk_hash],
+            buffer_extend_hashes: chunks
+                .iter()
+                .map(|chunk| Sha256::digest(chunk).into())
+                .collect(),
+            chunk_size,
+            written_chunks: vec![false; chunks.len()],
             voters: vec![],
+            rejecters: vec![],
             expected_signers: vec![],
-            buffer: vec![0u8; 15],
-        };
-        let chunk = [2u8; 10];
-        assert!(buffer.validate_extend_chunk(&chunk).is_err());
+            buffer: vec![],
+        }
+    }
+
+    #[test]
+    fn test_validate_extend_chunk_wrong_size_fails() {
+        let buffer = mk_chunked_buffer(&[&[1u8; 10], &[2u8; 10]], 10);
+        let err = buffer
+            .validate_extend_chunk(1, &[2u8; 12])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("FinalBufferSizeMismatch"));
     }
 
     #[test]
     fn test_validate_extend_chunk_wrong_hash_fails() {
-        let wrong_hash = Sha256::digest(&[99u8; 5]).into();
-        let buffer = TransactionBuffer {
-            multi_wallet_settings: Pubkey::new_unique(),
-            multi_wallet_bump: 0,
-            can_execute: false,
-            preauthorize_execution: false,
-            valid_till: 0,
-            payer: Pubkey::new_unique(),
-            bump: 0,
-            buffer_index: 0,
-            final_buffer_hash: [0u8; 32],
-            final_buffer_size: 20,
-            creator: MemberKey::default(),
-            executor: MemberKey::default(),
-            buffer_extend_hashes: vec![wrong_hash],
-            voters: vec![],
-            expected_signers: vec![],
-            buffer: vec![0u8; 5],
-        };
-        let chunk = [1u8; 10];
-        assert!(buffer.validate_extend_chunk(&chunk).is_err());
+        let buffer = mk_chunked_buffer(&[&[1u8; 10], &[2u8; 10]], 10);
+        assert!(buffer.validate_extend_chunk(0, &[2u8; 10]).is_err());
+        assert!(buffer.validate_extend_chunk(2, &[2u8; 10]).is_err());
     }
 
     #[test]
     fn test_validate_extend_chunk_ok() {
-        let chunk = [1u8; 10];
-        let chunk_hash = Sha256::digest(&chunk).into();
-        let buffer = TransactionBuffer {
-            multi_wallet_settings: Pubkey::new_unique(),
-            multi_wallet_bump: 0,
-            can_execute: false,
-            preauthorize_execution: false,
-            valid_till: 0,
-            payer: Pubkey::new_unique(),
-            bump: 0,
-            buffer_index: 0,
-            final_buffer_hash: [0u8; 32],
-            final_buffer_size: 20,
-            creator: MemberKey::default(),
-            executor: MemberKey::default(),
-            buffer_extend_hashes: vec![chunk_hash],
-            voters: vec![],
-            expected_signers: vec![],
-            buffer: vec![0u8; 5],
+        let buffer = mk_chunked_buffer(&[&[1u8; 10], &[2u8; 5]], 10);
+        assert!(buffer.validate_extend_chunk(0, &[1u8; 10]).is_ok());
+        assert!(buffer.validate_extend_chunk(1, &[2u8; 5]).is_ok());
+    }
+
+    #[test]
+    fn test_write_chunks_out_of_order() {
+        let chunks: [&[u8]; 3] = [&[1u8; 4], &[2u8; 4], &[3u8; 2]];
+        let mut buffer = mk_chunked_buffer(&chunks, 4);
+
+        buffer.validate_extend_chunk(2, chunks[2]).unwrap();
+        buffer.write_chunk(2, chunks[2]).unwrap();
+        buffer.validate_extend_chunk(0, chunks[0]).unwrap();
+        buffer.write_chunk(0, chunks[0]).unwrap();
+        assert!(buffer.invariant().is_ok());
+
+        let err = buffer.validate_hash().unwrap_err().to_string();
+        assert!(err.contains("BufferChunksMissing"));
+        assert!(buffer.validate_size().is_err());
+
+        let err = buffer
+            .validate_extend_chunk(0, chunks[0])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("BufferChunkAlreadyWritten"));
+
+        buffer.validate_extend_chunk(1, chunks[1]).unwrap();
+        buffer.write_chunk(1, chunks[1]).unwrap();
+        assert_eq!(buffer.buffer, chunks.concat());
+        assert!(buffer.validate_hash().is_ok());
+        assert!(buffer.validate_size().is_ok());
+    }
+
+    fn serialize_message(message: &VaultTransactionMessage) -> Vec<u8> {
+        let mut data = Vec::new();
+        message.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_batch_messages_run_in_order() {
+        let first = VaultTransactionMessage {
+            num_signers: 1,
+            account_keys: vec![Pubkey::new_unique()],
+            ..Default::default()
+        };
+        let second = VaultTransactionMessage {
+            num_signers: 1,
+            account_keys: vec![Pubkey::new_unique(), Pubkey::new_unique()],
+            ..Default::default()
         };
-        assert!(buffer.validate_extend_chunk(&chunk).is_ok());
+        let mut data = serialize_message(&first);
+        data.extend(serialize_message(&second));
+
+        let mut buffer = mk_chunked_buffer(&[&data], data.len() as u32);
+        buffer.message_count = 2;
+        buffer.write_chunk(0, &data).unwrap();
+
+        assert_eq!(buffer.messages().unwrap().len(), 2);
+        assert_eq!(
+            buffer.next_message().unwrap().account_keys,
+            first.account_keys
+        );
+        assert!(!buffer.advance_batch().unwrap());
+        assert_eq!(
+            buffer.next_message().unwrap().account_keys,
+            second.account_keys
+        );
+        assert!(buffer.advance_batch().unwrap());
+        assert!(buffer.advance_batch().is_err());
+    }
+
+    #[test]
+    fn test_batch_messages_must_fill_buffer() {
+        let message = serialize_message(&VaultTransactionMessage::default());
+        let mut data = message.clone();
+        data.extend(&message);
+
+        let mut buffer = mk_chunked_buffer(&[&data], data.len() as u32);
+        buffer.write_chunk(0, &data).unwrap();
+        // One message declared, two stored
+        assert!(buffer.messages().is_err());
+
+        buffer.message_count = 3;
+        assert!(buffer.messages().is_err());
+
+        buffer.message_count = 2;
+        assert!(buffer.messages().is_ok());
+    }
+
+    #[test]
+    fn test_validate_chunk_layout() {
+        assert!(TransactionBuffer::validate_chunk_layout(0, 0, 0).is_ok());
+        assert!(TransactionBuffer::validate_chunk_layout(10, 4, 3).is_ok());
+        assert!(TransactionBuffer::validate_chunk_layout(12, 4, 3).is_ok());
+        // Too few chunks to cover the buffer
+        assert!(TransactionBuffer::validate_chunk_layout(13, 4, 3).is_err());
+        // The last chunk would be empty
+        assert!(TransactionBuffer::validate_chunk_layout(8, 4, 3).is_err());
+        assert!(TransactionBuffer::validate_chunk_layout(10, 0, 3).is_err());
+        assert!(TransactionBuffer::validate_chunk_layout(10, 10, 0).is_err());
     }
 }
@@ -1,5 +1,5 @@
 use crate::utils::{resize_account_if_necessary, KeyType, Transports, UserRole};
-use crate::{AddMemberArgs, MemberKey, MultisigError, RemoveMemberArgs, ID, SEED_USER};
+use crate::{AddMemberArgs, Member, MemberKey, MultisigError, RemoveMemberArgs, ID, SEED_USER};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -12,6 +12,9 @@ pub struct User {
     pub role: UserRole,
     pub transaction_manager_url: Option<String>,
     pub bump: u8,
+    /// Last signature counter seen from the user's passkey, see https://w3c.github.io/webauthn/#signature-counter.
+    /// Kept last so accounts created before it existed can be upgraded by `migrate_user_account`.
+    pub sign_count: u32,
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, PartialEq, Debug, Clone, InitSpace)]
@@ -24,6 +27,39 @@ pub struct SettingsIndexWithDelegateInfo {
 pub enum UserWalletOperation {
     Add(AddMemberArgs),
     Remove(RemoveMemberArgs),
+    /// Removal of the permanent member by a recovery, which releases its user account instead of failing.
+    Release(RemoveMemberArgs),
+    Rotate {
+        old: MemberKey,
+        new: MemberKey,
+    },
+}
+
+impl UserWalletOperation {
+    /// Key of the member whose user account the operation touches. A rotation never changes key type,
+    /// so the old key stands in for both sides.
+    pub fn member_key(&self) -> MemberKey {
+        match self {
+            UserWalletOperation::Add(args) => args.member_key,
+            UserWalletOperation::Remove(args) => args.member_key,
+            UserWalletOperation::Release(args) => args.member_key,
+            UserWalletOperation::Rotate { old, .. } => *old,
+        }
+    }
+}
+
+/// `User` layout from before passkey signature counters were tracked, read by `migrate_user_account`.
+#[derive(AnchorDeserialize)]
+#[cfg_attr(test, derive(AnchorSerialize))]
+struct LegacyUser {
+    domain_config: Option<Pubkey>,
+    member: MemberKey,
+    credential_id: Option<Vec<u8>>,
+    transports: Option<Vec<Transports>>,
+    wallets: Vec<SettingsIndexWithDelegateInfo>,
+    role: UserRole,
+    transaction_manager_url: Option<String>,
+    bump: u8,
 }
 
 impl User {
@@ -42,6 +78,7 @@ impl User {
         + 1                                 // user role
         + 1 + 4 + transaction_manager_url_len   // transaction manager url
         + 1 //bump
+        + 4 // sign count
     }
 
     pub fn invariant(&self) -> Result<()> {
@@ -51,7 +88,7 @@ impl User {
                 MultisigError::InvalidTransactionManagerConfig
             );
             require!(
-                self.member.get_type().eq(&KeyType::Ed25519),
+                self.member.get_type()?.eq(&KeyType::Ed25519),
                 MultisigError::InvalidTransactionManagerConfig
             );
             require!(
@@ -71,7 +108,7 @@ impl User {
                 MultisigError::InvalidAdministratorConfig
             );
             require!(
-                self.member.get_type().eq(&KeyType::Ed25519),
+                self.member.get_type()?.eq(&KeyType::Ed25519),
                 MultisigError::InvalidAdministratorConfig
             );
             require!(
@@ -80,7 +117,7 @@ impl User {
             );
         }
 
-        if self.member.get_type().eq(&KeyType::Secp256r1) {
+        if self.member.get_type()?.eq(&KeyType::Secp256r1) {
             require!(
                 self.domain_config.is_some(),
                 MultisigError::DomainConfigIsMissing
@@ -94,12 +131,14 @@ impl User {
                 MultisigError::TransportsIsMissing
             );
             require!(
-                self.role.eq(&UserRole::Member) || self.role.eq(&UserRole::PermanentMember),
+                self.role.eq(&UserRole::Member)
+                    || self.role.eq(&UserRole::PermanentMember)
+                    || self.role.eq(&UserRole::Guardian),
                 MultisigError::InvalidUserRole
             );
         }
 
-        if self.member.get_type().eq(&KeyType::Ed25519) {
+        if self.member.get_type()?.eq(&KeyType::Ed25519) {
             require!(
                 self.credential_id.is_none(),
                 MultisigError::InvalidUserEd25519Config
@@ -112,7 +151,7 @@ impl User {
 
         if self.role.eq(&UserRole::PermanentMember) {
             require!(
-                self.member.get_type().eq(&KeyType::Secp256r1),
+                self.member.get_type()?.eq(&KeyType::Secp256r1),
                 MultisigError::InvalidUserRole
             );
             require!(
@@ -129,6 +168,77 @@ impl User {
         Ok(())
     }
 
+    /// Reads a user account created before `sign_count` was added. Accounts that already load with the
+    /// current layout are refused, so a counter that is in use can never be reset.
+    pub fn from_legacy_data(data: &[u8]) -> Result<User> {
+        require!(
+            data.starts_with(User::DISCRIMINATOR),
+            MultisigError::InvalidAccount
+        );
+        require!(
+            User::try_deserialize(&mut &data[..]).is_err(),
+            MultisigError::AccountAlreadyMigrated
+        );
+
+        let legacy = LegacyUser::deserialize(&mut &data[User::DISCRIMINATOR.len()..])?;
+
+        Ok(User {
+            domain_config: legacy.domain_config,
+            member: legacy.member,
+            credential_id: legacy.credential_id,
+            transports: legacy.transports,
+            wallets: legacy.wallets,
+            role: legacy.role,
+            transaction_manager_url: legacy.transaction_manager_url,
+            bump: legacy.bump,
+            sign_count: 0,
+        })
+    }
+
+    /// Records the passkey signature counter. Once either counter is non-zero it must strictly increase,
+    /// otherwise the credential may have been cloned.
+    pub fn record_sign_count(&mut self, sign_count: u32) -> Result<()> {
+        if sign_count == 0 && self.sign_count == 0 {
+            return Ok(());
+        }
+
+        require!(
+            sign_count > self.sign_count,
+            MultisigError::SignCountNotIncreased
+        );
+        self.sign_count = sign_count;
+
+        Ok(())
+    }
+
+    /// Records the counter of a passkey assertion on the signer's user account, which must be writable.
+    pub fn record_sign_count_on_account(
+        user_account_info: &AccountInfo,
+        signer: &MemberKey,
+        sign_count: u32,
+    ) -> Result<()> {
+        require!(
+            user_account_info.owner.eq(&ID) && user_account_info.is_writable,
+            MultisigError::UserAccountKeyMismatch
+        );
+
+        let mut data = user_account_info.try_borrow_mut_data()?;
+        let mut user = User::try_deserialize(&mut &data[..])?;
+
+        let user_account_pubkey =
+            Pubkey::create_program_address(&[SEED_USER, &signer.get_seed()?, &[user.bump]], &ID)
+                .map_err(|_| MultisigError::UserAccountKeyMismatch)?;
+        require!(
+            user_account_info.key.eq(&user_account_pubkey),
+            MultisigError::UserAccountKeyMismatch
+        );
+
+        user.record_sign_count(sign_count)?;
+        user.try_serialize(&mut &mut data[..])?;
+
+        Ok(())
+    }
+
     pub fn process_user_wallet_operations<'info>(
         wallet_operations: Vec<UserWalletOperation>,
         settings_index: u128,
@@ -137,9 +247,16 @@ impl User {
         remaining_accounts: &[AccountInfo<'info>],
     ) -> Result<()> {
         for operation in wallet_operations.into_iter() {
+            // Nested wallet, Ethereum key and raw P-256 key members have no user account to keep in sync
+            if !operation.member_key().has_user_account() {
+                continue;
+            }
             match operation {
                 UserWalletOperation::Remove(pk) => {
-                    User::remove_wallet_from_user(pk, settings_index, remaining_accounts)?;
+                    User::remove_wallet_from_user(pk, settings_index, false, remaining_accounts)?;
+                }
+                UserWalletOperation::Release(pk) => {
+                    User::remove_wallet_from_user(pk, settings_index, true, remaining_accounts)?;
                 }
                 UserWalletOperation::Add(pk) => {
                     User::add_wallet_to_user(
@@ -150,6 +267,16 @@ impl User {
                         remaining_accounts,
                     )?;
                 }
+                UserWalletOperation::Rotate { old, new } => {
+                    User::move_wallet_between_users(
+                        old,
+                        new,
+                        settings_index,
+                        payer,
+                        system_program,
+                        remaining_accounts,
+                    )?;
+                }
             }
         }
 
@@ -181,7 +308,7 @@ impl User {
             MultisigError::OnlyOnePermanentMemberAllowed
         );
 
-        if user.role == UserRole::Member {
+        if user.role == UserRole::Member || user.role == UserRole::Guardian {
             user.wallets.push(SettingsIndexWithDelegateInfo {
                 index: settings_index,
                 is_delegate: false,
@@ -207,9 +334,137 @@ impl User {
         Ok(())
     }
 
+    /// Moves the wallet entry, including its delegate flag, from the user behind `old` to the user behind `new`.
+    /// A permanent member's role moves along with the wallet so neither account breaks the permanent member invariant.
+    fn move_wallet_between_users<'info>(
+        old: MemberKey,
+        new: MemberKey,
+        settings_index: u128,
+        payer: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let (old_user_pubkey, _) =
+            Pubkey::find_program_address(&[SEED_USER, &old.get_seed()?], &ID);
+        let (new_user_pubkey, _) =
+            Pubkey::find_program_address(&[SEED_USER, &new.get_seed()?], &ID);
+
+        let old_user_info = remaining_accounts
+            .iter()
+            .find(|f| f.key.eq(&old_user_pubkey))
+            .ok_or(MultisigError::MissingAccount)?;
+        let new_user_info = remaining_accounts
+            .iter()
+            .find(|f| f.key.eq(&new_user_pubkey))
+            .ok_or(MultisigError::MissingAccount)?;
+
+        let mut old_user = {
+            let data = old_user_info.try_borrow_data()?;
+            User::try_deserialize(&mut &data[..])?
+        };
+        let mut new_user = {
+            let data = new_user_info.try_borrow_data()?;
+            User::try_deserialize(&mut &data[..])?
+        };
+
+        let old_role = old_user.role;
+        if old_role.eq(&UserRole::PermanentMember) {
+            require!(
+                new_user.role.eq(&UserRole::Member) && new_user.wallets.is_empty(),
+                MultisigError::InvalidUserRole
+            );
+            old_user.role = UserRole::Member;
+            new_user.role = UserRole::PermanentMember;
+        } else {
+            require!(new_user.role.eq(&old_role), MultisigError::InvalidUserRole);
+        }
+
+        let entry = old_user
+            .wallets
+            .iter()
+            .position(|f| f.index.eq(&settings_index))
+            .map(|i| old_user.wallets.remove(i));
+
+        if let Some(entry) = entry {
+            new_user.wallets.push(entry);
+        }
+
+        let new_size = User::size(
+            new_user.credential_id.as_ref().map_or(0, |f| f.len()),
+            new_user.transports.as_ref().map_or(0, |f| f.len()),
+            new_user
+                .transaction_manager_url
+                .as_ref()
+                .map_or(0, |f| f.len()),
+            new_user.wallets.len(),
+        );
+
+        resize_account_if_necessary(new_user_info, payer, system_program, new_size)?;
+
+        old_user.invariant()?;
+        new_user.invariant()?;
+
+        {
+            let mut data = old_user_info.try_borrow_mut_data()?;
+            old_user.try_serialize(&mut &mut data[..])?;
+        }
+        {
+            let mut data = new_user_info.try_borrow_mut_data()?;
+            new_user.try_serialize(&mut &mut data[..])?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes a closed wallet from its members' user accounts. Roles that do not track wallets are skipped,
+    /// and a permanent member is released back to a regular member since its only wallet is gone.
+    pub fn detach_wallet_from_users(
+        members: &[Member],
+        settings_index: u128,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<()> {
+        for member in members {
+            let role = UserRole::from(member.role);
+            if role.eq(&UserRole::TransactionManager)
+                || role.eq(&UserRole::Administrator)
+                || !member.pubkey.has_user_account()
+            {
+                continue;
+            }
+
+            let (user_account_pubkey, _) =
+                Pubkey::find_program_address(&[SEED_USER, &member.pubkey.get_seed()?], &ID);
+            let user_account_info = remaining_accounts
+                .iter()
+                .find(|f| f.key.eq(&user_account_pubkey))
+                .ok_or(MultisigError::MissingAccount)?;
+            let mut data = user_account_info.try_borrow_mut_data()?;
+            let mut user = User::try_deserialize(&mut &data[..])?;
+
+            user.release_wallet(settings_index);
+
+            user.invariant()?;
+            user.try_serialize(&mut &mut data[..])?;
+        }
+
+        Ok(())
+    }
+
+    /// Drops the wallet entry and releases a permanent member back to a regular member, since a permanent
+    /// member only ever belongs to that one wallet.
+    fn release_wallet(&mut self, settings_index: u128) {
+        self.wallets.retain(|f| f.index.ne(&settings_index));
+        if self.role.eq(&UserRole::PermanentMember) {
+            self.role = UserRole::Member;
+        }
+    }
+
+    /// Permanent members can only leave a wallet through recovery or closing the wallet, both of which
+    /// release the user account.
     fn remove_wallet_from_user(
         args: RemoveMemberArgs,
         settings_index: u128,
+        release_permanent_member: bool,
         remaining_accounts: &[AccountInfo],
     ) -> Result<()> {
         let (user_account_pubkey, _) =
@@ -221,12 +476,15 @@ impl User {
         let mut data = user_account_info.try_borrow_mut_data()?;
         let mut user = User::try_deserialize(&mut &data[..])?;
 
-        require!(
-            user.role.ne(&UserRole::PermanentMember),
-            MultisigError::PermanentMember
-        );
-
-        user.wallets.retain(|f| f.index.ne(&settings_index));
+        if release_permanent_member {
+            user.release_wallet(settings_index);
+        } else {
+            require!(
+                user.role.ne(&UserRole::PermanentMember),
+                MultisigError::PermanentMember
+            );
+            user.wallets.retain(|f| f.index.ne(&settings_index));
+        }
 
         user.invariant()?;
         user.try_serialize(&mut &mut data[..])?;
@@ -256,6 +514,53 @@ mod tests {
         }
     }
 
+    fn mk_legacy_user_data() -> Vec<u8> {
+        let legacy = LegacyUser {
+            domain_config: Some(Pubkey::new_unique()),
+            member: mk_secp256r1_member_key(1),
+            credential_id: Some(vec![7; 16]),
+            transports: Some(vec![Transports::Internal, Transports::Hybrid]),
+            wallets: vec![SettingsIndexWithDelegateInfo {
+                index: 3,
+                is_delegate: true,
+            }],
+            role: UserRole::Member,
+            transaction_manager_url: None,
+            bump: 255,
+        };
+        let mut data = User::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_from_legacy_data() {
+        let user = User::from_legacy_data(&mk_legacy_user_data()).unwrap();
+        assert_eq!(user.member, mk_secp256r1_member_key(1));
+        assert_eq!(user.credential_id, Some(vec![7; 16]));
+        assert_eq!(user.wallets.len(), 1);
+        assert_eq!(user.bump, 255);
+        assert_eq!(user.sign_count, 0);
+        assert!(user.invariant().is_ok());
+
+        let err = User::from_legacy_data(&[0u8; 16])
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(err.contains("InvalidAccount"));
+    }
+
+    #[test]
+    fn test_from_legacy_data_twice_fails() {
+        let mut user = User::from_legacy_data(&mk_legacy_user_data()).unwrap();
+        user.record_sign_count(42).unwrap();
+
+        let mut migrated = Vec::new();
+        user.try_serialize(&mut migrated).unwrap();
+        let err = User::from_legacy_data(&migrated).err().unwrap().to_string();
+        assert!(err.contains("AccountAlreadyMigrated"));
+    }
+
     #[test]
     fn test_invariant_transaction_manager_valid() {
         let user = User {
@@ -267,6 +572,7 @@ mod tests {
             role: UserRole::TransactionManager,
             transaction_manager_url: Some("https://tm.example.com".to_string()),
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_ok());
     }
@@ -282,6 +588,7 @@ mod tests {
             role: UserRole::TransactionManager,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -297,6 +604,7 @@ mod tests {
             role: UserRole::TransactionManager,
             transaction_manager_url: Some("https://tm.example.com".to_string()),
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -315,6 +623,7 @@ mod tests {
             role: UserRole::TransactionManager,
             transaction_manager_url: Some("https://tm.example.com".to_string()),
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -330,6 +639,7 @@ mod tests {
             role: UserRole::Member,
             transaction_manager_url: Some("https://tm.example.com".to_string()),
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -345,6 +655,7 @@ mod tests {
             role: UserRole::Member,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_ok());
     }
@@ -360,6 +671,7 @@ mod tests {
             role: UserRole::Administrator,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_ok());
     }
@@ -378,6 +690,7 @@ mod tests {
             role: UserRole::Administrator,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -393,6 +706,7 @@ mod tests {
             role: UserRole::Administrator,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -408,6 +722,7 @@ mod tests {
             role: UserRole::Administrator,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -423,6 +738,23 @@ mod tests {
             role: UserRole::Member,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
+        };
+        assert!(user.invariant().is_ok());
+    }
+
+    #[test]
+    fn test_invariant_secp256r1_guardian_valid() {
+        let user = User {
+            domain_config: Some(Pubkey::new_unique()),
+            member: mk_secp256r1_member_key(1),
+            credential_id: Some(vec![1, 2, 3]),
+            transports: Some(vec![Transports::Usb]),
+            wallets: vec![],
+            role: UserRole::Guardian,
+            transaction_manager_url: None,
+            bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_ok());
     }
@@ -438,6 +770,7 @@ mod tests {
             role: UserRole::Member,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -453,6 +786,7 @@ mod tests {
             role: UserRole::Member,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -468,6 +802,7 @@ mod tests {
             role: UserRole::Member,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -483,6 +818,7 @@ mod tests {
             role: UserRole::Administrator,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -498,6 +834,7 @@ mod tests {
             role: UserRole::Member,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -513,6 +850,7 @@ mod tests {
             role: UserRole::Member,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -531,6 +869,7 @@ mod tests {
             role: UserRole::PermanentMember,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_ok());
     }
@@ -549,6 +888,7 @@ mod tests {
             role: UserRole::PermanentMember,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -567,6 +907,7 @@ mod tests {
             role: UserRole::PermanentMember,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -582,6 +923,7 @@ mod tests {
             role: UserRole::PermanentMember,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -606,6 +948,7 @@ mod tests {
             role: UserRole::Member,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_err());
     }
@@ -624,7 +967,88 @@ mod tests {
             role: UserRole::Member,
             transaction_manager_url: None,
             bump: 0,
+            sign_count: 0,
         };
         assert!(user.invariant().is_ok());
     }
+
+    #[test]
+    fn test_release_wallet_demotes_permanent_member() {
+        let mut user = User {
+            domain_config: Some(Pubkey::new_unique()),
+            member: mk_secp256r1_member_key(1),
+            credential_id: Some(vec![1, 2, 3]),
+            transports: Some(vec![Transports::Usb]),
+            wallets: vec![SettingsIndexWithDelegateInfo {
+                index: 7,
+                is_delegate: true,
+            }],
+            role: UserRole::PermanentMember,
+            transaction_manager_url: None,
+            bump: 0,
+            sign_count: 0,
+        };
+        user.release_wallet(7);
+        assert!(user.wallets.is_empty());
+        assert_eq!(user.role, UserRole::Member);
+        assert!(user.invariant().is_ok());
+    }
+
+    #[test]
+    fn test_sign_count_serialized_last() {
+        let user = User {
+            domain_config: Some(Pubkey::new_unique()),
+            member: mk_secp256r1_member_key(1),
+            credential_id: Some(vec![1, 2, 3]),
+            transports: Some(vec![]),
+            wallets: vec![SettingsIndexWithDelegateInfo {
+                index: 1,
+                is_delegate: false,
+            }],
+            role: UserRole::Member,
+            transaction_manager_url: Some("https://tm.example.com".to_string()),
+            bump: 0,
+            sign_count: 7,
+        };
+        let mut data = Vec::new();
+        user.try_serialize(&mut data).unwrap();
+
+        // With every optional field set, `size` is exact and the counter occupies the final bytes
+        assert_eq!(
+            data.len(),
+            User::size(3, 0, "https://tm.example.com".len(), 1)
+        );
+        assert_eq!(data[data.len() - 4..], 7u32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_record_sign_count() {
+        let mut user = User {
+            domain_config: Some(Pubkey::new_unique()),
+            member: mk_secp256r1_member_key(1),
+            credential_id: Some(vec![1, 2, 3]),
+            transports: Some(vec![]),
+            wallets: vec![],
+            role: UserRole::Member,
+            transaction_manager_url: None,
+            bump: 0,
+            sign_count: 0,
+        };
+
+        // Authenticators without a counter always report zero
+        user.record_sign_count(0).unwrap();
+        user.record_sign_count(0).unwrap();
+
+        user.record_sign_count(5).unwrap();
+        assert_eq!(user.sign_count, 5);
+
+        for sign_count in [5, 4, 0] {
+            let err = user.record_sign_count(sign_count).unwrap_err().to_string();
+            assert!(err.contains("SignCountNotIncreased"));
+        }
+        assert_eq!(user.sign_count, 5);
+
+        user.record_sign_count(6).unwrap();
+        assert_eq!(user.sign_count, 6);
+    }
 }
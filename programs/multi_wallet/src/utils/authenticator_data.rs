@@ -0,0 +1,242 @@
+use crate::MultisigError;
+use anchor_lang::prelude::*;
+
+// Flag bits of the authenticatorData flags byte.
+pub const FLAG_USER_PRESENT: u8 = 1 << 0;
+pub const FLAG_USER_VERIFIED: u8 = 1 << 2;
+pub const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 1 << 6;
+pub const FLAG_EXTENSION_DATA: u8 = 1 << 7;
+
+const RP_ID_HASH_SIZE: usize = 32;
+const AUTHENTICATOR_DATA_MIN_SIZE: usize = RP_ID_HASH_SIZE + 1 + 4;
+const AAGUID_SIZE: usize = 16;
+// Bounds the recursion into nested CBOR maps and arrays.
+const MAX_CBOR_DEPTH: u8 = 8;
+
+/// Parsed WebAuthn authenticatorData, see https://w3c.github.io/webauthn/#sctn-authenticator-data
+#[derive(PartialEq, Debug)]
+pub struct AuthenticatorData {
+    pub rp_id_hash: [u8; 32],
+    pub flags: u8,
+    pub sign_count: u32,
+}
+
+impl AuthenticatorData {
+    /// Parses the whole structure: rpIdHash, flags, signCount, then the attested credential data and
+    /// extensions when their flags are set. Trailing or missing bytes are rejected.
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= AUTHENTICATOR_DATA_MIN_SIZE,
+            MultisigError::InvalidAuthenticatorData
+        );
+
+        let rp_id_hash: [u8; 32] = data[..RP_ID_HASH_SIZE]
+            .try_into()
+            .map_err(|_| MultisigError::InvalidAuthenticatorData)?;
+        let flags = data[RP_ID_HASH_SIZE];
+        let sign_count = u32::from_be_bytes(
+            data[RP_ID_HASH_SIZE + 1..AUTHENTICATOR_DATA_MIN_SIZE]
+                .try_into()
+                .map_err(|_| MultisigError::InvalidAuthenticatorData)?,
+        );
+
+        let mut cursor = AUTHENTICATOR_DATA_MIN_SIZE;
+
+        if flags & FLAG_ATTESTED_CREDENTIAL_DATA != 0 {
+            // aaguid, then a big endian u16 credential id length, the credential id and a COSE key
+            let length_offset = cursor + AAGUID_SIZE;
+            let length_bytes = data
+                .get(length_offset..length_offset + 2)
+                .ok_or(MultisigError::InvalidAuthenticatorData)?;
+            let credential_id_length =
+                usize::from(u16::from_be_bytes([length_bytes[0], length_bytes[1]]));
+            cursor = length_offset + 2 + credential_id_length;
+            cursor += cbor_item_len(
+                data.get(cursor..)
+                    .ok_or(MultisigError::InvalidAuthenticatorData)?,
+                0,
+            )?;
+        }
+
+        if flags & FLAG_EXTENSION_DATA != 0 {
+            let extensions = data
+                .get(cursor..)
+                .ok_or(MultisigError::InvalidAuthenticatorData)?;
+            let extensions_length = cbor_item_len(extensions, 0)?;
+            // Extensions are a CBOR map
+            require!(
+                extensions[0] >> 5 == 5,
+                MultisigError::InvalidAuthenticatorData
+            );
+            cursor += extensions_length;
+        }
+
+        require!(
+            cursor == data.len(),
+            MultisigError::InvalidAuthenticatorData
+        );
+
+        Ok(Self {
+            rp_id_hash,
+            flags,
+            sign_count,
+        })
+    }
+
+    pub fn user_present(&self) -> bool {
+        self.flags & FLAG_USER_PRESENT != 0
+    }
+
+    pub fn user_verified(&self) -> bool {
+        self.flags & FLAG_USER_VERIFIED != 0
+    }
+}
+
+/// Returns the encoded length of the CBOR data item at the start of `data`. Only definite lengths are
+/// accepted, which is all CTAP2 canonical CBOR uses.
+fn cbor_item_len(data: &[u8], depth: u8) -> Result<usize> {
+    require!(
+        depth < MAX_CBOR_DEPTH,
+        MultisigError::InvalidAuthenticatorData
+    );
+
+    let initial = *data
+        .first()
+        .ok_or(MultisigError::InvalidAuthenticatorData)?;
+    let major_type = initial >> 5;
+    let additional_info = initial & 0x1f;
+
+    let (argument, header_len) = match additional_info {
+        0..=23 => (u64::from(additional_info), 1),
+        24..=27 => {
+            let size = 1usize << (additional_info - 24);
+            let bytes = data
+                .get(1..1 + size)
+                .ok_or(MultisigError::InvalidAuthenticatorData)?;
+            let argument = bytes
+                .iter()
+                .fold(0u64, |value, byte| (value << 8) | u64::from(*byte));
+            (argument, 1 + size)
+        }
+        _ => return err!(MultisigError::InvalidAuthenticatorData),
+    };
+
+    let nested_items = match major_type {
+        // Unsigned and negative integers, simple values and floats carry everything in the header
+        0 | 1 | 7 => return Ok(header_len),
+        // Byte and text strings
+        2 | 3 => {
+            let len = usize::try_from(argument)
+                .ok()
+                .and_then(|len| len.checked_add(header_len))
+                .ok_or(MultisigError::InvalidAuthenticatorData)?;
+            require!(len <= data.len(), MultisigError::InvalidAuthenticatorData);
+            return Ok(len);
+        }
+        4 => argument,
+        5 => argument
+            .checked_mul(2)
+            .ok_or(MultisigError::InvalidAuthenticatorData)?,
+        // Tags wrap a single item
+        _ => 1,
+    };
+
+    let mut cursor = header_len;
+    for _ in 0..nested_items {
+        cursor += cbor_item_len(
+            data.get(cursor..)
+                .ok_or(MultisigError::InvalidAuthenticatorData)?,
+            depth + 1,
+        )?;
+    }
+
+    Ok(cursor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_authenticator_data(flags: u8, sign_count: u32, rest: &[u8]) -> Vec<u8> {
+        let mut data = vec![9u8; RP_ID_HASH_SIZE];
+        data.push(flags);
+        data.extend_from_slice(&sign_count.to_be_bytes());
+        data.extend_from_slice(rest);
+        data
+    }
+
+    #[test]
+    fn test_parse_minimal() {
+        let data = mk_authenticator_data(FLAG_USER_PRESENT | FLAG_USER_VERIFIED, 7, &[]);
+        let parsed = AuthenticatorData::parse(&data).unwrap();
+        assert_eq!(parsed.rp_id_hash, [9u8; 32]);
+        assert_eq!(parsed.sign_count, 7);
+        assert!(parsed.user_present());
+        assert!(parsed.user_verified());
+
+        let parsed = AuthenticatorData::parse(&mk_authenticator_data(0, 0, &[])).unwrap();
+        assert!(!parsed.user_present());
+        assert!(!parsed.user_verified());
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_lengths() {
+        let data = mk_authenticator_data(FLAG_USER_PRESENT, 0, &[]);
+        assert!(AuthenticatorData::parse(&data[..36]).is_err());
+
+        // Trailing bytes without the extension flag
+        let data = mk_authenticator_data(FLAG_USER_PRESENT, 0, &[0xa0]);
+        assert!(AuthenticatorData::parse(&data).is_err());
+    }
+
+    #[test]
+    fn test_parse_extensions() {
+        // {"credProtect": 2}
+        let mut extensions = vec![0xa1, 0x6b];
+        extensions.extend_from_slice(b"credProtect");
+        extensions.push(0x02);
+        let data = mk_authenticator_data(FLAG_USER_PRESENT | FLAG_EXTENSION_DATA, 1, &extensions);
+        assert_eq!(AuthenticatorData::parse(&data).unwrap().sign_count, 1);
+
+        // Truncated map
+        let data = mk_authenticator_data(
+            FLAG_USER_PRESENT | FLAG_EXTENSION_DATA,
+            1,
+            &extensions[..extensions.len() - 1],
+        );
+        assert!(AuthenticatorData::parse(&data).is_err());
+
+        // Extensions that are not a map
+        let data = mk_authenticator_data(FLAG_USER_PRESENT | FLAG_EXTENSION_DATA, 1, &[0x01]);
+        assert!(AuthenticatorData::parse(&data).is_err());
+    }
+
+    #[test]
+    fn test_parse_attested_credential_data() {
+        let mut rest = vec![0u8; AAGUID_SIZE];
+        rest.extend_from_slice(&4u16.to_be_bytes());
+        rest.extend_from_slice(&[1, 2, 3, 4]);
+        // COSE key {1: 2, 3: -7}
+        rest.extend_from_slice(&[0xa2, 0x01, 0x02, 0x03, 0x26]);
+        let data =
+            mk_authenticator_data(FLAG_USER_PRESENT | FLAG_ATTESTED_CREDENTIAL_DATA, 3, &rest);
+        assert_eq!(AuthenticatorData::parse(&data).unwrap().sign_count, 3);
+
+        // Credential id longer than the data
+        let mut rest = vec![0u8; AAGUID_SIZE];
+        rest.extend_from_slice(&64u16.to_be_bytes());
+        let data =
+            mk_authenticator_data(FLAG_USER_PRESENT | FLAG_ATTESTED_CREDENTIAL_DATA, 3, &rest);
+        assert!(AuthenticatorData::parse(&data).is_err());
+    }
+
+    #[test]
+    fn test_cbor_item_len_depth_limit() {
+        let nested = vec![0x81u8; MAX_CBOR_DEPTH as usize + 1];
+        assert!(cbor_item_len(&nested, 0).is_err());
+
+        let mut nested = vec![0x81u8; 3];
+        nested.push(0x00);
+        assert_eq!(cbor_item_len(&nested, 0).unwrap(), 4);
+    }
+}
@@ -1,7 +1,7 @@
-use crate::{MemberKey, Permissions};
+use crate::{ActionThresholds, MemberKey, Permissions, RecoveryConfig};
 use anchor_lang::prelude::*;
 
-#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, PartialEq, Clone, Debug)]
 pub struct AddMemberArgs {
     pub member_key: MemberKey,
     pub permissions: Permissions,
@@ -18,10 +18,22 @@ pub struct EditMemberArgs {
     pub permissions: Permissions,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct EditMemberWeightArgs {
+    pub member_key: MemberKey,
+    pub weight: u8,
+}
+
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub enum ConfigAction {
     EditPermissions(Vec<EditMemberArgs>),
     AddMembers(Vec<AddMemberArgs>),
     RemoveMembers(Vec<RemoveMemberArgs>),
     SetThreshold(u8),
+    EditWeights(Vec<EditMemberWeightArgs>),
+    SetActionThresholds(ActionThresholds),
+    SetConfigTimelock(u64),
+    SetRecoveryConfig(RecoveryConfig),
+    RotateMemberKey { old: MemberKey, new: MemberKey },
+    SetTransactionExpiry(u64),
 }
@@ -0,0 +1,175 @@
+use crate::{
+    fetch_slot_hash, utils::ChallengeArgs, MultisigError, CURRENT_INSTRUCTION_INDEX,
+    SIGNATURE_OFFSETS_SERIALIZED_SIZE, SIGNATURE_OFFSETS_START,
+};
+use anchor_lang::prelude::*;
+
+pub const ED25519_PROGRAM_ID: Pubkey = pubkey!("Ed25519SigVerify111111111111111111111111111");
+pub const ED25519_PUBKEY_SERIALIZED_SIZE: usize = 32;
+
+#[derive(Debug)]
+struct Ed25519SignatureOffsets {
+    pub public_key_offset: u16,
+    pub message_data_offset: u16,
+    pub message_data_size: u16,
+}
+
+/// Detached Ed25519 signature over an action challenge, checked by an Ed25519SigVerify precompile
+/// instruction. Lets a member sign off-chain and a relayer submit the transaction on their behalf.
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct Ed25519VerifyArgs {
+    /// Absolute index of the Ed25519SigVerify instruction in the transaction.
+    pub instruction_index: u8,
+    pub signed_message_index: u8,
+    /// Slot whose hash the signed challenge commits to.
+    pub slot_number: u64,
+}
+
+impl Ed25519VerifyArgs {
+    /// Reads the signature offsets, only accepting ones that point into the precompile instruction itself.
+    fn read_signature_offsets(
+        data: &[u8],
+        signed_message_index: u8,
+    ) -> Result<Ed25519SignatureOffsets> {
+        let num_signatures = *data
+            .first()
+            .ok_or(MultisigError::InvalidEd25519Instruction)?;
+
+        require!(
+            signed_message_index < num_signatures,
+            MultisigError::SignatureIndexOutOfBounds
+        );
+
+        let start = SIGNATURE_OFFSETS_START
+            + usize::from(signed_message_index) * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let offsets = data
+            .get(start..start + SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+            .ok_or(MultisigError::InvalidSignatureOffsets)?;
+
+        // Offsets struct layout: 7 u16 fields in order.
+        let field = |i: usize| u16::from_le_bytes([offsets[2 * i], offsets[2 * i + 1]]);
+        require!(
+            [field(1), field(3), field(6)]
+                .iter()
+                .all(|index| *index == CURRENT_INSTRUCTION_INDEX),
+            MultisigError::InvalidSignatureOffsets
+        );
+
+        Ok(Ed25519SignatureOffsets {
+            public_key_offset: field(2),
+            message_data_offset: field(4),
+            message_data_size: field(5),
+        })
+    }
+
+    fn load_instruction_data(&self, instructions_sysvar: &UncheckedAccount) -> Result<Vec<u8>> {
+        let instruction = solana_instructions_sysvar::load_instruction_at_checked(
+            usize::from(self.instruction_index),
+            instructions_sysvar,
+        )?;
+
+        require!(
+            instruction.program_id.eq(&ED25519_PROGRAM_ID),
+            MultisigError::InvalidEd25519Instruction
+        );
+
+        Ok(instruction.data)
+    }
+
+    pub fn extract_public_key_from_instruction(
+        &self,
+        instructions_sysvar: &UncheckedAccount,
+    ) -> Result<Pubkey> {
+        let data = self.load_instruction_data(instructions_sysvar)?;
+        let offsets = Self::read_signature_offsets(&data, self.signed_message_index)?;
+
+        let public_key_offset = usize::from(offsets.public_key_offset);
+        let public_key_bytes: [u8; ED25519_PUBKEY_SERIALIZED_SIZE] = data
+            .get(public_key_offset..public_key_offset + ED25519_PUBKEY_SERIALIZED_SIZE)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(MultisigError::InvalidSignatureOffsets)?;
+
+        Ok(Pubkey::new_from_array(public_key_bytes))
+    }
+
+    pub fn verify<'info>(
+        &self,
+        sysvar_slot_history: &Option<UncheckedAccount<'info>>,
+        instructions_sysvar: &UncheckedAccount<'info>,
+        challenge_args: ChallengeArgs,
+    ) -> Result<()> {
+        let data = self.load_instruction_data(instructions_sysvar)?;
+        let offsets = Self::read_signature_offsets(&data, self.signed_message_index)?;
+
+        let message_offset = usize::from(offsets.message_data_offset);
+        let message = data
+            .get(message_offset..message_offset + usize::from(offsets.message_data_size))
+            .ok_or(MultisigError::InvalidSignatureOffsets)?;
+
+        let slot_hash = fetch_slot_hash(sysvar_slot_history, self.slot_number)?;
+        let expected_challenge = challenge_args.slot_bound_challenge(&slot_hash);
+
+        require!(
+            message.eq(&expected_challenge),
+            MultisigError::Ed25519ChallengeMismatch
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_instruction_data(public_key: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let public_key_offset = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let signature_offset = public_key_offset + ED25519_PUBKEY_SERIALIZED_SIZE;
+        let message_data_offset = signature_offset + 64;
+
+        let mut data = vec![1u8, 0u8];
+        for field in [
+            signature_offset as u16,
+            instruction_index,
+            public_key_offset as u16,
+            instruction_index,
+            message_data_offset as u16,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(public_key.as_ref());
+        data.extend_from_slice(&[0u8; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_read_signature_offsets() {
+        let public_key = Pubkey::new_unique();
+        let data = mk_instruction_data(&public_key, &[7u8; 32], CURRENT_INSTRUCTION_INDEX);
+
+        let offsets = Ed25519VerifyArgs::read_signature_offsets(&data, 0).unwrap();
+        let public_key_offset = usize::from(offsets.public_key_offset);
+        assert_eq!(
+            &data[public_key_offset..public_key_offset + ED25519_PUBKEY_SERIALIZED_SIZE],
+            public_key.as_ref()
+        );
+        assert_eq!(offsets.message_data_size, 32);
+
+        let err = Ed25519VerifyArgs::read_signature_offsets(&data, 1)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("SignatureIndexOutOfBounds"));
+    }
+
+    #[test]
+    fn test_read_signature_offsets_rejects_other_instructions() {
+        let data = mk_instruction_data(&Pubkey::new_unique(), &[7u8; 32], 0);
+        let err = Ed25519VerifyArgs::read_signature_offsets(&data, 0)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("InvalidSignatureOffsets"));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{MultisigError, VaultTransactionMessage};
+use crate::{MultisigError, VaultSignerSeeds, VaultTransactionMessage};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
 use anchor_lang::solana_program::program::invoke_signed;
@@ -164,9 +164,10 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
 
     pub fn execute_message(
         self,
-        vault_seeds: &[&[u8]],
+        vault_signer_seeds: &VaultSignerSeeds,
         protected_accounts: &[Pubkey],
     ) -> Result<()> {
+        let vault_seeds = vault_signer_seeds.seeds();
         for (ix, account_infos) in self.to_instructions_and_accounts()?.iter() {
             for account_meta in ix.accounts.iter().filter(|m| m.is_writable) {
                 require!(
@@ -174,7 +175,7 @@ impl<'a, 'info> ExecutableTransactionMessage<'a, 'info> {
                     MultisigError::ProtectedAccount
                 );
             }
-            invoke_signed(&ix, &account_infos, &[vault_seeds])?;
+            invoke_signed(&ix, &account_infos, &[&vault_seeds])?;
         }
         Ok(())
     }
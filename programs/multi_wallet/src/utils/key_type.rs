@@ -1,15 +1,30 @@
+use crate::MultisigError;
+use anchor_lang::prelude::*;
+
 #[derive(PartialEq, Debug)]
 pub enum KeyType {
     Ed25519 = 1 << 0,
     Secp256r1 = 1 << 1,
+    /// Settings address of another wallet, approving through its default vault.
+    MultiWallet = 1 << 2,
+    /// Ethereum address, approving through EIP-191 signatures checked by the secp256k1 precompile.
+    Secp256k1 = 1 << 3,
+    /// Plain P-256 key held by an HSM or secure enclave, signing the slot-bound challenge directly
+    /// instead of a WebAuthn assertion. It is not tied to a domain.
+    Secp256r1Raw = 1 << 4,
 }
 
-impl KeyType {
-    pub fn from(value: u8) -> KeyType {
-        if value == KeyType::Ed25519 as u8 {
-            return KeyType::Ed25519;
-        } else {
-            return KeyType::Secp256r1;
+impl TryFrom<u8> for KeyType {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            v if v == KeyType::Ed25519 as u8 => Ok(KeyType::Ed25519),
+            v if v == KeyType::Secp256r1 as u8 => Ok(KeyType::Secp256r1),
+            v if v == KeyType::MultiWallet as u8 => Ok(KeyType::MultiWallet),
+            v if v == KeyType::Secp256k1 as u8 => Ok(KeyType::Secp256k1),
+            v if v == KeyType::Secp256r1Raw as u8 => Ok(KeyType::Secp256r1Raw),
+            _ => err!(MultisigError::InvalidKeyType),
         }
     }
 }
@@ -20,18 +35,51 @@ mod tests {
 
     #[test]
     fn test_key_type_from_ed25519() {
-        assert_eq!(KeyType::from(KeyType::Ed25519 as u8), KeyType::Ed25519);
+        assert_eq!(
+            KeyType::try_from(KeyType::Ed25519 as u8).unwrap(),
+            KeyType::Ed25519
+        );
     }
 
     #[test]
     fn test_key_type_from_secp256r1() {
-        assert_eq!(KeyType::from(KeyType::Secp256r1 as u8), KeyType::Secp256r1);
+        assert_eq!(
+            KeyType::try_from(KeyType::Secp256r1 as u8).unwrap(),
+            KeyType::Secp256r1
+        );
+    }
+
+    #[test]
+    fn test_key_type_from_multi_wallet() {
+        assert_eq!(
+            KeyType::try_from(KeyType::MultiWallet as u8).unwrap(),
+            KeyType::MultiWallet
+        );
+    }
+
+    #[test]
+    fn test_key_type_from_secp256k1() {
+        assert_eq!(
+            KeyType::try_from(KeyType::Secp256k1 as u8).unwrap(),
+            KeyType::Secp256k1
+        );
+    }
+
+    #[test]
+    fn test_key_type_from_secp256r1_raw() {
+        assert_eq!(
+            KeyType::try_from(KeyType::Secp256r1Raw as u8).unwrap(),
+            KeyType::Secp256r1Raw
+        );
     }
 
     #[test]
-    fn test_key_type_from_unknown_defaults_to_secp256r1() {
-        assert_eq!(KeyType::from(0), KeyType::Secp256r1);
-        assert_eq!(KeyType::from(255), KeyType::Secp256r1);
+    fn test_key_type_from_unknown_fails() {
+        // 32 is the next unassigned flag after Secp256r1Raw
+        for value in [0, 3, 32, 255] {
+            let err = KeyType::try_from(value).unwrap_err().to_string();
+            assert!(err.contains("InvalidKeyType"));
+        }
     }
 
     #[test]
@@ -45,5 +93,8 @@ mod tests {
     fn test_key_type_values() {
         assert_eq!(KeyType::Ed25519 as u8, 1);
         assert_eq!(KeyType::Secp256r1 as u8, 2);
+        assert_eq!(KeyType::MultiWallet as u8, 4);
+        assert_eq!(KeyType::Secp256k1 as u8, 8);
+        assert_eq!(KeyType::Secp256r1Raw as u8, 16);
     }
 }
@@ -1,6 +1,7 @@
 use crate::{
-    utils::UserRole, KeyType, MultisigError, Permissions, Secp256r1Pubkey, Secp256r1VerifyArgs,
-    COMPRESSED_PUBKEY_SERIALIZED_SIZE,
+    utils::UserRole, KeyType, MultisigError, Permission, Permissions, Secp256k1VerifyArgs,
+    Secp256r1Pubkey, Secp256r1VerifyArgs, COMPRESSED_PUBKEY_SERIALIZED_SIZE,
+    ETH_ADDRESS_SERIALIZED_SIZE, ID, SEED_MULTISIG, SEED_VAULT,
 };
 use anchor_lang::prelude::*;
 use bytemuck::{Pod, Zeroable};
@@ -24,7 +25,12 @@ pub struct Member {
     pub permissions: Permissions,
     pub _padding: u8,
     pub is_delegate: u8,
+    pub weight: u8,
 }
+
+/// Weight assigned to newly added members, so that an unweighted wallet behaves as one vote per member.
+pub const DEFAULT_MEMBER_WEIGHT: u8 = 1;
+
 impl Member {
     pub fn new(
         pubkey: MemberKey,
@@ -38,8 +44,27 @@ impl Member {
             permissions,
             is_delegate: bool_to_u8_delegate(is_delegate),
             _padding: 0,
+            weight: DEFAULT_MEMBER_WEIGHT,
+        }
+    }
+
+    /// Weight this member contributes towards the threshold, zero if the member cannot vote.
+    pub fn vote_weight(&self) -> u32 {
+        if self.permissions.has(Permission::VoteTransaction) {
+            u32::from(self.weight)
+        } else {
+            0
         }
     }
+
+    /// Finds the member approving through `signer`, matching either its key directly or,
+    /// for a multi-wallet member, its default vault.
+    pub fn find_signer<'a>(members: &'a [Member], signer: &MemberKey) -> Option<&'a Member> {
+        members
+            .iter()
+            .find(|m| m.pubkey.eq(signer))
+            .or_else(|| members.iter().find(|m| m.pubkey.is_signed_by(signer)))
+    }
 }
 
 #[derive(
@@ -81,6 +106,7 @@ impl MemberKey {
     pub fn get_signer(
         key: &Option<Signer>,
         secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: &Option<Secp256k1VerifyArgs>,
         instructions_sysvar: Option<&UncheckedAccount>,
     ) -> Result<MemberKey> {
         if let Some(pubkey) = key {
@@ -88,8 +114,12 @@ impl MemberKey {
         }
 
         if let Some(args) = secp256r1_verify_args {
-            let pubkey = args.extract_public_key_from_instruction(instructions_sysvar)?;
-            return MemberKey::convert_secp256r1(&pubkey);
+            return args.extract_member_key_from_instruction(instructions_sysvar);
+        }
+
+        if let Some(args) = secp256k1_verify_args {
+            let eth_address = args.extract_eth_address_from_instruction(instructions_sysvar)?;
+            return MemberKey::convert_secp256k1(&eth_address);
         }
 
         Err(error!(MultisigError::NoSignerFound))
@@ -97,7 +127,7 @@ impl MemberKey {
 
     pub fn to_pubkey(&self) -> Result<Pubkey> {
         require!(
-            self.get_type() == KeyType::Ed25519,
+            self.get_type()? == KeyType::Ed25519,
             MultisigError::InvalidMemberKeyFormat
         );
         Ok(Pubkey::new_from_array(
@@ -117,19 +147,80 @@ impl MemberKey {
         MemberKey::new(KeyType::Secp256r1, pubkey.to_bytes())
     }
 
+    pub fn convert_secp256r1_raw(pubkey: &Secp256r1Pubkey) -> Result<MemberKey> {
+        MemberKey::new(KeyType::Secp256r1Raw, pubkey.to_bytes())
+    }
+
+    pub fn convert_secp256k1(eth_address: &[u8; ETH_ADDRESS_SERIALIZED_SIZE]) -> Result<MemberKey> {
+        let mut padded = [0u8; COMPRESSED_PUBKEY_SERIALIZED_SIZE];
+        padded[1..1 + ETH_ADDRESS_SERIALIZED_SIZE].copy_from_slice(eth_address);
+        MemberKey::new(KeyType::Secp256k1, padded)
+    }
+
+    pub fn convert_multi_wallet(settings_key: &Pubkey) -> Result<MemberKey> {
+        let mut padded = [0u8; COMPRESSED_PUBKEY_SERIALIZED_SIZE];
+        padded[1..COMPRESSED_PUBKEY_SERIALIZED_SIZE].copy_from_slice(settings_key.as_ref());
+        MemberKey::new(KeyType::MultiWallet, padded)
+    }
+
+    /// Default vault of the wallet behind a multi-wallet key. Only this program can sign for it,
+    /// and only while executing that wallet's own approved transaction.
+    pub fn multi_wallet_vault(&self) -> Result<Pubkey> {
+        require!(
+            self.get_type()? == KeyType::MultiWallet,
+            MultisigError::InvalidMemberKeyFormat
+        );
+        let settings_key = self.get_seed()?;
+        let (vault, _) =
+            Pubkey::find_program_address(&[SEED_MULTISIG, &settings_key, SEED_VAULT], &ID);
+        Ok(vault)
+    }
+
+    /// Whether `signer` proves this key's approval: the same key, or the default vault of a
+    /// multi-wallet key signing through CPI.
+    pub fn is_signed_by(&self, signer: &MemberKey) -> bool {
+        if self.eq(signer) {
+            return true;
+        }
+        if !matches!(self.get_type(), Ok(KeyType::MultiWallet))
+            || !matches!(signer.get_type(), Ok(KeyType::Ed25519))
+        {
+            return false;
+        }
+        match (self.multi_wallet_vault(), signer.to_pubkey()) {
+            (Ok(vault), Ok(signer)) => vault.eq(&signer),
+            _ => false,
+        }
+    }
+
     pub fn get_seed(&self) -> Result<[u8; 32]> {
-        match KeyType::from(self.key_type) {
+        match self.get_type()? {
             KeyType::Ed25519 => self.key[1..]
                 .try_into()
                 .map_err(|_| error!(MultisigError::InvalidMemberKeyFormat)),
             KeyType::Secp256r1 => self.key[1..]
                 .try_into()
                 .map_err(|_| error!(MultisigError::InvalidMemberKeyFormat)),
+            KeyType::MultiWallet => self.key[1..]
+                .try_into()
+                .map_err(|_| error!(MultisigError::InvalidMemberKeyFormat)),
+            KeyType::Secp256k1 => self.key[1..]
+                .try_into()
+                .map_err(|_| error!(MultisigError::InvalidMemberKeyFormat)),
+            KeyType::Secp256r1Raw => self.key[1..]
+                .try_into()
+                .map_err(|_| error!(MultisigError::InvalidMemberKeyFormat)),
         }
     }
 
-    pub fn get_type(&self) -> KeyType {
-        KeyType::from(self.key_type)
+    /// Nested wallets, Ethereum keys and raw P-256 keys cannot sign Solana transactions, so they
+    /// have no user account.
+    pub fn has_user_account(&self) -> bool {
+        matches!(self.get_type(), Ok(KeyType::Ed25519 | KeyType::Secp256r1))
+    }
+
+    pub fn get_type(&self) -> Result<KeyType> {
+        KeyType::try_from(self.key_type)
     }
 }
 
@@ -156,7 +247,7 @@ mod tests {
     fn test_convert_ed25519_roundtrip() {
         let pubkey = Pubkey::new_unique();
         let member_key = MemberKey::convert_ed25519(&pubkey).unwrap();
-        assert_eq!(member_key.get_type(), KeyType::Ed25519);
+        assert_eq!(member_key.get_type().unwrap(), KeyType::Ed25519);
         let recovered = member_key.to_pubkey().unwrap();
         assert_eq!(recovered, pubkey);
     }
@@ -167,8 +258,35 @@ mod tests {
         key_bytes[0] = KeyType::Secp256r1 as u8;
         let secp_pubkey = Secp256r1Pubkey(key_bytes);
         let member_key = MemberKey::convert_secp256r1(&secp_pubkey).unwrap();
-        assert_eq!(member_key.get_type(), KeyType::Secp256r1);
+        assert_eq!(member_key.get_type().unwrap(), KeyType::Secp256r1);
+        assert!(member_key.to_pubkey().is_err());
+    }
+
+    #[test]
+    fn test_convert_secp256k1() {
+        let eth_address = [0xABu8; ETH_ADDRESS_SERIALIZED_SIZE];
+        let member_key = MemberKey::convert_secp256k1(&eth_address).unwrap();
+        assert_eq!(member_key.get_type().unwrap(), KeyType::Secp256k1);
+        assert_eq!(&member_key.key[1..21], &eth_address);
+        assert!(member_key.key[21..].iter().all(|b| *b == 0));
         assert!(member_key.to_pubkey().is_err());
+        assert!(!member_key.has_user_account());
+        assert!(MemberKey::convert_ed25519(&Pubkey::new_unique())
+            .unwrap()
+            .has_user_account());
+    }
+
+    #[test]
+    fn test_convert_secp256r1_raw() {
+        let secp_pubkey = Secp256r1Pubkey([2u8; COMPRESSED_PUBKEY_SERIALIZED_SIZE]);
+        let member_key = MemberKey::convert_secp256r1_raw(&secp_pubkey).unwrap();
+        assert_eq!(member_key.get_type().unwrap(), KeyType::Secp256r1Raw);
+        assert_eq!(member_key.key, secp_pubkey.to_bytes());
+        assert!(!member_key.has_user_account());
+        assert_ne!(
+            member_key,
+            MemberKey::convert_secp256r1(&secp_pubkey).unwrap()
+        );
     }
 
     #[test]
@@ -251,6 +369,78 @@ mod tests {
         assert!(member.permissions.has(Permission::InitiateTransaction));
         assert!(member.permissions.has(Permission::VoteTransaction));
         assert!(member.permissions.has(Permission::ExecuteTransaction));
+        assert_eq!(member.weight, DEFAULT_MEMBER_WEIGHT);
+    }
+
+    #[test]
+    fn test_member_vote_weight() {
+        let member_key = MemberKey::convert_ed25519(&Pubkey::new_unique()).unwrap();
+        let mut voter = Member::new(
+            member_key,
+            UserRole::Member,
+            Permissions::from_permissions(vec![Permission::VoteTransaction]),
+            false,
+        );
+        voter.weight = 3;
+        assert_eq!(voter.vote_weight(), 3);
+
+        let mut non_voter = Member::new(
+            member_key,
+            UserRole::Member,
+            Permissions::from_permissions(vec![Permission::InitiateTransaction]),
+            false,
+        );
+        non_voter.weight = 3;
+        assert_eq!(non_voter.vote_weight(), 0);
+    }
+
+    #[test]
+    fn test_multi_wallet_signed_by_default_vault() {
+        let settings_key = Pubkey::new_unique();
+        let member_key = MemberKey::convert_multi_wallet(&settings_key).unwrap();
+        assert_eq!(member_key.get_type().unwrap(), KeyType::MultiWallet);
+        assert_eq!(member_key.get_seed().unwrap(), settings_key.to_bytes());
+        assert!(member_key.to_pubkey().is_err());
+
+        let (vault, _) =
+            Pubkey::find_program_address(&[SEED_MULTISIG, settings_key.as_ref(), SEED_VAULT], &ID);
+        let vault_signer = MemberKey::convert_ed25519(&vault).unwrap();
+        assert!(member_key.is_signed_by(&vault_signer));
+
+        let settings_signer = MemberKey::convert_ed25519(&settings_key).unwrap();
+        assert!(!member_key.is_signed_by(&settings_signer));
+        assert!(!settings_signer.is_signed_by(&member_key));
+    }
+
+    #[test]
+    fn test_find_signer_maps_vault_to_multi_wallet_member() {
+        let settings_key = Pubkey::new_unique();
+        let nested = Member::new(
+            MemberKey::convert_multi_wallet(&settings_key).unwrap(),
+            UserRole::Member,
+            Permissions::from_permissions(vec![Permission::VoteTransaction]),
+            false,
+        );
+        let plain = Member::new(
+            MemberKey::convert_ed25519(&Pubkey::new_unique()).unwrap(),
+            UserRole::Member,
+            Permissions::from_permissions(vec![Permission::VoteTransaction]),
+            false,
+        );
+        let members = [plain, nested];
+
+        let (vault, _) =
+            Pubkey::find_program_address(&[SEED_MULTISIG, settings_key.as_ref(), SEED_VAULT], &ID);
+        let vault_signer = MemberKey::convert_ed25519(&vault).unwrap();
+        let found = Member::find_signer(&members, &vault_signer).unwrap();
+        assert_eq!(found.pubkey, nested.pubkey);
+
+        assert_eq!(
+            Member::find_signer(&members, &plain.pubkey).unwrap().pubkey,
+            plain.pubkey
+        );
+        let stranger = MemberKey::convert_ed25519(&Pubkey::new_unique()).unwrap();
+        assert!(Member::find_signer(&members, &stranger).is_none());
     }
 
     #[test]
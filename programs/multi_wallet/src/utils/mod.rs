@@ -22,6 +22,15 @@ pub use secp256r1_pubkey::*;
 pub mod secp256r1_verify_args;
 pub use secp256r1_verify_args::*;
 
+pub mod authenticator_data;
+pub use authenticator_data::*;
+
+pub mod ed25519_verify_args;
+pub use ed25519_verify_args::*;
+
+pub mod secp256k1_verify_args;
+pub use secp256k1_verify_args::*;
+
 pub mod key_type;
 pub use key_type::*;
 
@@ -45,3 +54,6 @@ pub use transports::*;
 
 pub mod transaction_signers;
 pub use transaction_signers::*;
+
+pub mod vault;
+pub use vault::*;
@@ -41,11 +41,13 @@ impl Permissions {
 #[derive(Default)]
 pub struct PermissionCounts {
     pub voters: usize,
+    pub vote_weight: usize,
     pub initiators: usize,
     pub executors: usize,
     pub permanent_members: usize,
     pub transaction_manager: usize,
     pub administrator: usize,
+    pub guardians: usize,
 }
 
 #[cfg(test)]
@@ -147,6 +149,7 @@ mod tests {
     fn test_permission_counts_default() {
         let counts = PermissionCounts::default();
         assert_eq!(counts.voters, 0);
+        assert_eq!(counts.vote_weight, 0);
         assert_eq!(counts.initiators, 0);
         assert_eq!(counts.executors, 0);
         assert_eq!(counts.permanent_members, 0);
@@ -0,0 +1,200 @@
+use crate::{fetch_slot_hash, utils::ChallengeArgs, MultisigError};
+use anchor_lang::prelude::*;
+
+pub const SECP256K1_PROGRAM_ID: Pubkey = pubkey!("KeccakSecp256k11111111111111111111111111111");
+pub const ETH_ADDRESS_SERIALIZED_SIZE: usize = 20;
+pub const SECP256K1_SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 11;
+pub const SECP256K1_SIGNATURE_OFFSETS_START: usize = 1;
+
+// EIP-191 personal-sign prefix for a 32 byte message.
+pub const EIP191_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
+#[derive(Debug)]
+struct Secp256k1SignatureOffsets {
+    pub eth_address_offset: u16,
+    pub message_data_offset: u16,
+    pub message_data_size: u16,
+}
+
+/// Ethereum-style signature over an action challenge, checked by a secp256k1 precompile instruction.
+/// The precompile recovers the signer from `keccak256(message)` and matches it to the Ethereum address.
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
+pub struct Secp256k1VerifyArgs {
+    /// Absolute index of the secp256k1 instruction in the transaction.
+    pub instruction_index: u8,
+    pub signed_message_index: u8,
+    /// Slot whose hash the signed challenge commits to.
+    pub slot_number: u64,
+}
+
+impl Secp256k1VerifyArgs {
+    /// Reads the signature offsets, only accepting ones that point into the precompile instruction itself.
+    fn read_signature_offsets(
+        data: &[u8],
+        signed_message_index: u8,
+        instruction_index: u8,
+    ) -> Result<Secp256k1SignatureOffsets> {
+        let num_signatures = *data
+            .first()
+            .ok_or(MultisigError::InvalidSecp256k1Instruction)?;
+
+        require!(
+            signed_message_index < num_signatures,
+            MultisigError::SignatureIndexOutOfBounds
+        );
+
+        let start = SECP256K1_SIGNATURE_OFFSETS_START
+            + usize::from(signed_message_index) * SECP256K1_SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let offsets = data
+            .get(start..start + SECP256K1_SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+            .ok_or(MultisigError::InvalidSignatureOffsets)?;
+
+        // Offsets layout: signature_offset u16, signature_instruction_index u8, eth_address_offset u16,
+        // eth_address_instruction_index u8, message_data_offset u16, message_data_size u16, message_instruction_index u8
+        let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+        require!(
+            [offsets[2], offsets[5], offsets[10]]
+                .iter()
+                .all(|index| *index == instruction_index),
+            MultisigError::InvalidSignatureOffsets
+        );
+
+        Ok(Secp256k1SignatureOffsets {
+            eth_address_offset: read_u16(3),
+            message_data_offset: read_u16(6),
+            message_data_size: read_u16(8),
+        })
+    }
+
+    fn load_instruction_data(&self, instructions_sysvar: &UncheckedAccount) -> Result<Vec<u8>> {
+        let instruction = solana_instructions_sysvar::load_instruction_at_checked(
+            usize::from(self.instruction_index),
+            instructions_sysvar,
+        )?;
+
+        require!(
+            instruction.program_id.eq(&SECP256K1_PROGRAM_ID),
+            MultisigError::InvalidSecp256k1Instruction
+        );
+
+        Ok(instruction.data)
+    }
+
+    pub fn extract_eth_address_from_instruction(
+        &self,
+        instructions_sysvar: Option<&UncheckedAccount>,
+    ) -> Result<[u8; ETH_ADDRESS_SERIALIZED_SIZE]> {
+        let instructions_sysvar =
+            instructions_sysvar.ok_or(MultisigError::MissingInstructionsSysvar)?;
+        let data = self.load_instruction_data(instructions_sysvar)?;
+        let offsets =
+            Self::read_signature_offsets(&data, self.signed_message_index, self.instruction_index)?;
+
+        let eth_address_offset = usize::from(offsets.eth_address_offset);
+        data.get(eth_address_offset..eth_address_offset + ETH_ADDRESS_SERIALIZED_SIZE)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(error!(MultisigError::InvalidSignatureOffsets))
+    }
+
+    /// EIP-191 personal-sign message wrapping the challenge, as produced by `personal_sign`.
+    pub fn personal_sign_message(challenge: &[u8; 32]) -> Vec<u8> {
+        [EIP191_PREFIX, challenge.as_ref()].concat()
+    }
+
+    pub fn verify<'info>(
+        &self,
+        sysvar_slot_history: &Option<UncheckedAccount<'info>>,
+        instructions_sysvar: &UncheckedAccount<'info>,
+        challenge_args: ChallengeArgs,
+    ) -> Result<()> {
+        let data = self.load_instruction_data(instructions_sysvar)?;
+        let offsets =
+            Self::read_signature_offsets(&data, self.signed_message_index, self.instruction_index)?;
+
+        let message_offset = usize::from(offsets.message_data_offset);
+        let message = data
+            .get(message_offset..message_offset + usize::from(offsets.message_data_size))
+            .ok_or(MultisigError::InvalidSignatureOffsets)?;
+
+        let slot_hash = fetch_slot_hash(sysvar_slot_history, self.slot_number)?;
+        let expected_message =
+            Self::personal_sign_message(&challenge_args.slot_bound_challenge(&slot_hash));
+
+        require!(
+            message.eq(expected_message.as_slice()),
+            MultisigError::Secp256k1ChallengeMismatch
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_instruction_data(
+        eth_address: &[u8; 20],
+        message: &[u8],
+        instruction_index: u8,
+    ) -> Vec<u8> {
+        let signature_offset =
+            SECP256K1_SIGNATURE_OFFSETS_START + SECP256K1_SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let eth_address_offset = signature_offset + 65;
+        let message_data_offset = eth_address_offset + ETH_ADDRESS_SERIALIZED_SIZE;
+
+        let mut data = vec![1u8];
+        data.extend_from_slice(&(signature_offset as u16).to_le_bytes());
+        data.push(instruction_index);
+        data.extend_from_slice(&(eth_address_offset as u16).to_le_bytes());
+        data.push(instruction_index);
+        data.extend_from_slice(&(message_data_offset as u16).to_le_bytes());
+        data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        data.push(instruction_index);
+        data.extend_from_slice(&[0u8; 65]);
+        data.extend_from_slice(eth_address);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn test_read_signature_offsets() {
+        let eth_address = [9u8; 20];
+        let message = Secp256k1VerifyArgs::personal_sign_message(&[7u8; 32]);
+        let data = mk_instruction_data(&eth_address, &message, 3);
+
+        let offsets = Secp256k1VerifyArgs::read_signature_offsets(&data, 0, 3).unwrap();
+        let eth_address_offset = usize::from(offsets.eth_address_offset);
+        assert_eq!(
+            &data[eth_address_offset..eth_address_offset + ETH_ADDRESS_SERIALIZED_SIZE],
+            &eth_address
+        );
+        let message_offset = usize::from(offsets.message_data_offset);
+        assert_eq!(
+            &data[message_offset..message_offset + usize::from(offsets.message_data_size)],
+            message.as_slice()
+        );
+
+        let err = Secp256k1VerifyArgs::read_signature_offsets(&data, 1, 3)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("SignatureIndexOutOfBounds"));
+    }
+
+    #[test]
+    fn test_read_signature_offsets_rejects_other_instructions() {
+        let data = mk_instruction_data(&[9u8; 20], &[7u8; 60], 2);
+        let err = Secp256k1VerifyArgs::read_signature_offsets(&data, 0, 3)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("InvalidSignatureOffsets"));
+    }
+
+    #[test]
+    fn test_personal_sign_message() {
+        let message = Secp256k1VerifyArgs::personal_sign_message(&[7u8; 32]);
+        assert_eq!(message.len(), 28 + 32);
+        assert!(message.starts_with(b"\x19Ethereum Signed Message:\n32"));
+        assert!(message.ends_with(&[7u8; 32]));
+    }
+}
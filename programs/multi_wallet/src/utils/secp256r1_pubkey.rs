@@ -3,6 +3,8 @@ use anchor_lang::prelude::*;
 pub const COMPRESSED_PUBKEY_SERIALIZED_SIZE: usize = 33;
 pub const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
 pub const SIGNATURE_OFFSETS_START: usize = 2;
+// Precompile offsets pointing into the precompile's own data carry this instruction index.
+pub const CURRENT_INSTRUCTION_INDEX: u16 = u16::MAX;
 pub const SECP256R1_PROGRAM_ID: Pubkey = pubkey!("Secp256r1SigVerify1111111111111111111111111");
 
 #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Eq, PartialEq, Hash, Debug)]
@@ -1,11 +1,14 @@
 use crate::{
-    state::ExpectedSigner, utils::MemberKey, DomainConfig, MultisigError, Secp256r1Pubkey,
-    TransactionActionType, COMPRESSED_PUBKEY_SERIALIZED_SIZE, SECP256R1_PROGRAM_ID,
+    state::{ExpectedSigner, User},
+    utils::MemberKey,
+    AuthenticatorData, DomainConfig, MultisigError, Secp256r1Pubkey, TransactionActionType,
+    COMPRESSED_PUBKEY_SERIALIZED_SIZE, CURRENT_INSTRUCTION_INDEX, SECP256R1_PROGRAM_ID,
     SIGNATURE_OFFSETS_SERIALIZED_SIZE, SIGNATURE_OFFSETS_START,
 };
 use anchor_lang::prelude::*;
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use sha2::{Digest, Sha256};
+use std::borrow::Cow;
 
 #[allow(dead_code)]
 struct Secp256r1SignatureOffsets {
@@ -20,20 +23,39 @@ struct Secp256r1SignatureOffsets {
 
 #[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug)]
 pub struct Secp256r1VerifyArgs {
+    /// Absolute index of the secp256r1 instruction in the transaction.
+    pub instruction_index: u8,
     pub signed_message_index: u8,
     pub slot_number: u64,
     pub origin_index: u8,
     pub cross_origin: bool,
     pub truncated_client_data_json: Vec<u8>,
     pub client_and_device_hash: [u8; 32],
+    /// Plain P-256 signature over the slot-bound challenge instead of a WebAuthn assertion. The
+    /// signer resolves to a `KeyType::Secp256r1Raw` member, so passkeys cannot use this mode.
+    pub raw: bool,
 }
 
+#[derive(Clone, Copy)]
 pub struct ChallengeArgs {
     pub account: Pubkey,
     pub message_hash: [u8; 32],
     pub action_type: TransactionActionType,
 }
 
+impl ChallengeArgs {
+    /// Challenge signed by keys that carry no WebAuthn client data, bound to a recent slot hash.
+    pub fn slot_bound_challenge(&self, slot_hash: &[u8; 32]) -> [u8; 32] {
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(self.action_type.to_bytes());
+        buffer.extend_from_slice(self.account.as_ref());
+        buffer.extend_from_slice(&self.message_hash);
+        buffer.extend_from_slice(slot_hash);
+
+        Sha256::digest(&buffer).into()
+    }
+}
+
 impl Secp256r1VerifyArgs {
     /// Safely reads signature offsets from instruction data with bounds checking
     fn read_signature_offsets(
@@ -97,6 +119,22 @@ impl Secp256r1VerifyArgs {
         Ok(&data[message_offset..message_end])
     }
 
+    /// Extracts the message of a raw signature, which must be exactly the 32 byte challenge.
+    /// WebAuthn messages are at least 69 bytes, so an assertion can never pass as a raw signature.
+    fn extract_raw_message_data<'a>(
+        data: &'a [u8],
+        offsets: &Secp256r1SignatureOffsets,
+    ) -> Result<&'a [u8]> {
+        require!(
+            offsets.message_data_size == 32,
+            MultisigError::InvalidSignatureOffsets
+        );
+
+        let message_offset = offsets.message_data_offset as usize;
+        data.get(message_offset..message_offset + 32)
+            .ok_or(error!(MultisigError::InvalidSignatureOffsets))
+    }
+
     /// Validates and extracts public key from instruction with bounds checking
     fn extract_public_key_data<'a>(
         data: &'a [u8],
@@ -194,88 +232,27 @@ impl Secp256r1VerifyArgs {
         &self,
         sysvar_slot_history: &Option<UncheckedAccount<'info>>,
     ) -> Result<[u8; 32]> {
-        let sysvar_slot_history = sysvar_slot_history
-            .as_ref()
-            .ok_or(MultisigError::MissingSysvarSlotHistory)?;
-
-        let data = sysvar_slot_history
-            .try_borrow_data()
-            .map_err(|_| MultisigError::InvalidSysvarDataFormat)?;
-
-        require!(data.len() >= 8, MultisigError::InvalidSysvarDataFormat);
-
-        let num_slot_hashes = u64::from_le_bytes(
-            data[..8]
-                .try_into()
-                .map_err(|_| MultisigError::InvalidSysvarDataFormat)?,
-        ) as usize;
-
-        if num_slot_hashes == 0 {
-            return err!(MultisigError::InvalidSysvarDataFormat);
-        }
-
-        // Binary search for the slot (entries are stored in descending order by slot)
-        // Each entry is 40 bytes (8 bytes slot + 32 bytes hash), starting at offset 8
-        let mut left = 0usize;
-        let mut right = num_slot_hashes;
-
-        while left < right {
-            let mid = left + (right - left) / 2;
-
-            let pos = 8usize
-                .checked_add(
-                    mid.checked_mul(40)
-                        .ok_or(MultisigError::InvalidSysvarDataFormat)?,
-                )
-                .ok_or(MultisigError::InvalidSysvarDataFormat)?;
-
-            require!(
-                pos.checked_add(40)
-                    .ok_or(MultisigError::InvalidSysvarDataFormat)?
-                    <= data.len(),
-                MultisigError::InvalidSysvarDataFormat
-            );
-
-            let slot = u64::from_le_bytes(
-                data[pos..pos + 8]
-                    .try_into()
-                    .map_err(|_| MultisigError::InvalidSysvarDataFormat)?,
-            );
-
-            if slot == self.slot_number {
-                // Found the slot - extract the hash
-                let hash = &data[pos + 8..pos + 40];
-                return Ok(hash
-                    .try_into()
-                    .map_err(|_| MultisigError::InvalidSysvarDataFormat)?);
-            } else if slot > self.slot_number {
-                // Target slot is older (lower number), search right half
-                left = mid + 1;
-            } else {
-                // Target slot is newer (higher number), search left half
-                right = mid;
-            }
-        }
-
-        err!(MultisigError::SlotNumberNotFound)
+        fetch_slot_hash(sysvar_slot_history, self.slot_number)
     }
 
-    fn extract_webauthn_signed_message_from_instruction(
+    /// Loads the secp256r1 instruction at `instruction_index` and reads the offsets of the chosen signature.
+    fn load_signature_offsets(
         &self,
         instructions_sysvar: &UncheckedAccount,
-        expected_signers: &[ExpectedSigner],
-    ) -> Result<([u8; 32], [u8; 32])> {
-        let instruction = solana_instructions_sysvar::get_instruction_relative(-1, instructions_sysvar)?;
+    ) -> Result<(Vec<u8>, Secp256r1SignatureOffsets)> {
+        let instruction = solana_instructions_sysvar::load_instruction_at_checked(
+            usize::from(self.instruction_index),
+            instructions_sysvar,
+        )?;
 
         require!(
             instruction.program_id.eq(&SECP256R1_PROGRAM_ID),
             MultisigError::InvalidSecp256r1Instruction
         );
 
-        let data = instruction.data.as_slice();
-
+        let data = instruction.data;
         let num_signatures = *data
-            .get(0)
+            .first()
             .ok_or(MultisigError::InvalidSecp256r1Instruction)?;
 
         require!(
@@ -285,96 +262,196 @@ impl Secp256r1VerifyArgs {
 
         // Use safe helper function to read offsets
         let offsets =
-            Self::read_signature_offsets(data, self.signed_message_index, num_signatures)?;
+            Self::read_signature_offsets(&data, self.signed_message_index, num_signatures)?;
 
-        // Extract and validate message data
-        let message = Self::extract_message_data(data, &offsets)?;
+        Ok((data, offsets))
+    }
 
-        // Verify expected signers if provided
-        if !expected_signers.is_empty() {
-            let public_key_bytes = Self::extract_public_key_data(data, &offsets)?;
-
-            let extracted_pubkey = MemberKey::convert_secp256r1(&Secp256r1Pubkey(
-                public_key_bytes
-                    .try_into()
-                    .map_err(|_| MultisigError::InvalidSecp256r1PublicKey)?,
-            ))?;
-
-            let extracted_message_hash = expected_signers
-                .iter()
-                .find(|f| f.member_key.eq(&extracted_pubkey))
-                .ok_or(MultisigError::MalformedSignedMessage)?
-                .message_hash
-                .ok_or(MultisigError::InvalidArguments)?;
-
-            let computed_hash :[u8;32]=
-                Sha256::digest(message).into();
-
-            require!(
-                extracted_message_hash.eq(&computed_hash),
-                MultisigError::ExpectedMessageHashMismatch
-            );
+    /// Returns the data an offset points into: the precompile's own data for `CURRENT_INSTRUCTION_INDEX`,
+    /// otherwise the data of the referenced instruction, as the precompile itself resolves it.
+    fn referenced_instruction_data<'a>(
+        data: &'a [u8],
+        instruction_index: u16,
+        instructions_sysvar: &UncheckedAccount,
+    ) -> Result<Cow<'a, [u8]>> {
+        if instruction_index == CURRENT_INSTRUCTION_INDEX {
+            return Ok(Cow::Borrowed(data));
         }
 
-        // Extract rp_id_hash (first 32 bytes) and client_data_hash (last 32 bytes)
-        // Message size is already validated to be >= 64 bytes in extract_message_data
-        let rp_id_hash: [u8; 32] = message[..32]
+        let instruction = solana_instructions_sysvar::load_instruction_at_checked(
+            usize::from(instruction_index),
+            instructions_sysvar,
+        )?;
+
+        Ok(Cow::Owned(instruction.data))
+    }
+
+    fn extract_public_key(
+        data: &[u8],
+        offsets: &Secp256r1SignatureOffsets,
+        instructions_sysvar: &UncheckedAccount,
+    ) -> Result<Secp256r1Pubkey> {
+        let public_key_data = Self::referenced_instruction_data(
+            data,
+            offsets.public_key_instruction_index,
+            instructions_sysvar,
+        )?;
+
+        // Extract and validate public key data
+        let public_key_bytes = Self::extract_public_key_data(&public_key_data, offsets)?;
+
+        let extracted_pubkey: [u8; COMPRESSED_PUBKEY_SERIALIZED_SIZE] = public_key_bytes
             .try_into()
-            .map_err(|_| MultisigError::InvalidSignatureOffsets)?;
+            .map_err(|_| MultisigError::InvalidSecp256r1PublicKey)?;
+
+        Ok(Secp256r1Pubkey(extracted_pubkey))
+    }
 
-        let client_data_hash: [u8; 32] = message[(message.len() - 32)..]
+    fn extract_webauthn_signed_message_from_instruction(
+        &self,
+        instructions_sysvar: &UncheckedAccount,
+        expected_signers: &[ExpectedSigner],
+    ) -> Result<(MemberKey, AuthenticatorData, [u8; 32])> {
+        let (data, offsets) = self.load_signature_offsets(instructions_sysvar)?;
+        let message_data = Self::referenced_instruction_data(
+            &data,
+            offsets.message_instruction_index,
+            instructions_sysvar,
+        )?;
+
+        // Extract and validate message data
+        let message = Self::extract_message_data(&message_data, &offsets)?;
+        let signer = MemberKey::convert_secp256r1(&Self::extract_public_key(
+            &data,
+            &offsets,
+            instructions_sysvar,
+        )?)?;
+
+        Self::check_expected_signers(&signer, message, expected_signers)?;
+
+        // The message is authenticatorData followed by the 32 byte client_data_hash
+        // Message size is already validated to be >= 64 bytes in extract_message_data
+        let (authenticator_data, client_data_hash) = message.split_at(message.len() - 32);
+        let authenticator_data = AuthenticatorData::parse(authenticator_data)?;
+
+        let client_data_hash: [u8; 32] = client_data_hash
             .try_into()
             .map_err(|_| MultisigError::InvalidSignatureOffsets)?;
 
-        Ok((rp_id_hash, client_data_hash))
+        Ok((signer, authenticator_data, client_data_hash))
     }
 
-    pub fn extract_public_key_from_instruction(
-        &self,
-        instructions_sysvar: Option<&UncheckedAccount>,
-    ) -> Result<Secp256r1Pubkey> {
-        let instructions_sysvar = instructions_sysvar
-            .as_ref()
-            .ok_or(MultisigError::MissingInstructionsSysvar)?;
-        let instruction = solana_instructions_sysvar::get_instruction_relative(-1, instructions_sysvar)?;
+    /// Checks the signed message against the hash committed to by the matching expected signer, if any are given.
+    fn check_expected_signers(
+        signer: &MemberKey,
+        message: &[u8],
+        expected_signers: &[ExpectedSigner],
+    ) -> Result<()> {
+        if expected_signers.is_empty() {
+            return Ok(());
+        }
 
-        require!(
-            instruction.program_id.eq(&SECP256R1_PROGRAM_ID),
-            MultisigError::InvalidSecp256r1Instruction
-        );
+        let extracted_message_hash = expected_signers
+            .iter()
+            .find(|f| f.member_key.eq(signer))
+            .ok_or(MultisigError::MalformedSignedMessage)?
+            .message_hash
+            .ok_or(MultisigError::InvalidArguments)?;
 
-        let data = instruction.data.as_slice();
-        let num_signatures = *data
-            .get(0)
-            .ok_or(MultisigError::InvalidSecp256r1Instruction)?;
+        let computed_hash: [u8; 32] = Sha256::digest(message).into();
 
         require!(
-            self.signed_message_index < num_signatures,
-            MultisigError::SignatureIndexOutOfBounds
+            extracted_message_hash.eq(&computed_hash),
+            MultisigError::ExpectedMessageHashMismatch
         );
 
-        // Use safe helper function to read offsets
-        let offsets =
-            Self::read_signature_offsets(data, self.signed_message_index, num_signatures)?;
+        Ok(())
+    }
 
-        // Extract and validate public key data
-        let public_key_bytes = Self::extract_public_key_data(data, &offsets)?;
+    fn extract_raw_signed_message_from_instruction(
+        &self,
+        instructions_sysvar: &UncheckedAccount,
+        expected_signers: &[ExpectedSigner],
+    ) -> Result<[u8; 32]> {
+        let (data, offsets) = self.load_signature_offsets(instructions_sysvar)?;
+        let message_data = Self::referenced_instruction_data(
+            &data,
+            offsets.message_instruction_index,
+            instructions_sysvar,
+        )?;
 
-        let extracted_pubkey: [u8; COMPRESSED_PUBKEY_SERIALIZED_SIZE] = public_key_bytes
+        let message = Self::extract_raw_message_data(&message_data, &offsets)?;
+
+        if !expected_signers.is_empty() {
+            let signer = MemberKey::convert_secp256r1_raw(&Self::extract_public_key(
+                &data,
+                &offsets,
+                instructions_sysvar,
+            )?)?;
+            Self::check_expected_signers(&signer, message, expected_signers)?;
+        }
+
+        message
             .try_into()
-            .map_err(|_| MultisigError::InvalidSecp256r1PublicKey)?;
+            .map_err(|_| error!(MultisigError::InvalidSignatureOffsets))
+    }
 
-        Ok(Secp256r1Pubkey(extracted_pubkey))
+    fn verify_raw<'info>(
+        &self,
+        sysvar_slot_history: &Option<UncheckedAccount<'info>>,
+        instructions_sysvar: &UncheckedAccount<'info>,
+        challenge_args: ChallengeArgs,
+        expected_signers: &[ExpectedSigner],
+    ) -> Result<()> {
+        let message = self
+            .extract_raw_signed_message_from_instruction(instructions_sysvar, expected_signers)?;
+
+        let slot_hash = self.fetch_slot_hash(sysvar_slot_history)?;
+
+        require!(
+            message.eq(&challenge_args.slot_bound_challenge(&slot_hash)),
+            MultisigError::Secp256r1ChallengeMismatch
+        );
+
+        Ok(())
+    }
+
+    /// Member key of the signer: a raw P-256 key for raw signatures, a passkey otherwise.
+    pub fn extract_member_key_from_instruction(
+        &self,
+        instructions_sysvar: Option<&UncheckedAccount>,
+    ) -> Result<MemberKey> {
+        let instructions_sysvar = instructions_sysvar
+            .as_ref()
+            .ok_or(MultisigError::MissingInstructionsSysvar)?;
+        let (data, offsets) = self.load_signature_offsets(instructions_sysvar)?;
+        let pubkey = Self::extract_public_key(&data, &offsets, instructions_sysvar)?;
+
+        if self.raw {
+            MemberKey::convert_secp256r1_raw(&pubkey)
+        } else {
+            MemberKey::convert_secp256r1(&pubkey)
+        }
     }
 
     pub fn verify_webauthn<'info>(
         &self,
         sysvar_slot_history: &Option<UncheckedAccount<'info>>,
         domain_config: &Option<AccountLoader<'info, DomainConfig>>,
+        user_account: Option<&AccountInfo<'info>>,
         instructions_sysvar: &UncheckedAccount<'info>,
         challenge_args: ChallengeArgs,
         expected_signers: &[ExpectedSigner],
     ) -> Result<()> {
+        if self.raw {
+            return self.verify_raw(
+                sysvar_slot_history,
+                instructions_sysvar,
+                challenge_args,
+                expected_signers,
+            );
+        }
+
         let domain_data = domain_config
             .as_ref()
             .ok_or(MultisigError::DomainConfigIsMissing)?
@@ -385,17 +462,19 @@ impl Secp256r1VerifyArgs {
             MultisigError::DomainConfigIsDisabled
         );
 
-        let (rp_id_hash, client_data_hash) = self
+        let (signer, authenticator_data, client_data_hash) = self
             .extract_webauthn_signed_message_from_instruction(
                 instructions_sysvar,
                 expected_signers,
             )?;
 
         require!(
-            domain_data.rp_id_hash.eq(&rp_id_hash),
+            domain_data.rp_id_hash.eq(&authenticator_data.rp_id_hash),
             MultisigError::RpIdHashMismatch
         );
 
+        domain_data.check_authenticator_flags(&authenticator_data)?;
+
         let slot_hash = self.fetch_slot_hash(sysvar_slot_history)?;
 
         let whitelisted_origins = domain_data.parse_origins()?;
@@ -410,13 +489,13 @@ impl Secp256r1VerifyArgs {
         buffer.extend_from_slice(&slot_hash);
         buffer.extend_from_slice(self.client_and_device_hash.as_ref());
 
-        let expected_challenge =
-            Sha256::digest(&buffer).into();
+        let expected_challenge = Sha256::digest(&buffer).into();
 
         let generated_client_data_json =
             self.generate_client_data_json(expected_origin, expected_challenge)?;
 
-        let expected_client_data_hash :[u8;32]= Sha256::digest(&generated_client_data_json).into();
+        let expected_client_data_hash: [u8; 32] =
+            Sha256::digest(&generated_client_data_json).into();
 
         if client_data_hash.ne(&expected_client_data_hash) {
             msg!(
@@ -427,10 +506,85 @@ impl Secp256r1VerifyArgs {
             return err!(MultisigError::ClientDataHashMismatch);
         }
 
+        // Raw signatures carry no counter, so only WebAuthn assertions are tracked
+        let user_account = user_account.ok_or(MultisigError::MissingAccount)?;
+        User::record_sign_count_on_account(user_account, &signer, authenticator_data.sign_count)?;
+
         Ok(())
     }
 }
 
+/// Looks up the hash of `slot_number` in the SlotHashes sysvar, so a signed challenge can commit to a recent slot.
+pub fn fetch_slot_hash<'info>(
+    sysvar_slot_history: &Option<UncheckedAccount<'info>>,
+    slot_number: u64,
+) -> Result<[u8; 32]> {
+    let sysvar_slot_history = sysvar_slot_history
+        .as_ref()
+        .ok_or(MultisigError::MissingSysvarSlotHistory)?;
+
+    let data = sysvar_slot_history
+        .try_borrow_data()
+        .map_err(|_| MultisigError::InvalidSysvarDataFormat)?;
+
+    require!(data.len() >= 8, MultisigError::InvalidSysvarDataFormat);
+
+    let num_slot_hashes = u64::from_le_bytes(
+        data[..8]
+            .try_into()
+            .map_err(|_| MultisigError::InvalidSysvarDataFormat)?,
+    ) as usize;
+
+    if num_slot_hashes == 0 {
+        return err!(MultisigError::InvalidSysvarDataFormat);
+    }
+
+    // Binary search for the slot (entries are stored in descending order by slot)
+    // Each entry is 40 bytes (8 bytes slot + 32 bytes hash), starting at offset 8
+    let mut left = 0usize;
+    let mut right = num_slot_hashes;
+
+    while left < right {
+        let mid = left + (right - left) / 2;
+
+        let pos = 8usize
+            .checked_add(
+                mid.checked_mul(40)
+                    .ok_or(MultisigError::InvalidSysvarDataFormat)?,
+            )
+            .ok_or(MultisigError::InvalidSysvarDataFormat)?;
+
+        require!(
+            pos.checked_add(40)
+                .ok_or(MultisigError::InvalidSysvarDataFormat)?
+                <= data.len(),
+            MultisigError::InvalidSysvarDataFormat
+        );
+
+        let slot = u64::from_le_bytes(
+            data[pos..pos + 8]
+                .try_into()
+                .map_err(|_| MultisigError::InvalidSysvarDataFormat)?,
+        );
+
+        if slot == slot_number {
+            // Found the slot - extract the hash
+            let hash = &data[pos + 8..pos + 40];
+            return Ok(hash
+                .try_into()
+                .map_err(|_| MultisigError::InvalidSysvarDataFormat)?);
+        } else if slot > slot_number {
+            // Target slot is older (lower number), search right half
+            left = mid + 1;
+        } else {
+            // Target slot is newer (higher number), search left half
+            right = mid;
+        }
+    }
+
+    err!(MultisigError::SlotNumberNotFound)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -471,6 +625,28 @@ mod tests {
         assert!(err.contains("Failed to deserialize secp256r1 signature offsets"));
     }
 
+    #[test]
+    fn test_extract_raw_message_data_requires_challenge_len() {
+        let offsets_start = SIGNATURE_OFFSETS_START;
+        let message_offset = offsets_start + SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+
+        let mut data = vec![7u8; message_offset + 64];
+        write_u16_le(&mut data, offsets_start + 8, message_offset as u16);
+        write_u16_le(&mut data, offsets_start + 10, 32u16);
+
+        let offsets = Secp256r1VerifyArgs::read_signature_offsets(&data, 0, 1).unwrap();
+        let message = Secp256r1VerifyArgs::extract_raw_message_data(&data, &offsets).unwrap();
+        assert_eq!(message, &[7u8; 32]);
+
+        // A WebAuthn sized message is rejected
+        write_u16_le(&mut data, offsets_start + 10, 64u16);
+        let offsets = Secp256r1VerifyArgs::read_signature_offsets(&data, 0, 1).unwrap();
+        let err = Secp256r1VerifyArgs::extract_raw_message_data(&data, &offsets)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Failed to deserialize secp256r1 signature offsets"));
+    }
+
     #[test]
     fn test_extract_public_key_data_oob_fails() {
         let offsets_start = SIGNATURE_OFFSETS_START;
@@ -489,15 +665,47 @@ mod tests {
         assert!(err.contains("invalid length or encoding"));
     }
 
+    #[test]
+    fn test_referenced_instruction_data_current_instruction() {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0u64;
+        let owner = Pubkey::new_unique();
+        let mut sysvar_data = vec![0u8; 4];
+        let account_info = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut sysvar_data,
+            &owner,
+            false,
+        );
+        let unchecked: UncheckedAccount = UncheckedAccount::try_from(&account_info);
+
+        let data = [7u8; 8];
+        let resolved = Secp256r1VerifyArgs::referenced_instruction_data(
+            &data,
+            CURRENT_INSTRUCTION_INDEX,
+            &unchecked,
+        )
+        .unwrap();
+        assert!(matches!(resolved, Cow::Borrowed(bytes) if bytes == data.as_slice()));
+
+        // Any other index is read from the instructions sysvar, which this account is not
+        assert!(Secp256r1VerifyArgs::referenced_instruction_data(&data, 0, &unchecked).is_err());
+    }
+
     #[test]
     fn test_fetch_slot_hash_found() {
         let args = Secp256r1VerifyArgs {
+            instruction_index: 0,
             signed_message_index: 0,
             slot_number: 42,
             origin_index: 0,
             cross_origin: false,
             truncated_client_data_json: vec![],
             client_and_device_hash: [0u8; 32],
+            raw: false,
         };
 
         // Sysvar layout expected here:
@@ -4,3 +4,5 @@ pub const SEED_TRANSACTION_BUFFER: &[u8] = b"transaction_buffer";
 pub const SEED_DOMAIN_CONFIG: &[u8] = b"domain_config";
 pub const SEED_USER: &[u8] = b"user";
 pub const SEED_GLOBAL_COUNTER: &[u8] = b"global_counter";
+pub const SEED_CONFIG_PROPOSAL: &[u8] = b"config_proposal";
+pub const SEED_RECOVERY: &[u8] = b"recovery";
@@ -1,8 +1,8 @@
 use crate::{
-    state::User,
+    state::{ActionThresholds, RecoveryConfig, User, MIN_RECOVERY_DELAY},
     utils::{UserRole, SEED_USER},
-    AddMemberArgs, EditMemberArgs, KeyType, Member, MemberKey, MultisigError, Permission,
-    PermissionCounts, RemoveMemberArgs, ID,
+    AddMemberArgs, EditMemberArgs, EditMemberWeightArgs, KeyType, Member, MemberKey, MultisigError,
+    Permission, PermissionCounts, RemoveMemberArgs, TransactionActionType, ID,
 };
 use anchor_lang::prelude::*;
 use std::collections::{HashMap, HashSet};
@@ -15,11 +15,42 @@ pub trait MultisigSettings {
     fn delete_members(&mut self, members: Vec<MemberKey>) -> Result<()>;
     fn set_latest_slot_number(&mut self, value: u64) -> Result<()>;
     fn get_threshold(&self) -> Result<u8>;
+    fn set_action_thresholds(&mut self, value: ActionThresholds) -> Result<()>;
+    fn get_action_thresholds(&self) -> Result<ActionThresholds>;
+    fn get_recovery_config(&self) -> Result<RecoveryConfig>;
     fn get_members(&self) -> Result<&[Member]>;
     fn get_members_mut(&mut self) -> Result<&mut [Member]>;
     fn get_latest_slot_number(&self) -> Result<u64>;
     fn is_compressed(&self) -> Result<bool>;
 
+    /// Resolves the threshold for an action, falling back to the default threshold when no override is set.
+    fn get_threshold_for_action(&self, action_type: TransactionActionType) -> Result<u8> {
+        let action_thresholds = self.get_action_thresholds()?;
+        let threshold = match action_type {
+            // Closing sweeps every vault and destroys the settings, so it needs the strictest threshold
+            TransactionActionType::CloseWallet => {
+                return Ok(self
+                    .get_threshold()?
+                    .max(action_thresholds.change_config)
+                    .max(action_thresholds.sync)
+                    .max(action_thresholds.execute)
+                    .max(action_thresholds.transfer_intent));
+            }
+            TransactionActionType::ChangeConfig | TransactionActionType::ProposeConfig => {
+                action_thresholds.change_config
+            }
+            TransactionActionType::Sync => action_thresholds.sync,
+            TransactionActionType::TransferIntent => action_thresholds.transfer_intent,
+            TransactionActionType::Execute => action_thresholds.execute,
+            _ => 0,
+        };
+        if threshold == 0 {
+            self.get_threshold()
+        } else {
+            Ok(threshold)
+        }
+    }
+
     fn sort_members(&mut self) -> Result<()> {
         self.get_members_mut()?.sort_by_key(|m| m.role);
         Ok(())
@@ -105,7 +136,9 @@ pub trait MultisigSettings {
             }
             let p = &member.permissions;
             if p.has(Permission::VoteTransaction) {
-                permission_counts.voters += 1
+                permission_counts.voters += 1;
+                require!(member.weight > 0, MultisigError::InvalidMemberWeight);
+                permission_counts.vote_weight += usize::from(member.weight);
             }
             if p.has(Permission::InitiateTransaction) {
                 permission_counts.initiators += 1;
@@ -116,6 +149,25 @@ pub trait MultisigSettings {
 
             let role = UserRole::from(member.role);
 
+            if member.pubkey.get_type()? == KeyType::MultiWallet {
+                require!(
+                    role == UserRole::Member && member.is_delegate == 0,
+                    MultisigError::InvalidMultiWalletMemberConfig
+                );
+            }
+            if member.pubkey.get_type()? == KeyType::Secp256k1 {
+                require!(
+                    role == UserRole::Member && member.is_delegate == 0,
+                    MultisigError::InvalidSecp256k1MemberConfig
+                );
+            }
+            if member.pubkey.get_type()? == KeyType::Secp256r1Raw {
+                require!(
+                    role == UserRole::Member && member.is_delegate == 0,
+                    MultisigError::InvalidSecp256r1RawMemberConfig
+                );
+            }
+
             match role {
                 UserRole::PermanentMember => {
                     permission_counts.permanent_members += 1;
@@ -140,14 +192,21 @@ pub trait MultisigSettings {
                         MultisigError::InvalidTransactionManagerConfig
                     );
                     require!(
-                        member.pubkey.get_type() == KeyType::Ed25519,
+                        member.pubkey.get_type()? == KeyType::Ed25519,
                         MultisigError::InvalidTransactionManagerConfig
                     );
                 }
+                UserRole::Guardian => {
+                    permission_counts.guardians += 1;
+                    require!(
+                        p.mask == 0 && member.is_delegate == 0,
+                        MultisigError::InvalidGuardianConfig
+                    );
+                }
                 UserRole::Administrator => {
                     permission_counts.administrator += 1;
                     require!(
-                        member.pubkey.get_type() == KeyType::Ed25519,
+                        member.pubkey.get_type()? == KeyType::Ed25519,
                         MultisigError::InvalidAdministratorConfig
                     );
                     require!(
@@ -174,11 +233,35 @@ pub trait MultisigSettings {
             MultisigError::OnlyOneAdministratorAllowed
         );
 
+        let recovery_config = self.get_recovery_config()?;
         require!(
-            threshold as usize <= permission_counts.voters,
+            recovery_config.guardian_threshold as usize <= permission_counts.guardians,
+            MultisigError::InvalidGuardianThreshold
+        );
+        require!(
+            recovery_config.guardian_threshold == 0
+                || recovery_config.recovery_delay >= MIN_RECOVERY_DELAY,
+            MultisigError::RecoveryDelayTooShort
+        );
+
+        require!(
+            threshold as usize <= permission_counts.vote_weight,
             MultisigError::InsufficientSignersWithVotePermission
         );
 
+        for action_type in [
+            TransactionActionType::ChangeConfig,
+            TransactionActionType::Sync,
+            TransactionActionType::Execute,
+            TransactionActionType::TransferIntent,
+        ] {
+            require!(
+                self.get_threshold_for_action(action_type)? as usize
+                    <= permission_counts.vote_weight,
+                MultisigError::InsufficientSignersWithVotePermission
+            );
+        }
+
         require!(
             permission_counts.initiators >= 1,
             MultisigError::InsufficientSignerWithInitiatePermission
@@ -209,6 +292,18 @@ pub trait MultisigSettings {
         let mut new_member_data = Vec::with_capacity(new_members.len());
 
         for member in &new_members {
+            // Nested wallets, Ethereum keys and raw P-256 keys have no user account and always join
+            // as regular members
+            if !member.member_key.has_user_account() {
+                new_member_data.push(Member::new(
+                    member.member_key,
+                    UserRole::Member,
+                    member.permissions,
+                    false,
+                ));
+                continue;
+            }
+
             let seed = member.member_key.get_seed()?;
 
             let (user_account_pubkey, _) = Pubkey::find_program_address(&[SEED_USER, &seed], &ID);
@@ -273,6 +368,47 @@ pub trait MultisigSettings {
 
         Ok(())
     }
+
+    /// Swaps a member's key in place, keeping its permissions, role, delegate flag and weight.
+    fn rotate_member_key(&mut self, old: MemberKey, new: MemberKey) -> Result<()> {
+        let members = self.get_members_mut()?;
+
+        require!(
+            !members.iter().any(|m| m.pubkey.eq(&new)),
+            MultisigError::DuplicateMember
+        );
+
+        // The member's role and user account setup depend on the key type, so a key can only be swapped
+        // for another of the same type
+        require!(
+            old.get_type()? == new.get_type()?,
+            MultisigError::MemberKeyTypeMismatch
+        );
+
+        let member = members
+            .iter_mut()
+            .find(|m| m.pubkey.eq(&old))
+            .ok_or(MultisigError::MemberNotFound)?;
+
+        member.pubkey = new;
+
+        Ok(())
+    }
+
+    fn edit_weights(&mut self, new_weights: Vec<EditMemberWeightArgs>) -> Result<()> {
+        let members = self.get_members_mut()?;
+
+        for nw in new_weights {
+            let member = members
+                .iter_mut()
+                .find(|m| m.pubkey.eq(&nw.member_key))
+                .ok_or(MultisigError::MemberNotFound)?;
+
+            member.weight = nw.weight;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -304,6 +440,14 @@ mod tests {
             bump: 0,
             _padding: 0,
             latest_slot_number,
+            action_thresholds: ActionThresholds::default(),
+            config_timelock: 0,
+            recovery_config: RecoveryConfig::default(),
+            config_version: 0,
+            transaction_expiry: 0,
+            transaction_index: 0,
+            open_transaction_buffers: 0,
+            open_config_proposals: 0,
         }
     }
 
@@ -371,4 +515,27 @@ mod tests {
             .unwrap();
         assert_eq!(settings.latest_slot_number, 12);
     }
+
+    #[test]
+    fn test_latest_slot_number_check_rejects_vote_replayed_after_unvote() {
+        let mut settings = mk_settings(0);
+
+        let mut data = vec![0u8; 16];
+        data[..8].copy_from_slice(&200u64.to_le_bytes());
+        data[8..16].copy_from_slice(&100u64.to_le_bytes());
+        let unchecked = make_unchecked_account_with_data(data);
+        let sysvar = Some(unchecked);
+
+        // Vote at slot 10, then unvote at slot 12
+        settings.latest_slot_number_check(&[10], &sysvar).unwrap();
+        settings.latest_slot_number_check(&[12], &sysvar).unwrap();
+
+        // Replaying the vote assertion signed at slot 10 is rejected
+        let err = settings
+            .latest_slot_number_check(&[10], &sysvar)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("InvalidSlotNumber"));
+        assert_eq!(settings.latest_slot_number, 12);
+    }
 }
@@ -13,7 +13,7 @@ pub fn durable_nonce_check<'info>(
 ) -> Result<()> {
     if signers
         .iter()
-        .all(|f| f.get_type().eq(&crate::utils::KeyType::Ed25519))
+        .all(|f| matches!(f.get_type(), Ok(crate::utils::KeyType::Ed25519)))
     {
         let ix: anchor_lang::solana_program::instruction::Instruction =
             solana_instructions_sysvar::load_instruction_at_checked(0, instructions_sysvar)?;
@@ -48,6 +48,9 @@ pub fn resize_account_if_necessary<'info>(
             ),
             top_up,
         )?;
+    }
+
+    if account.data_len() < new_size {
         account.resize(new_size)?;
     }
 
@@ -9,6 +9,13 @@ pub enum TransactionActionType {
     TransferIntent,
     ChangeDelegate,
     ChangeConfig,
+    ProposeConfig,
+    CancelConfigProposal,
+    InitiateRecovery,
+    VetoRecovery,
+    CloseWallet,
+    Reject,
+    Unvote,
 }
 
 impl TransactionActionType {
@@ -25,6 +32,13 @@ impl TransactionActionType {
             TransactionActionType::TransferIntent => b"transfer_intent",
             TransactionActionType::ChangeDelegate => b"change_delegate",
             TransactionActionType::ChangeConfig => b"change_config",
+            TransactionActionType::ProposeConfig => b"propose_config",
+            TransactionActionType::CancelConfigProposal => b"cancel_config_proposal",
+            TransactionActionType::InitiateRecovery => b"initiate_recovery",
+            TransactionActionType::VetoRecovery => b"veto_recovery",
+            TransactionActionType::CloseWallet => b"close_wallet",
+            TransactionActionType::Reject => b"reject",
+            TransactionActionType::Unvote => b"unvote",
         }
     }
 }
@@ -56,5 +70,27 @@ mod tests {
             TransactionActionType::ChangeConfig.to_bytes(),
             b"change_config"
         );
+        assert_eq!(
+            TransactionActionType::ProposeConfig.to_bytes(),
+            b"propose_config"
+        );
+        assert_eq!(
+            TransactionActionType::CancelConfigProposal.to_bytes(),
+            b"cancel_config_proposal"
+        );
+        assert_eq!(
+            TransactionActionType::InitiateRecovery.to_bytes(),
+            b"initiate_recovery"
+        );
+        assert_eq!(
+            TransactionActionType::VetoRecovery.to_bytes(),
+            b"veto_recovery"
+        );
+        assert_eq!(
+            TransactionActionType::CloseWallet.to_bytes(),
+            b"close_wallet"
+        );
+        assert_eq!(TransactionActionType::Reject.to_bytes(), b"reject");
+        assert_eq!(TransactionActionType::Unvote.to_bytes(), b"unvote");
     }
 }
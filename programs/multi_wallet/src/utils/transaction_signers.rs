@@ -1,23 +1,36 @@
 use anchor_lang::prelude::*;
+use std::collections::HashSet;
 
 use crate::{
     durable_nonce_check,
     error::MultisigError,
-    state::ExpectedSigner,
-    utils::{ChallengeArgs, Member, MemberKey, Secp256r1VerifyArgs},
+    state::{ExpectedSigner, Settings},
+    utils::{
+        ChallengeArgs, Ed25519VerifyArgs, Member, MemberKey, MultisigSettings, Secp256k1VerifyArgs,
+        Secp256r1VerifyArgs, UserRole,
+    },
     DomainConfig, Permission, TransactionActionType,
 };
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub enum TransactionSyncSigners {
+    /// Index of a signing account in remaining accounts. A member wallet's default vault
+    /// signing through CPI counts as that multi-wallet member.
     Ed25519(u8),
     Secp256r1(Secp256r1VerifyArgsWithDomainConfigIndex),
+    /// Detached signature checked by an Ed25519SigVerify instruction, so the member does not
+    /// have to sign the transaction itself.
+    Ed25519Precompile(Ed25519VerifyArgs),
+    /// Ethereum key signing an EIP-191 message, checked by a secp256k1 precompile instruction.
+    Secp256k1(Secp256k1VerifyArgs),
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct Secp256r1VerifyArgsWithDomainConfigIndex {
     pub verify_args: Secp256r1VerifyArgs,
     pub domain_config_index: u8,
+    /// Index of the signer's user account in the remaining accounts, which tracks the passkey signature counter.
+    pub user_account_index: u8,
 }
 
 impl TransactionSyncSigners {
@@ -25,12 +38,7 @@ impl TransactionSyncSigners {
         signers: &'a [TransactionSyncSigners],
         remaining_accounts: &'info [AccountInfo<'info>],
         instructions_sysvar: &UncheckedAccount<'info>,
-    ) -> Result<
-        Vec<(
-            MemberKey,
-            Option<&'a Secp256r1VerifyArgsWithDomainConfigIndex>,
-        )>,
-    > {
+    ) -> Result<Vec<(MemberKey, &'a TransactionSyncSigners)>> {
         let mut resolved = Vec::with_capacity(signers.len());
         for signer in signers {
             let entry = match signer {
@@ -39,21 +47,29 @@ impl TransactionSyncSigners {
                         .get(*index as usize)
                         .ok_or(MultisigError::InvalidNumberOfAccounts)?;
                     require!(account.is_signer, MultisigError::NoSignerFound);
-                    let member_key = MemberKey::convert_ed25519(account.key)
-                        .map_err(|_| MultisigError::InvalidAccount)?;
-                    (member_key, None)
+                    MemberKey::convert_ed25519(account.key)
+                        .map_err(|_| MultisigError::InvalidAccount)?
                 }
-                TransactionSyncSigners::Secp256r1(args) => {
+                TransactionSyncSigners::Secp256r1(args) => args
+                    .verify_args
+                    .extract_member_key_from_instruction(Some(instructions_sysvar))
+                    .map_err(|_| MultisigError::InvalidAccount)?,
+                TransactionSyncSigners::Ed25519Precompile(args) => {
                     let pubkey = args
-                        .verify_args
-                        .extract_public_key_from_instruction(Some(instructions_sysvar))
+                        .extract_public_key_from_instruction(instructions_sysvar)
                         .map_err(|_| MultisigError::InvalidAccount)?;
-                    let member_key = MemberKey::convert_secp256r1(&pubkey)
+                    MemberKey::convert_ed25519(&pubkey)
+                        .map_err(|_| MultisigError::InvalidAccount)?
+                }
+                TransactionSyncSigners::Secp256k1(args) => {
+                    let eth_address = args
+                        .extract_eth_address_from_instruction(Some(instructions_sysvar))
                         .map_err(|_| MultisigError::InvalidAccount)?;
-                    (member_key, Some(args))
+                    MemberKey::convert_secp256k1(&eth_address)
+                        .map_err(|_| MultisigError::InvalidAccount)?
                 }
             };
-            resolved.push(entry);
+            resolved.push((entry, signer));
         }
         Ok(resolved)
     }
@@ -61,38 +77,96 @@ impl TransactionSyncSigners {
     pub fn collect_slot_numbers(signers: &[TransactionSyncSigners]) -> Vec<u64> {
         let mut slot_numbers = Vec::with_capacity(signers.len());
         for signer in signers {
-            if let TransactionSyncSigners::Secp256r1(args) = signer {
-                slot_numbers.push(args.verify_args.slot_number);
+            match signer {
+                TransactionSyncSigners::Secp256r1(args) => {
+                    slot_numbers.push(args.verify_args.slot_number);
+                }
+                TransactionSyncSigners::Ed25519Precompile(args) => {
+                    slot_numbers.push(args.slot_number);
+                }
+                TransactionSyncSigners::Secp256k1(args) => {
+                    slot_numbers.push(args.slot_number);
+                }
+                TransactionSyncSigners::Ed25519(_) => {}
             }
         }
         slot_numbers
     }
 
-    pub fn verify<'info>(
+    /// Checks the signer's off-chain signature over the challenge. Transaction signers need no extra check.
+    fn verify_challenge(
+        &self,
+        sync_signer_args: &SyncSignerArgs,
+        challenge_args: ChallengeArgs,
+    ) -> Result<()> {
+        let SyncSignerArgs {
+            remaining_accounts,
+            instructions_sysvar,
+            slot_hash_sysvar,
+            ..
+        } = *sync_signer_args;
+
+        match self {
+            TransactionSyncSigners::Ed25519(_) => Ok(()),
+            TransactionSyncSigners::Secp256r1(secp256r1_verify_data) => {
+                // Raw P-256 members are not tied to a domain, so no domain config is loaded for them
+                let domain_config = if secp256r1_verify_data.verify_args.raw {
+                    None
+                } else {
+                    Some(DomainConfig::extract_domain_config_account(
+                        remaining_accounts,
+                        secp256r1_verify_data.domain_config_index,
+                    )?)
+                };
+
+                secp256r1_verify_data.verify_args.verify_webauthn(
+                    slot_hash_sysvar,
+                    &domain_config,
+                    remaining_accounts.get(usize::from(secp256r1_verify_data.user_account_index)),
+                    instructions_sysvar,
+                    challenge_args,
+                    &[],
+                )
+            }
+            TransactionSyncSigners::Ed25519Precompile(ed25519_verify_data) => {
+                ed25519_verify_data.verify(slot_hash_sysvar, instructions_sysvar, challenge_args)
+            }
+            TransactionSyncSigners::Secp256k1(secp256k1_verify_data) => {
+                secp256k1_verify_data.verify(slot_hash_sysvar, instructions_sysvar, challenge_args)
+            }
+        }
+    }
+
+    pub fn verify(
         signers: &[TransactionSyncSigners],
-        remaining_accounts: &'info [AccountInfo<'info>],
-        instructions_sysvar: &UncheckedAccount<'info>,
-        slot_hash_sysvar: &Option<UncheckedAccount<'info>>,
-        members: &[Member],
-        threshold: u8,
-        challenge_account: Pubkey,
-        message_hash: [u8; 32],
-        action_type: TransactionActionType,
+        sync_signer_args: &SyncSignerArgs,
+        challenge_args: ChallengeArgs,
     ) -> Result<()> {
+        let SyncSignerArgs {
+            remaining_accounts,
+            instructions_sysvar,
+            settings,
+            ..
+        } = *sync_signer_args;
+        let action_type = challenge_args.action_type;
+        let members = settings.get_members()?;
+        let threshold = settings.get_threshold_for_action(action_type)?;
+
         let mut initiate = false;
         let mut execute = false;
-        let mut vote_count = 0u32;
+        let mut vote_weight = 0u32;
         let mut are_delegates = true;
 
         let signer_member_keys = Self::resolve(signers, remaining_accounts, instructions_sysvar)?;
         let keys: Vec<MemberKey> = signer_member_keys.iter().map(|f| f.0).collect();
         durable_nonce_check(instructions_sysvar, &keys)?;
 
+        // A signer listed twice must not have its weight counted twice
+        let mut seen: HashSet<MemberKey> = HashSet::with_capacity(keys.len());
         for (signer, signer_args) in &signer_member_keys {
-            let member = members
-                .iter()
-                .find(|m| m.pubkey.eq(signer))
-                .ok_or(MultisigError::UnexpectedSigner)?;
+            let member =
+                Member::find_signer(members, signer).ok_or(MultisigError::UnexpectedSigner)?;
+            require!(seen.insert(member.pubkey), MultisigError::DuplicateMember);
 
             let has_permission = |perm| member.permissions.has(perm);
 
@@ -102,31 +176,14 @@ impl TransactionSyncSigners {
             if has_permission(Permission::ExecuteTransaction) {
                 execute = true;
             }
-            if has_permission(Permission::VoteTransaction) {
-                vote_count += 1;
-            }
-            if signer_args.is_some() && member.is_delegate == 0 {
+            vote_weight += member.vote_weight();
+            if matches!(signer_args, TransactionSyncSigners::Secp256r1(_))
+                && member.is_delegate == 0
+            {
                 are_delegates = false;
             }
 
-            if let Some(secp256r1_verify_data) = signer_args {
-                let account_loader = DomainConfig::extract_domain_config_account(
-                    remaining_accounts,
-                    secp256r1_verify_data.domain_config_index,
-                )?;
-
-                secp256r1_verify_data.verify_args.verify_webauthn(
-                    slot_hash_sysvar,
-                    &Some(account_loader),
-                    instructions_sysvar,
-                    ChallengeArgs {
-                        account: challenge_account,
-                        message_hash,
-                        action_type,
-                    },
-                    &[],
-                )?;
-            }
+            signer_args.verify_challenge(sync_signer_args, challenge_args)?;
         }
 
         require!(
@@ -138,7 +195,7 @@ impl TransactionSyncSigners {
             MultisigError::InsufficientSignerWithExecutePermission
         );
         require!(
-            vote_count >= threshold as u32,
+            vote_weight >= threshold as u32,
             MultisigError::InsufficientSignersWithVotePermission
         );
 
@@ -148,31 +205,153 @@ impl TransactionSyncSigners {
 
         Ok(())
     }
+
+    /// Verifies that enough guardians signed to initiate a recovery.
+    pub fn verify_guardians(
+        signers: &[TransactionSyncSigners],
+        sync_signer_args: &SyncSignerArgs,
+        message_hash: [u8; 32],
+    ) -> Result<()> {
+        let SyncSignerArgs {
+            remaining_accounts,
+            instructions_sysvar,
+            settings,
+            ..
+        } = *sync_signer_args;
+        let members = settings.get_members()?;
+        let guardian_threshold = settings.get_recovery_config()?.guardian_threshold;
+        require!(guardian_threshold > 0, MultisigError::RecoveryNotEnabled);
+
+        let signer_member_keys = Self::resolve(signers, remaining_accounts, instructions_sysvar)?;
+        let keys: Vec<MemberKey> = signer_member_keys.iter().map(|f| f.0).collect();
+        durable_nonce_check(instructions_sysvar, &keys)?;
+
+        let mut seen: HashSet<MemberKey> = HashSet::with_capacity(keys.len());
+        for (signer, signer_args) in &signer_member_keys {
+            let member =
+                Member::find_signer(members, signer).ok_or(MultisigError::UnexpectedSigner)?;
+            require!(seen.insert(member.pubkey), MultisigError::DuplicateMember);
+
+            require!(
+                UserRole::from(member.role) == UserRole::Guardian,
+                MultisigError::UnexpectedSigner
+            );
+
+            signer_args.verify_challenge(
+                sync_signer_args,
+                ChallengeArgs {
+                    account: settings.key(),
+                    message_hash,
+                    action_type: TransactionActionType::InitiateRecovery,
+                },
+            )?;
+        }
+
+        require!(
+            seen.len() >= guardian_threshold as usize,
+            MultisigError::InsufficientGuardianSigners
+        );
+
+        Ok(())
+    }
+}
+
+/// Accounts that transaction signers are verified with, and the wallet they sign for.
+#[derive(Clone, Copy)]
+pub struct SyncSignerArgs<'a, 'info> {
+    pub remaining_accounts: &'info [AccountInfo<'info>],
+    pub instructions_sysvar: &'a UncheckedAccount<'info>,
+    pub slot_hash_sysvar: &'a Option<UncheckedAccount<'info>>,
+    pub settings: &'a Account<'info, Settings>,
+}
+
+/// The signer of a transaction buffer or veto action: a transaction signer, a passkey or an Ethereum key,
+/// along with the accounts needed to verify the signature.
+pub struct SignerArgs<'a, 'info> {
+    pub signer: &'a Option<Signer<'info>>,
+    pub secp256r1_verify_args: &'a Option<Secp256r1VerifyArgs>,
+    pub secp256k1_verify_args: &'a Option<Secp256k1VerifyArgs>,
+    pub instructions_sysvar: Option<&'a UncheckedAccount<'info>>,
+    pub slot_hash_sysvar: &'a Option<UncheckedAccount<'info>>,
+    pub domain_config: &'a Option<AccountLoader<'info, DomainConfig>>,
+    pub user_account: &'a Option<UncheckedAccount<'info>>,
+}
+
+impl<'a, 'info> SignerArgs<'a, 'info> {
+    fn instructions_sysvar(&self) -> Result<&'a UncheckedAccount<'info>> {
+        Ok(self
+            .instructions_sysvar
+            .ok_or(MultisigError::MissingInstructionsSysvar)?)
+    }
+
+    fn member_key(&self) -> Result<MemberKey> {
+        MemberKey::get_signer(
+            self.signer,
+            self.secp256r1_verify_args,
+            self.secp256k1_verify_args,
+            Some(self.instructions_sysvar()?),
+        )
+    }
+
+    /// Checks the signer's off-chain signature over the challenge. Transaction signers need no extra check.
+    fn verify_challenge(
+        &self,
+        challenge_args: ChallengeArgs,
+        expected_signers: &[ExpectedSigner],
+    ) -> Result<()> {
+        let instructions_sysvar = self.instructions_sysvar()?;
+        if let Some(secp256r1_verify_data) = self.secp256r1_verify_args {
+            secp256r1_verify_data.verify_webauthn(
+                self.slot_hash_sysvar,
+                self.domain_config,
+                self.user_account.as_deref(),
+                instructions_sysvar,
+                challenge_args,
+                expected_signers,
+            )?;
+        } else if let Some(secp256k1_verify_data) = self.secp256k1_verify_args {
+            secp256k1_verify_data.verify(
+                self.slot_hash_sysvar,
+                instructions_sysvar,
+                challenge_args,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Passkey signers are matched against the expected signers while verifying the challenge, every
+    /// other signer is checked here.
+    fn check_expected_signer(
+        &self,
+        member_key: &MemberKey,
+        expected_signers: &[ExpectedSigner],
+    ) -> Result<()> {
+        if self.secp256r1_verify_args.is_none() {
+            require!(
+                expected_signers.iter().any(|f| f.member_key.eq(member_key)),
+                MultisigError::UnexpectedSigner
+            );
+        }
+        Ok(())
+    }
 }
 
 pub struct TransactionBufferSigners;
 
 impl TransactionBufferSigners {
-    pub fn verify_create<'info>(
-        signer: &Option<Signer<'info>>,
-        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
-        instructions_sysvar: &UncheckedAccount<'info>,
-        slot_hash_sysvar: &Option<UncheckedAccount<'info>>,
-        domain_config: &Option<AccountLoader<'info, DomainConfig>>,
+    pub fn verify_create(
+        signer_args: &SignerArgs,
         members: &[Member],
         settings_key: Pubkey,
         message_hash: [u8; 32],
         preauthorize_execution: bool,
     ) -> Result<()> {
-        let member_key =
-            MemberKey::get_signer(signer, secp256r1_verify_args, Some(instructions_sysvar))?;
+        let member_key = signer_args.member_key()?;
 
-        durable_nonce_check(instructions_sysvar, &[member_key])?;
+        durable_nonce_check(signer_args.instructions_sysvar()?, &[member_key])?;
 
-        let member = members
-            .iter()
-            .find(|m| m.pubkey.eq(&member_key))
-            .ok_or(MultisigError::MemberNotFound)?;
+        let member =
+            Member::find_signer(members, &member_key).ok_or(MultisigError::MemberNotFound)?;
 
         require!(
             member.permissions.has(Permission::InitiateTransaction),
@@ -186,85 +365,98 @@ impl TransactionBufferSigners {
             );
         }
 
-        if let Some(secp256r1_verify_data) = secp256r1_verify_args {
-            secp256r1_verify_data.verify_webauthn(
-                slot_hash_sysvar,
-                domain_config,
-                instructions_sysvar,
-                ChallengeArgs {
-                    account: settings_key,
-                    message_hash,
-                    action_type: if preauthorize_execution {
-                        TransactionActionType::CreateWithPreauthorizedExecution
-                    } else {
-                        TransactionActionType::Create
-                    },
-                },
-                &[],
-            )?;
-        }
+        let challenge_args = ChallengeArgs {
+            account: settings_key,
+            message_hash,
+            action_type: if preauthorize_execution {
+                TransactionActionType::CreateWithPreauthorizedExecution
+            } else {
+                TransactionActionType::Create
+            },
+        };
 
-        Ok(())
+        signer_args.verify_challenge(challenge_args, &[])
     }
 
-    pub fn verify_vote<'info>(
-        signer: &Option<Signer<'info>>,
-        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
-        instructions_sysvar: &Option<UncheckedAccount<'info>>,
-        slot_hash_sysvar: &Option<UncheckedAccount<'info>>,
-        domain_config: &Option<AccountLoader<'info, DomainConfig>>,
+    pub fn verify_vote(
+        signer_args: &SignerArgs,
         members: &[Member],
         settings_key: Pubkey,
         message_hash: [u8; 32],
         expected_signers: &[ExpectedSigner],
     ) -> Result<MemberKey> {
-        let instructions_sysvar = instructions_sysvar
-            .as_ref()
-            .ok_or(MultisigError::MissingInstructionsSysvar)?;
+        let member_key = signer_args.member_key()?;
 
-        let member_key =
-            MemberKey::get_signer(signer, secp256r1_verify_args, Some(instructions_sysvar))?;
+        let member =
+            Member::find_signer(members, &member_key).ok_or(MultisigError::MemberNotFound)?;
 
-        let member = members
-            .iter()
-            .find(|m| m.pubkey.eq(&member_key))
-            .ok_or(MultisigError::MemberNotFound)?;
+        require!(
+            member.permissions.has(Permission::VoteTransaction),
+            MultisigError::InsufficientSignersWithVotePermission
+        );
+
+        let challenge_args = ChallengeArgs {
+            account: settings_key,
+            message_hash,
+            action_type: TransactionActionType::Vote,
+        };
+
+        signer_args.verify_challenge(challenge_args, expected_signers)?;
+        signer_args.check_expected_signer(&member.pubkey, expected_signers)?;
+
+        Ok(member.pubkey)
+    }
+
+    pub fn verify_reject(
+        signer_args: &SignerArgs,
+        members: &[Member],
+        settings_key: Pubkey,
+        message_hash: [u8; 32],
+        expected_signers: &[ExpectedSigner],
+    ) -> Result<MemberKey> {
+        let member_key = signer_args.member_key()?;
+
+        let member =
+            Member::find_signer(members, &member_key).ok_or(MultisigError::MemberNotFound)?;
 
         require!(
             member.permissions.has(Permission::VoteTransaction),
             MultisigError::InsufficientSignersWithVotePermission
         );
 
-        if let Some(secp256r1_verify_data) = secp256r1_verify_args {
-            secp256r1_verify_data.verify_webauthn(
-                slot_hash_sysvar,
-                domain_config,
-                instructions_sysvar,
-                ChallengeArgs {
-                    account: settings_key,
-                    message_hash,
-                    action_type: TransactionActionType::Vote,
-                },
-                expected_signers,
-            )?;
-        } else {
-            require!(
-                expected_signers
-                    .iter()
-                    .any(|f| f.member_key.eq(&member_key)),
-                MultisigError::UnexpectedSigner
-            );
-        }
+        let challenge_args = ChallengeArgs {
+            account: settings_key,
+            message_hash,
+            action_type: TransactionActionType::Reject,
+        };
+
+        signer_args.verify_challenge(challenge_args, expected_signers)?;
+        signer_args.check_expected_signer(&member.pubkey, expected_signers)?;
 
-        Ok(member_key)
+        Ok(member.pubkey)
     }
 
-    pub fn verify_execute<'info>(
-        signer: &Option<Signer<'info>>,
-        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
-        instructions_sysvar: &Option<UncheckedAccount<'info>>,
-        slot_hash_sysvar: &Option<UncheckedAccount<'info>>,
-        domain_config: &Option<AccountLoader<'info, DomainConfig>>,
+    /// Verifies a voter withdrawing their vote. The challenge must use `TransactionActionType::Unvote`
+    /// so a vote assertion cannot be replayed to revoke it.
+    pub fn verify_unvote(
+        signer_args: &SignerArgs,
+        voters: &[MemberKey],
+        challenge_args: ChallengeArgs,
+    ) -> Result<MemberKey> {
+        let member_key = signer_args.member_key()?;
+
+        let voter = voters
+            .iter()
+            .find(|v| v.is_signed_by(&member_key))
+            .ok_or(MultisigError::VoterNotFound)?;
+
+        signer_args.verify_challenge(challenge_args, &[])?;
+
+        Ok(*voter)
+    }
+
+    pub fn verify_execute(
+        signer_args: &SignerArgs,
         members: &[Member],
         threshold: u8,
         settings_key: Pubkey,
@@ -272,113 +464,103 @@ impl TransactionBufferSigners {
         voters: &[MemberKey],
         expected_signers: &[ExpectedSigner],
     ) -> Result<MemberKey> {
-        let instructions_sysvar = instructions_sysvar
-            .as_ref()
-            .ok_or(MultisigError::MissingInstructionsSysvar)?;
-
-        let member_key =
-            MemberKey::get_signer(signer, secp256r1_verify_args, Some(instructions_sysvar))?;
+        let member_key = signer_args.member_key()?;
 
-        let member = members
-            .iter()
-            .find(|m| m.pubkey.eq(&member_key))
-            .ok_or(MultisigError::MemberNotFound)?;
+        let member =
+            Member::find_signer(members, &member_key).ok_or(MultisigError::MemberNotFound)?;
 
         require!(
             member.permissions.has(Permission::ExecuteTransaction),
             MultisigError::InsufficientSignerWithExecutePermission
         );
 
-        let vote_count = members
+        let vote_weight: u32 = members
             .iter()
-            .filter(|m| {
-                m.permissions.has(Permission::VoteTransaction)
-                    && (voters.contains(&m.pubkey) || member_key.eq(&m.pubkey))
-            })
-            .count();
+            .filter(|m| voters.contains(&m.pubkey) || member.pubkey.eq(&m.pubkey))
+            .map(Member::vote_weight)
+            .sum();
 
         require!(
-            vote_count >= threshold as usize,
+            vote_weight >= threshold as u32,
             MultisigError::InsufficientSignersWithVotePermission
         );
 
-        if let Some(secp256r1_verify_data) = secp256r1_verify_args {
-            secp256r1_verify_data.verify_webauthn(
-                slot_hash_sysvar,
-                domain_config,
-                instructions_sysvar,
-                ChallengeArgs {
-                    account: settings_key,
-                    message_hash,
-                    action_type: TransactionActionType::Execute,
-                },
-                expected_signers,
-            )?;
-        } else {
-            require!(
-                expected_signers
-                    .iter()
-                    .any(|f| f.member_key.eq(&member_key)),
-                MultisigError::UnexpectedSigner
-            );
+        let challenge_args = ChallengeArgs {
+            account: settings_key,
+            message_hash,
+            action_type: TransactionActionType::Execute,
         };
 
-        Ok(member_key)
+        signer_args.verify_challenge(challenge_args, expected_signers)?;
+        signer_args.check_expected_signer(&member.pubkey, expected_signers)?;
+
+        Ok(member.pubkey)
     }
 
-    pub fn verify_close<'info>(
-        signer: &Option<Signer<'info>>,
-        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
-        instructions_sysvar: &Option<UncheckedAccount<'info>>,
-        slot_hash_sysvar: &Option<UncheckedAccount<'info>>,
-        domain_config: &Option<AccountLoader<'info, DomainConfig>>,
+    pub fn verify_close(
+        signer_args: &SignerArgs,
         creator: &MemberKey,
         payer: &Pubkey,
         settings_key: Pubkey,
         message_hash: [u8; 32],
-        valid_till: u64,
+        is_expired: bool,
     ) -> Result<()> {
-        let instructions_sysvar = instructions_sysvar
-            .as_ref()
-            .ok_or(MultisigError::MissingInstructionsSysvar)?;
-
-        let member_key =
-            MemberKey::get_signer(signer, secp256r1_verify_args, Some(instructions_sysvar))?;
+        let member_key = signer_args.member_key()?;
 
         // Allow rent payer to become the closer after transaction has expired
-        let is_rent_payer_after_expiry = Clock::get()?.unix_timestamp as u64 > valid_till
-            && signer.is_some()
+        let is_rent_payer_after_expiry = is_expired
+            && signer_args.signer.is_some()
             && MemberKey::convert_ed25519(payer)?.eq(&member_key);
 
         if !is_rent_payer_after_expiry {
             require!(
-                creator.eq(&member_key),
+                creator.is_signed_by(&member_key),
                 MultisigError::UnauthorisedToCloseTransactionBuffer
             );
 
-            if let Some(secp256r1_verify_data) = secp256r1_verify_args {
-                secp256r1_verify_data.verify_webauthn(
-                    slot_hash_sysvar,
-                    domain_config,
-                    instructions_sysvar,
-                    ChallengeArgs {
-                        account: settings_key,
-                        message_hash,
-                        action_type: TransactionActionType::Close,
-                    },
-                    &[],
-                )?;
-            }
+            let challenge_args = ChallengeArgs {
+                account: settings_key,
+                message_hash,
+                action_type: TransactionActionType::Close,
+            };
+
+            signer_args.verify_challenge(challenge_args, &[])?;
         }
         Ok(())
     }
 
-    pub fn collect_slot_numbers(secp256r1_verify_args: &Option<Secp256r1VerifyArgs>) -> Vec<u64> {
-        if let Some(args) = secp256r1_verify_args {
-            vec![args.slot_number]
-        } else {
-            Vec::new()
-        }
+    pub fn collect_slot_numbers(
+        secp256r1_verify_args: &Option<Secp256r1VerifyArgs>,
+        secp256k1_verify_args: &Option<Secp256k1VerifyArgs>,
+    ) -> Vec<u64> {
+        secp256r1_verify_args
+            .iter()
+            .map(|args| args.slot_number)
+            .chain(secp256k1_verify_args.iter().map(|args| args.slot_number))
+            .collect()
+    }
+}
+
+/// A single voting member signing off on cancelling a pending proposal.
+pub struct VetoSigners;
+
+impl VetoSigners {
+    pub fn verify(
+        signer_args: &SignerArgs,
+        members: &[Member],
+        challenge_args: ChallengeArgs,
+    ) -> Result<()> {
+        let member_key = signer_args.member_key()?;
+
+        let member =
+            Member::find_signer(members, &member_key).ok_or(MultisigError::MemberNotFound)?;
+
+        require!(
+            member.permissions.has(Permission::VoteTransaction),
+            MultisigError::InsufficientSignersWithVotePermission
+        );
+
+        signer_args.verify_challenge(challenge_args, &[])
     }
 }
 
@@ -389,25 +571,27 @@ mod tests {
 
     fn make_secp256r1_args(slot_number: u64) -> Secp256r1VerifyArgs {
         Secp256r1VerifyArgs {
+            instruction_index: 0,
             signed_message_index: 0,
             slot_number,
             origin_index: 0,
             cross_origin: false,
             truncated_client_data_json: vec![],
             client_and_device_hash: [0u8; 32],
+            raw: false,
         }
     }
 
     #[test]
     fn test_transaction_buffer_signers_collect_slot_numbers_none() {
-        let result = TransactionBufferSigners::collect_slot_numbers(&None);
+        let result = TransactionBufferSigners::collect_slot_numbers(&None, &None);
         assert!(result.is_empty());
     }
 
     #[test]
     fn test_transaction_buffer_signers_collect_slot_numbers_some() {
         let args = make_secp256r1_args(12345);
-        let result = TransactionBufferSigners::collect_slot_numbers(&Some(args));
+        let result = TransactionBufferSigners::collect_slot_numbers(&Some(args), &None);
         assert_eq!(result, vec![12345]);
     }
 
@@ -428,10 +612,41 @@ mod tests {
             TransactionSyncSigners::Secp256r1(Secp256r1VerifyArgsWithDomainConfigIndex {
                 verify_args: make_secp256r1_args(100),
                 domain_config_index: 0,
+                user_account_index: 0,
             }),
             TransactionSyncSigners::Secp256r1(Secp256r1VerifyArgsWithDomainConfigIndex {
                 verify_args: make_secp256r1_args(200),
                 domain_config_index: 1,
+                user_account_index: 1,
+            }),
+        ];
+        let result = TransactionSyncSigners::collect_slot_numbers(&signers);
+        assert_eq!(result, vec![100, 200]);
+    }
+
+    #[test]
+    fn test_transaction_buffer_signers_collect_slot_numbers_secp256k1() {
+        let secp256k1_args = Secp256k1VerifyArgs {
+            instruction_index: 0,
+            signed_message_index: 0,
+            slot_number: 300,
+        };
+        let result = TransactionBufferSigners::collect_slot_numbers(&None, &Some(secp256k1_args));
+        assert_eq!(result, vec![300]);
+    }
+
+    #[test]
+    fn test_transaction_sync_signers_collect_slot_numbers_precompile_signers() {
+        let signers = vec![
+            TransactionSyncSigners::Ed25519Precompile(Ed25519VerifyArgs {
+                instruction_index: 0,
+                signed_message_index: 0,
+                slot_number: 100,
+            }),
+            TransactionSyncSigners::Secp256k1(Secp256k1VerifyArgs {
+                instruction_index: 1,
+                signed_message_index: 0,
+                slot_number: 200,
             }),
         ];
         let result = TransactionSyncSigners::collect_slot_numbers(&signers);
@@ -488,8 +703,8 @@ mod tests {
         let sysvar = make_instructions_sysvar_static();
         let resolved = TransactionSyncSigners::resolve(&signers, remaining, &sysvar).unwrap();
         assert_eq!(resolved.len(), 1);
-        assert!(resolved[0].1.is_none());
-        assert_eq!(resolved[0].0.get_type(), crate::KeyType::Ed25519);
+        assert!(matches!(resolved[0].1, TransactionSyncSigners::Ed25519(0)));
+        assert_eq!(resolved[0].0.get_type().unwrap(), crate::KeyType::Ed25519);
         assert_eq!(resolved[0].0.to_pubkey().unwrap(), pk);
     }
 }
@@ -7,6 +7,7 @@ pub enum UserRole {
     PermanentMember,
     #[default]
     Member,
+    Guardian,
 }
 
 impl From<u8> for UserRole {
@@ -16,6 +17,7 @@ impl From<u8> for UserRole {
             1 => UserRole::Administrator,
             2 => UserRole::PermanentMember,
             3 => UserRole::Member,
+            4 => UserRole::Guardian,
             _ => UserRole::Member,
         }
     }
@@ -28,6 +30,7 @@ impl UserRole {
             UserRole::Administrator => 1,
             UserRole::PermanentMember => 2,
             UserRole::Member => 3,
+            UserRole::Guardian => 4,
         }
     }
 }
@@ -42,6 +45,7 @@ mod tests {
         assert_eq!(UserRole::from(1), UserRole::Administrator);
         assert_eq!(UserRole::from(2), UserRole::PermanentMember);
         assert_eq!(UserRole::from(3), UserRole::Member);
+        assert_eq!(UserRole::from(4), UserRole::Guardian);
         assert_eq!(UserRole::from(99), UserRole::Member); // unknown defaults to Member
     }
 
@@ -51,6 +55,7 @@ mod tests {
         assert_eq!(UserRole::Administrator.to_u8(), 1);
         assert_eq!(UserRole::PermanentMember.to_u8(), 2);
         assert_eq!(UserRole::Member.to_u8(), 3);
+        assert_eq!(UserRole::Guardian.to_u8(), 4);
     }
 
     #[test]
@@ -60,6 +65,7 @@ mod tests {
             UserRole::Administrator,
             UserRole::PermanentMember,
             UserRole::Member,
+            UserRole::Guardian,
         ] {
             assert_eq!(UserRole::from(role.to_u8()), role);
         }
@@ -0,0 +1,95 @@
+use crate::{ID, SEED_MULTISIG, SEED_VAULT};
+use anchor_lang::prelude::*;
+
+/// Index of the wallet's original vault.
+pub const DEFAULT_VAULT_INDEX: u8 = 0;
+
+/// Signer seeds for one of a wallet's vaults. The default vault keeps the original
+/// `[SEED_MULTISIG, settings, SEED_VAULT]` derivation, numbered sub-vaults append their index.
+pub struct VaultSignerSeeds {
+    settings_key: Pubkey,
+    vault_index: [u8; 1],
+    bump: [u8; 1],
+}
+
+impl VaultSignerSeeds {
+    pub fn new(settings_key: Pubkey, vault_index: u8, multi_wallet_bump: u8) -> Self {
+        let bump = if vault_index == DEFAULT_VAULT_INDEX {
+            multi_wallet_bump
+        } else {
+            Pubkey::find_program_address(
+                &[
+                    SEED_MULTISIG,
+                    settings_key.as_ref(),
+                    SEED_VAULT,
+                    &[vault_index],
+                ],
+                &ID,
+            )
+            .1
+        };
+
+        Self {
+            settings_key,
+            vault_index: [vault_index],
+            bump: [bump],
+        }
+    }
+
+    pub fn seeds(&self) -> Vec<&[u8]> {
+        if self.vault_index[0] == DEFAULT_VAULT_INDEX {
+            vec![
+                SEED_MULTISIG,
+                self.settings_key.as_ref(),
+                SEED_VAULT,
+                &self.bump,
+            ]
+        } else {
+            vec![
+                SEED_MULTISIG,
+                self.settings_key.as_ref(),
+                SEED_VAULT,
+                &self.vault_index,
+                &self.bump,
+            ]
+        }
+    }
+
+    pub fn address(&self) -> Result<Pubkey> {
+        let pubkey =
+            Pubkey::create_program_address(&self.seeds(), &ID).map_err(ProgramError::from)?;
+        Ok(pubkey)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_vault_matches_original_derivation() {
+        let settings_key = Pubkey::new_unique();
+        let (expected, bump) =
+            Pubkey::find_program_address(&[SEED_MULTISIG, settings_key.as_ref(), SEED_VAULT], &ID);
+        let vault = VaultSignerSeeds::new(settings_key, DEFAULT_VAULT_INDEX, bump);
+        assert_eq!(vault.address().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sub_vaults_have_distinct_addresses() {
+        let settings_key = Pubkey::new_unique();
+        let (_, bump) =
+            Pubkey::find_program_address(&[SEED_MULTISIG, settings_key.as_ref(), SEED_VAULT], &ID);
+        let default_vault = VaultSignerSeeds::new(settings_key, 0, bump)
+            .address()
+            .unwrap();
+        let vault_1 = VaultSignerSeeds::new(settings_key, 1, bump)
+            .address()
+            .unwrap();
+        let vault_2 = VaultSignerSeeds::new(settings_key, 2, bump)
+            .address()
+            .unwrap();
+        assert_ne!(default_vault, vault_1);
+        assert_ne!(vault_1, vault_2);
+    }
+}